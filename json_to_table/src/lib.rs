@@ -91,8 +91,10 @@ use serde_json::Value;
 
 pub use table::{JsonTable, Orientation};
 use tabled::{builder::Builder, Table};
+pub use writer::{JsonLayout, JsonWriter};
 
 mod table;
+mod writer;
 
 /// The function converts a given [`Value`] to a [`JsonTable`].
 ///
@@ -197,6 +199,32 @@ pub fn parse(value: &Value) -> Table {
     json_into_table(value)
 }
 
+/// The function converts a given [`Table`] back to a [`Value`].
+///
+/// A first row is used as a header,
+/// and each other row becomes an object keyed by the header.
+/// All values are strings; see [`JsonWriter`] for other options.
+///
+/// ```
+/// use serde_json::json;
+/// use tabled::Table;
+///
+/// let table = Table::new([("John Doe", 43), ("Jane Doe", 38)]);
+///
+/// assert_eq!(
+///     json_to_table::table_to_json(&table),
+///     json!([
+///         {"&str": "John Doe", "i32": "43"},
+///         {"&str": "Jane Doe", "i32": "38"},
+///     ]),
+/// );
+/// ```
+///
+/// [`Table`]: tabled::Table
+pub fn table_to_json(table: &Table) -> Value {
+    JsonWriter::new().to_value(table)
+}
+
 fn json_into_table(value: &Value) -> Table {
     match value {
         Value::Array(array) => {
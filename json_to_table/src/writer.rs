@@ -0,0 +1,232 @@
+use serde_json::{Map, Number, Value};
+use tabled::{
+    grid::records::{IntoRecords, Records},
+    Table,
+};
+
+/// Converter of a [`Table`] or any [`Records`] back to a [`Value`].
+///
+/// By default each row after a header becomes an object keyed by the header,
+/// and all values are kept as strings.
+///
+/// ```
+/// use json_to_table::{JsonLayout, JsonWriter};
+/// use serde_json::json;
+/// use tabled::Table;
+///
+/// let table = Table::new([("Rust", 2010, true), ("Go", 2009, false)]);
+///
+/// let value = JsonWriter::new().infer_types(true).to_value(&table);
+///
+/// assert_eq!(
+///     value,
+///     json!([
+///         {"&str": "Rust", "i32": 2010, "bool": true},
+///         {"&str": "Go", "i32": 2009, "bool": false},
+///     ]),
+/// );
+///
+/// let value = JsonWriter::new().layout(JsonLayout::Arrays).to_value(&table);
+///
+/// assert_eq!(
+///     value,
+///     json!([
+///         ["&str", "i32", "bool"],
+///         ["Rust", "2010", "true"],
+///         ["Go", "2009", "false"],
+///     ]),
+/// );
+/// ```
+///
+/// Keys of an object are ordered the way [`Map`] orders them,
+/// which is alphabetical unless `serde_json/preserve_order` feature is on.
+/// If a header has duplicate names the last column wins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonWriter {
+    layout: JsonLayout,
+    infer_types: bool,
+    strip_ansi: bool,
+}
+
+/// A shape of a [`Value`] built by [`JsonWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonLayout {
+    /// An array of objects keyed by a first row.
+    Objects,
+    /// An array of arrays, one per row, including a first row.
+    Arrays,
+}
+
+impl JsonWriter {
+    /// Creates a [`JsonWriter`] which builds an array of objects.
+    pub fn new() -> Self {
+        Self {
+            layout: JsonLayout::Objects,
+            infer_types: false,
+            strip_ansi: false,
+        }
+    }
+
+    /// Sets a shape of a resulting [`Value`].
+    pub fn layout(mut self, layout: JsonLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets whether types of values are inferred from a cell text.
+    ///
+    /// If it's on numbers, `true` and `false` become json numbers and booleans,
+    /// while `null` and empty cells become `null`.
+    /// Numbers with a leading zero (like `007`) are kept as strings though.
+    /// Otherwise all values are strings.
+    pub fn infer_types(mut self, on: bool) -> Self {
+        self.infer_types = on;
+        self
+    }
+
+    /// Sets whether ANSI sequences are removed from values.
+    #[cfg(feature = "ansi")]
+    pub fn strip_ansi(mut self, on: bool) -> Self {
+        self.strip_ansi = on;
+        self
+    }
+
+    /// Converts a [`Table`] to a [`Value`].
+    ///
+    /// Spans are ignored, a covered cell keeps its own text.
+    pub fn to_value(&self, table: &Table) -> Value {
+        self.records_to_value(table.get_records())
+    }
+
+    /// Converts [`Records`] to a [`Value`].
+    ///
+    /// It can be used to convert a [`Builder`] without building a [`Table`].
+    ///
+    /// ```
+    /// use json_to_table::JsonWriter;
+    /// use serde_json::json;
+    /// use tabled::{builder::Builder, grid::records::IterRecords};
+    ///
+    /// let mut builder = Builder::new();
+    /// builder.push_record(["name", "age"]);
+    /// builder.push_record(["John", "43"]);
+    ///
+    /// let data: Vec<Vec<String>> = builder.into();
+    /// let value = JsonWriter::new().records_to_value(IterRecords::new(&data, 2, None));
+    ///
+    /// assert_eq!(value, json!([{"name": "John", "age": "43"}]));
+    /// ```
+    ///
+    /// [`Builder`]: tabled::builder::Builder
+    pub fn records_to_value<R>(&self, records: R) -> Value
+    where
+        R: Records,
+        <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    {
+        let mut rows = records.iter_rows().into_iter().map(|row| {
+            row.into_iter()
+                .map(|cell| self.prepare_text(cell.as_ref()))
+                .collect::<Vec<_>>()
+        });
+
+        match self.layout {
+            JsonLayout::Arrays => {
+                let list = rows
+                    .map(|row| {
+                        Value::Array(row.into_iter().map(|text| self.convert(text)).collect())
+                    })
+                    .collect();
+
+                Value::Array(list)
+            }
+            JsonLayout::Objects => {
+                // keys are always strings, even when types are inferred
+                let keys = match rows.next() {
+                    Some(header) => header,
+                    None => return Value::Array(Vec::new()),
+                };
+
+                let list = rows
+                    .map(|row| {
+                        let map = keys
+                            .iter()
+                            .cloned()
+                            .zip(row.into_iter().map(|text| self.convert(text)))
+                            .collect::<Map<_, _>>();
+
+                        Value::Object(map)
+                    })
+                    .collect();
+
+                Value::Array(list)
+            }
+        }
+    }
+
+    fn prepare_text(&self, text: &str) -> String {
+        if self.strip_ansi {
+            strip_ansi(text)
+        } else {
+            text.to_owned()
+        }
+    }
+
+    fn convert(&self, text: String) -> Value {
+        if self.infer_types {
+            infer_value(text)
+        } else {
+            Value::String(text)
+        }
+    }
+}
+
+impl Default for JsonWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn infer_value(text: String) -> Value {
+    let trimmed = text.trim();
+    match trimmed {
+        "" | "null" => return Value::Null,
+        "true" => return Value::Bool(true),
+        "false" => return Value::Bool(false),
+        _ => {}
+    }
+
+    // a leading zero means it's an identifier like a zip code rather than a number
+    if has_leading_zero(trimmed) {
+        return Value::String(text);
+    }
+
+    if let Ok(n) = trimmed.parse::<i64>() {
+        return Value::Number(n.into());
+    }
+
+    if let Ok(n) = trimmed.parse::<u64>() {
+        return Value::Number(n.into());
+    }
+
+    // NaN and infinity can't be represented in json
+    let float = trimmed.parse::<f64>().ok().and_then(Number::from_f64);
+    match float {
+        Some(n) => Value::Number(n),
+        None => Value::String(text),
+    }
+}
+
+fn has_leading_zero(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    digits.len() > 1 && digits.starts_with('0') && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(feature = "ansi")]
+fn strip_ansi(text: &str) -> String {
+    ansi_str::AnsiStr::ansi_strip(text).into_owned()
+}
+
+#[cfg(not(feature = "ansi"))]
+fn strip_ansi(text: &str) -> String {
+    text.to_owned()
+}
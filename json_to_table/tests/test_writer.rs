@@ -0,0 +1,174 @@
+use json_to_table::{table_to_json, JsonLayout, JsonWriter};
+use serde_json::json;
+use tabled::{builder::Builder, grid::records::IterRecords, Table};
+
+#[test]
+fn table_to_json_test() {
+    let table = Table::new([("John Doe", 43), ("Jane Doe", 38)]);
+
+    assert_eq!(
+        table_to_json(&table),
+        json!([
+            {"&str": "John Doe", "i32": "43"},
+            {"&str": "Jane Doe", "i32": "38"},
+        ]),
+    );
+}
+
+#[test]
+fn objects_test() {
+    let table = Builder::from(vec![
+        vec![String::from("name"), String::from("city")],
+        vec![String::from("John Doe"), String::from("London")],
+        vec![String::from("Jane Doe"), String::new()],
+    ])
+    .build();
+
+    assert_eq!(
+        JsonWriter::new().to_value(&table),
+        json!([
+            {"name": "John Doe", "city": "London"},
+            {"name": "Jane Doe", "city": ""},
+        ]),
+    );
+}
+
+#[test]
+fn arrays_test() {
+    let table = Table::new([(1, "a"), (2, "b")]);
+
+    assert_eq!(
+        JsonWriter::new()
+            .layout(JsonLayout::Arrays)
+            .to_value(&table),
+        json!([["i32", "&str"], ["1", "a"], ["2", "b"]]),
+    );
+
+    assert_eq!(
+        JsonWriter::new()
+            .layout(JsonLayout::Arrays)
+            .infer_types(true)
+            .to_value(&table),
+        json!([["i32", "&str"], [1, "a"], [2, "b"]]),
+    );
+}
+
+#[test]
+fn infer_types_test() {
+    let table = Builder::from(vec![
+        vec![
+            "int", "uint", "float", "bool", "null", "empty", "text", "nan", "padded", "zero",
+            "zeros",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>(),
+        vec![
+            "-12",
+            "18446744073709551615",
+            "1.5",
+            "false",
+            "null",
+            "",
+            "12a",
+            "NaN",
+            " 7 ",
+            "0",
+            "007",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>(),
+    ])
+    .build();
+
+    assert_eq!(
+        JsonWriter::new().infer_types(true).to_value(&table),
+        json!([{
+            "int": -12,
+            "uint": 18446744073709551615u64,
+            "float": 1.5,
+            "bool": false,
+            "null": null,
+            "empty": null,
+            "text": "12a",
+            "nan": "NaN",
+            "padded": 7,
+            "zero": 0,
+            "zeros": "007",
+        }]),
+    );
+}
+
+#[test]
+fn header_is_not_inferred_test() {
+    let table = Builder::from(vec![
+        vec![String::from("01"), String::from("true")],
+        vec![String::from("1"), String::from("true")],
+    ])
+    .build();
+
+    assert_eq!(
+        JsonWriter::new().infer_types(true).to_value(&table),
+        json!([{"01": 1, "true": true}]),
+    );
+}
+
+#[test]
+fn duplicate_keys_test() {
+    let table = Builder::from(vec![
+        vec![String::from("a"), String::from("a")],
+        vec![String::from("1"), String::from("2")],
+    ])
+    .build();
+
+    assert_eq!(JsonWriter::new().to_value(&table), json!([{"a": "2"}]));
+}
+
+#[test]
+fn empty_test() {
+    let table = Builder::default().build();
+
+    assert_eq!(JsonWriter::new().to_value(&table), json!([]));
+    assert_eq!(
+        JsonWriter::new()
+            .layout(JsonLayout::Arrays)
+            .to_value(&table),
+        json!([]),
+    );
+}
+
+#[test]
+fn header_only_test() {
+    let table = Table::new(Vec::<(i32, i32)>::new());
+
+    assert_eq!(JsonWriter::new().to_value(&table), json!([]));
+}
+
+#[test]
+fn records_test() {
+    let data = vec![vec!["id", "value"], vec!["1", "one"]];
+    let records = IterRecords::new(&data, 2, None);
+
+    assert_eq!(
+        JsonWriter::new()
+            .infer_types(true)
+            .records_to_value(records),
+        json!([{"id": 1, "value": "one"}]),
+    );
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn strip_ansi_test() {
+    let table = Table::new(["\u{1b}[31mred\u{1b}[39m"]);
+
+    assert_eq!(
+        JsonWriter::new().to_value(&table),
+        json!([{"&str": "\u{1b}[31mred\u{1b}[39m"}]),
+    );
+    assert_eq!(
+        JsonWriter::new().strip_ansi(true).to_value(&table),
+        json!([{"&str": "red"}]),
+    );
+}
@@ -7,18 +7,24 @@ use core::cmp::max;
 use crate::{
     dimension::{Dimension, Estimate},
     records::{IntoRecords, Records},
-    util::string::{count_lines, get_text_width},
+    util::{
+        string::count_lines,
+        width::{UnicodeWidth, WidthMetric},
+    },
 };
 
 use crate::config::compact::CompactConfig;
 
 /// A [`Dimension`] implementation which calculates exact column/row width/height.
 ///
+/// A width is calculated by a [`WidthMetric`], which is [`UnicodeWidth`] by default.
+///
 /// [`Grid`]: crate::grid::iterable::Grid
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
-pub struct CompactGridDimension {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactGridDimension<M = UnicodeWidth> {
     height: usize,
     width: Vec<usize>,
+    metric: M,
 }
 
 impl CompactGridDimension {
@@ -37,7 +43,7 @@ impl CompactGridDimension {
         R: Records,
         <R::Iter as IntoRecords>::Cell: AsRef<str>,
     {
-        build_width(records, cfg)
+        build_width(records, cfg, &UnicodeWidth)
     }
 
     /// Calculates dimensions of columns.
@@ -46,11 +52,31 @@ impl CompactGridDimension {
         R: Records,
         <R::Iter as IntoRecords>::Cell: AsRef<str>,
     {
-        build_dims(records, cfg)
+        build_dims(records, cfg, &UnicodeWidth)
+    }
+}
+
+impl Default for CompactGridDimension {
+    fn default() -> Self {
+        Self::with_metric(UnicodeWidth)
+    }
+}
+
+impl<M> CompactGridDimension<M> {
+    /// Creates a new dimension which uses a given [`WidthMetric`].
+    pub fn with_metric(metric: M) -> Self {
+        Self {
+            height: 0,
+            width: Vec::new(),
+            metric,
+        }
     }
 }
 
-impl Dimension for CompactGridDimension {
+impl<M> Dimension for CompactGridDimension<M>
+where
+    M: WidthMetric,
+{
     fn get_width(&self, column: usize) -> usize {
         self.width[column]
     }
@@ -58,24 +84,30 @@ impl Dimension for CompactGridDimension {
     fn get_height(&self, _: usize) -> usize {
         self.height
     }
+
+    fn get_line_width(&self, line: &str) -> usize {
+        self.metric.line_width(line)
+    }
 }
 
-impl<R> Estimate<R, CompactConfig> for CompactGridDimension
+impl<R, M> Estimate<R, CompactConfig> for CompactGridDimension<M>
 where
     R: Records,
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    M: WidthMetric,
 {
     fn estimate(&mut self, records: R, cfg: &CompactConfig) {
-        self.width = build_width(records, cfg);
+        self.width = build_width(records, cfg, &self.metric);
         let pad = cfg.get_padding();
         self.height = 1 + pad.top.size + pad.bottom.size;
     }
 }
 
-fn build_dims<R>(records: R, cfg: &CompactConfig) -> (Vec<usize>, Vec<usize>)
+fn build_dims<R, M>(records: R, cfg: &CompactConfig, metric: &M) -> (Vec<usize>, Vec<usize>)
 where
     R: Records,
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    M: WidthMetric,
 {
     let mut heights = vec![];
     let mut widths = vec![0; records.count_columns()];
@@ -84,7 +116,7 @@ where
         let mut row_height = 0;
         for (col, cell) in columns.into_iter().enumerate() {
             let height = get_cell_height(cell.as_ref(), cfg);
-            let width = get_cell_width(cell.as_ref(), cfg);
+            let width = get_cell_width(cell.as_ref(), cfg, metric);
             row_height = max(row_height, height);
             widths[col] = max(widths[col], width)
         }
@@ -115,15 +147,16 @@ where
     heights
 }
 
-fn build_width<R>(records: R, cfg: &CompactConfig) -> Vec<usize>
+fn build_width<R, M>(records: R, cfg: &CompactConfig, metric: &M) -> Vec<usize>
 where
     R: Records,
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    M: WidthMetric,
 {
     let mut widths = vec![0; records.count_columns()];
     for columns in records.iter_rows() {
         for (col, cell) in columns.into_iter().enumerate() {
-            let width = get_cell_width(cell.as_ref(), cfg);
+            let width = get_cell_width(cell.as_ref(), cfg, metric);
             widths[col] = max(widths[col], width);
         }
     }
@@ -138,8 +171,8 @@ fn get_cell_height(cell: &str, cfg: &CompactConfig) -> usize {
     count_lines + pad.top.size + pad.bottom.size
 }
 
-fn get_cell_width(text: &str, cfg: &CompactConfig) -> usize {
-    let width = get_text_width(text);
+fn get_cell_width<M: WidthMetric>(text: &str, cfg: &CompactConfig, metric: &M) -> usize {
+    let width = metric.text_width(text);
     let pad = cfg.get_padding();
 
     width + pad.left.size + pad.right.size
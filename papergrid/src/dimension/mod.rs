@@ -1,5 +1,7 @@
 //! The module contains an [`Dimension`] trait and its implementations.
 
+use crate::util::string::get_line_width;
+
 #[cfg(feature = "std")]
 pub mod compact;
 #[cfg(feature = "std")]
@@ -18,6 +20,14 @@ pub trait Dimension {
 
     /// Get a row height by index.
     fn get_height(&self, row: usize) -> usize;
+
+    /// Get a width of a line of a cell.
+    ///
+    /// A grid uses it to align a line, so it must measure a text the same way widths were estimated.
+    /// By default it's [`get_line_width`].
+    fn get_line_width(&self, line: &str) -> usize {
+        get_line_width(line)
+    }
 }
 
 impl<T> Dimension for &T
@@ -31,6 +41,10 @@ where
     fn get_width(&self, column: usize) -> usize {
         T::get_width(self, column)
     }
+
+    fn get_line_width(&self, line: &str) -> usize {
+        T::get_line_width(self, line)
+    }
 }
 
 /// Dimension estimation of a [`Grid`]
@@ -11,18 +11,24 @@ use crate::{
     config::Position,
     dimension::{Dimension, Estimate},
     records::{IntoRecords, Records},
-    util::string::{count_lines, get_text_dimension, get_text_width},
+    util::{
+        string::{count_lines, get_lines},
+        width::{UnicodeWidth, WidthMetric},
+    },
 };
 
 use crate::config::spanned::SpannedConfig;
 
 /// A [`Dimension`] implementation which calculates exact column/row width/height.
 ///
+/// A width is calculated by a [`WidthMetric`], which is [`UnicodeWidth`] by default.
+///
 /// [`Grid`]: crate::grid::iterable::Grid
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
-pub struct SpannedGridDimension {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpannedGridDimension<M = UnicodeWidth> {
     height: Vec<usize>,
     width: Vec<usize>,
+    metric: M,
 }
 
 impl SpannedGridDimension {
@@ -41,7 +47,7 @@ impl SpannedGridDimension {
         R: Records,
         <R::Iter as IntoRecords>::Cell: AsRef<str>,
     {
-        build_width(records, cfg)
+        build_width(records, cfg, &UnicodeWidth)
     }
 
     /// Calculates width of columns.
@@ -50,7 +56,7 @@ impl SpannedGridDimension {
         R: Records,
         <R::Iter as IntoRecords>::Cell: AsRef<str>,
     {
-        get_width_total(records, cfg)
+        get_width_total(records, cfg, &UnicodeWidth)
     }
 
     /// Calculates height of rows.
@@ -61,6 +67,23 @@ impl SpannedGridDimension {
     {
         get_height_total(records, cfg)
     }
+}
+
+impl Default for SpannedGridDimension {
+    fn default() -> Self {
+        Self::with_metric(UnicodeWidth)
+    }
+}
+
+impl<M> SpannedGridDimension<M> {
+    /// Creates a new dimension which uses a given [`WidthMetric`].
+    pub fn with_metric(metric: M) -> Self {
+        Self {
+            height: Vec::new(),
+            width: Vec::new(),
+            metric,
+        }
+    }
 
     /// Return width and height lists.
     pub fn get_values(self) -> (Vec<usize>, Vec<usize>) {
@@ -68,7 +91,10 @@ impl SpannedGridDimension {
     }
 }
 
-impl Dimension for SpannedGridDimension {
+impl<M> Dimension for SpannedGridDimension<M>
+where
+    M: WidthMetric,
+{
     fn get_width(&self, column: usize) -> usize {
         self.width[column]
     }
@@ -76,24 +102,30 @@ impl Dimension for SpannedGridDimension {
     fn get_height(&self, row: usize) -> usize {
         self.height[row]
     }
+
+    fn get_line_width(&self, line: &str) -> usize {
+        self.metric.line_width(line)
+    }
 }
 
-impl<R> Estimate<R, SpannedConfig> for SpannedGridDimension
+impl<R, M> Estimate<R, SpannedConfig> for SpannedGridDimension<M>
 where
     R: Records,
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    M: WidthMetric,
 {
     fn estimate(&mut self, records: R, cfg: &SpannedConfig) {
-        let (width, height) = build_dimensions(records, cfg);
+        let (width, height) = build_dimensions(records, cfg, &self.metric);
         self.width = width;
         self.height = height;
     }
 }
 
-fn build_dimensions<R>(records: R, cfg: &SpannedConfig) -> (Vec<usize>, Vec<usize>)
+fn build_dimensions<R, M>(records: R, cfg: &SpannedConfig, metric: &M) -> (Vec<usize>, Vec<usize>)
 where
     R: Records,
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    M: WidthMetric,
 {
     let count_columns = records.count_columns();
 
@@ -112,7 +144,7 @@ where
             }
 
             let text = cell.as_ref();
-            let (height, width) = get_text_dimension(text);
+            let (height, width) = get_text_dimension(text, metric);
             let pad = cfg.get_padding(pos.into());
             let width = width + pad.left.size + pad.right.size;
             let height = height + pad.top.size + pad.bottom.size;
@@ -272,9 +304,20 @@ fn adjust_column_range(
     inc_range(widths, max_span_width - range_width, start, end);
 }
 
-fn get_cell_width(text: &str, cfg: &SpannedConfig, pos: Position) -> usize {
+fn get_text_dimension<M: WidthMetric>(text: &str, metric: &M) -> (usize, usize) {
+    get_lines(text)
+        .map(|line| metric.line_width(&line))
+        .fold((0, 0), |(i, acc), width| (i + 1, acc.max(width)))
+}
+
+fn get_cell_width<M: WidthMetric>(
+    text: &str,
+    cfg: &SpannedConfig,
+    pos: Position,
+    metric: &M,
+) -> usize {
     let padding = get_cell_padding(cfg, pos);
-    let width = get_text_width(text);
+    let width = metric.text_width(text);
     width + padding
 }
 
@@ -335,10 +378,11 @@ where
     heights
 }
 
-fn build_width<R>(records: R, cfg: &SpannedConfig) -> Vec<usize>
+fn build_width<R, M>(records: R, cfg: &SpannedConfig, metric: &M) -> Vec<usize>
 where
     R: Records,
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    M: WidthMetric,
 {
     let count_columns = records.count_columns();
 
@@ -352,7 +396,7 @@ where
                 continue;
             }
 
-            let width = get_cell_width(cell.as_ref(), cfg, pos);
+            let width = get_cell_width(cell.as_ref(), cfg, pos, metric);
             match cfg.get_column_span(pos) {
                 Some(n) if n > 1 => {
                     vspans.insert(pos, (n, width));
@@ -367,12 +411,13 @@ where
     widths
 }
 
-fn get_width_total<R>(records: R, cfg: &SpannedConfig) -> usize
+fn get_width_total<R, M>(records: R, cfg: &SpannedConfig, metric: &M) -> usize
 where
     R: Records,
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    M: WidthMetric,
 {
-    let widths = build_width(records, cfg);
+    let widths = build_width(records, cfg, metric);
     let count_columns = widths.len();
 
     let total = widths.into_iter().sum::<usize>();
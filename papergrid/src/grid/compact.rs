@@ -12,7 +12,6 @@ use crate::{
     config::{AlignmentHorizontal, Borders, HorizontalLine, Indent, Sides},
     dimension::Dimension,
     records::{IntoRecords, Records},
-    util::string::get_line_width,
 };
 
 use crate::config::compact::CompactConfig;
@@ -465,37 +464,34 @@ where
     let text = data
         .next()
         .expect("we check in the beginning that size must be at least 1 column");
-    let width = dims.get_width(0);
     let color = colors.get_color((row, 0));
 
     let text = text.as_ref();
     let text = text.lines().next().unwrap_or("");
-    print_cell(f, text, color, padding, alignment, width)?;
+    print_cell(f, text, color, padding, alignment, dims, 0)?;
 
     match borders.intersection {
         Some(indent) => {
             for (col, text) in data.enumerate() {
                 let col = col + 1;
 
-                let width = dims.get_width(col);
                 let color = colors.get_color((row, col));
                 let text = text.as_ref();
                 let text = text.lines().next().unwrap_or("");
 
                 print_char(f, indent.space.fill, indent.color)?;
-                print_cell(f, text, color, padding, alignment, width)?;
+                print_cell(f, text, color, padding, alignment, dims, col)?;
             }
         }
         None => {
             for (col, text) in data.enumerate() {
                 let col = col + 1;
 
-                let width = dims.get_width(col);
                 let color = colors.get_color((row, col));
                 let text = text.as_ref();
                 let text = text.lines().next().unwrap_or("");
 
-                print_cell(f, text, color, padding, alignment, width)?;
+                print_cell(f, text, color, padding, alignment, dims, col)?;
             }
         }
     }
@@ -549,21 +545,24 @@ where
     Ok(())
 }
 
-fn print_cell<F, C>(
+fn print_cell<F, C, D>(
     f: &mut F,
     text: &str,
     color: Option<C>,
     padding: &Sides<ColoredIndent>,
     alignment: AlignmentHorizontal,
-    width: usize,
+    dims: &D,
+    col: usize,
 ) -> fmt::Result
 where
     F: Write,
     C: ANSIFmt,
+    D: Dimension,
 {
+    let width = dims.get_width(col);
     let available = width - (padding.left.space.size + padding.right.space.size);
 
-    let text_width = get_line_width(text);
+    let text_width = dims.get_line_width(text);
     let (left, right) = if available > text_width {
         calculate_indent(alignment, text_width, available)
     } else {
@@ -14,7 +14,7 @@ use crate::{
     config::{AlignmentHorizontal, AlignmentVertical, Formatting, Indent, Position, Sides},
     dimension::Dimension,
     records::{IntoRecords, Records},
-    util::string::{count_lines, get_line_width, get_lines, get_text_width, Lines},
+    util::string::{count_lines, get_lines, Lines},
 };
 
 /// Grid provides a set of methods for building a text-based table.
@@ -244,7 +244,7 @@ where
     C: Colors,
 {
     collect_columns(buf, columns, cfg, colors, dimension, height, row);
-    print_columns_lines(f, buf, height, cfg, line, row, totalh, shape)?;
+    print_columns_lines(f, buf, height, cfg, dimension, line, row, totalh, shape)?;
     Ok(())
 }
 
@@ -274,7 +274,7 @@ where
         let width = dims.get_width(col);
         let color = colors.get_color(pos);
         print_vertical_char(f, cfg, pos, 0, 1, shape.1)?;
-        print_single_line_column(f, cell.as_ref(), cfg, width, color, pos)?;
+        print_single_line_column(f, cell.as_ref(), cfg, width, color, pos, dims)?;
     }
 
     print_vertical_char(f, cfg, (row, shape.1), 0, 1, shape.1)?;
@@ -284,13 +284,14 @@ where
     Ok(())
 }
 
-fn print_single_line_column<F: Write, C: ANSIFmt>(
+fn print_single_line_column<F: Write, C: ANSIFmt, D: Dimension>(
     f: &mut F,
     text: &str,
     cfg: &SpannedConfig,
     width: usize,
     color: Option<&C>,
     pos: Position,
+    dims: &D,
 ) -> fmt::Result {
     let pos = pos.into();
     let pad = cfg.get_padding(pos);
@@ -301,12 +302,12 @@ fn print_single_line_column<F: Write, C: ANSIFmt>(
 
     let (text, text_width) = if fmt.horizontal_trim && !text.is_empty() {
         let text = string_trim(text);
-        let width = get_line_width(&text);
+        let width = dims.get_line_width(&text);
 
         (text, width)
     } else {
         let text = Cow::Borrowed(text);
-        let width = get_text_width(&text);
+        let width = text_width(&text, false, dims);
 
         (text, width)
     };
@@ -327,11 +328,12 @@ fn print_single_line_column<F: Write, C: ANSIFmt>(
 }
 
 #[allow(clippy::too_many_arguments)]
-fn print_columns_lines<T, F: Write, C: ANSIFmt>(
+fn print_columns_lines<T, F: Write, C: ANSIFmt, D: Dimension>(
     f: &mut F,
     buf: &mut [Cell<T, C>],
     height: usize,
     cfg: &SpannedConfig,
+    dims: &D,
     line: usize,
     row: usize,
     totalh: Option<usize>,
@@ -344,7 +346,7 @@ fn print_columns_lines<T, F: Write, C: ANSIFmt>(
 
         for (col, cell) in buf.iter_mut().enumerate() {
             print_vertical_char(f, cfg, (row, col), i, height, shape.1)?;
-            cell.display(f)?;
+            cell.display(f, dims)?;
         }
 
         print_vertical_char(f, cfg, (row, shape.1), i, height, shape.1)?;
@@ -377,7 +379,7 @@ fn collect_columns<'a, I, D, C>(
         let pos = (row, col);
         let width = dimension.get_width(col);
         let color = colors.get_color(pos);
-        Cell::new(cell, width, height, cfg, color, pos)
+        Cell::new(cell, width, height, cfg, color, pos, dimension)
     });
 
     buf.extend(iter);
@@ -536,7 +538,7 @@ fn print_split_line_spanned<S, F: Write, D: Dimension, C: ANSIFmt>(
             // so. we just need to use line from other cell.
 
             let (cell, _, _) = buf.get_mut(&col).unwrap();
-            cell.display(f)?;
+            cell.display(f, dimension)?;
 
             // We need to use a correct right split char.
             let original_row = closest_visible_row(cfg, pos).unwrap();
@@ -645,7 +647,7 @@ where
             };
 
             let color = colors.get_color(pos);
-            let cell = Cell::new(cell, width, height, cfg, color, pos);
+            let cell = Cell::new(cell, width, height, cfg, color, pos, dimension);
 
             buf.insert(col, (cell, rowspan, colspan));
         }
@@ -688,7 +690,7 @@ where
         };
 
         let color = colors.get_color(pos);
-        let cell = Cell::new(cell, width, height, cfg, color, pos);
+        let cell = Cell::new(cell, width, height, cfg, color, pos, dimension);
 
         buf.insert(col, (cell, rowspan, colspan));
     }
@@ -701,7 +703,7 @@ where
 
         for (&col, (cell, _, _)) in buf.iter_mut() {
             print_vertical_char(f, cfg, (row, col), cell_line, this_height, shape.1)?;
-            cell.display(f)?;
+            cell.display(f, dimension)?;
         }
 
         print_vertical_char(f, cfg, (row, shape.1), cell_line, this_height, shape.1)?;
@@ -774,13 +776,14 @@ impl<T, C> Cell<T, C>
 where
     T: AsRef<str>,
 {
-    fn new(
+    fn new<D: Dimension>(
         text: T,
         width: usize,
         height: usize,
         cfg: &SpannedConfig,
         color: Option<C>,
         pos: Position,
+        dims: &D,
     ) -> Cell<T, C> {
         let fmt = *cfg.get_formatting(pos.into());
         let pad = cfg.get_padding(pos.into());
@@ -803,7 +806,7 @@ where
 
         let mut indent_left = None;
         if !fmt.allow_lines_alignment {
-            let text_width = text_width(text.as_ref(), fmt.horizontal_trim);
+            let text_width = text_width(text.as_ref(), fmt.horizontal_trim, dims);
             let available = width - pad.left.size - pad.right.size;
             indent_left = Some(calculate_indent(alignh, text_width, available).0);
         }
@@ -832,7 +835,7 @@ impl<T, C> Cell<T, C>
 where
     C: ANSIFmt,
 {
-    fn display<F: Write>(&mut self, f: &mut F) -> fmt::Result {
+    fn display<F: Write, D: Dimension>(&mut self, f: &mut F, dims: &D) -> fmt::Result {
         if self.indent_top > 0 {
             self.indent_top -= 1;
             print_padding_n(f, &self.pad.top, self.pad_color.top.as_ref(), self.width)?;
@@ -854,7 +857,7 @@ where
             line
         };
 
-        let line_width = get_line_width(&line);
+        let line_width = dims.get_line_width(&line);
         let available_width = self.width - self.pad.left.size - self.pad.right.size;
 
         let (left, right) = if self.fmt.allow_lines_alignment {
@@ -1300,15 +1303,14 @@ fn count_empty_lines(cell: &str) -> (usize, usize, usize) {
     (len, top, bottom)
 }
 
-fn text_width(text: &str, trim: bool) -> usize {
-    if trim {
-        get_lines(text)
-            .map(|line| get_line_width(line.trim()))
-            .max()
-            .unwrap_or(0)
-    } else {
-        get_text_width(text)
-    }
+fn text_width<D: Dimension>(text: &str, trim: bool, dims: &D) -> usize {
+    get_lines(text)
+        .map(|line| match trim {
+            true => dims.get_line_width(line.trim()),
+            false => dims.get_line_width(&line),
+        })
+        .max()
+        .unwrap_or(0)
 }
 
 #[cfg(test)]
@@ -0,0 +1,345 @@
+//! The module contains a [`GridLayout`] structure,
+//! which describes where cells and borders of a rendered grid are placed.
+
+use core::{cmp, ops::Range};
+
+use crate::{
+    config::{spanned::SpannedConfig, Entity, Position},
+    dimension::Dimension,
+};
+
+/// A rectangle on a rendered grid.
+///
+/// Lines and columns are 0-based,
+/// columns are counted in a display width (not in bytes or chars).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rect {
+    /// A first line.
+    pub line: usize,
+    /// A first column.
+    pub column: usize,
+    /// A number of lines.
+    pub height: usize,
+    /// A number of columns.
+    pub width: usize,
+}
+
+impl Rect {
+    /// Creates a new rectangle.
+    pub const fn new(line: usize, column: usize, height: usize, width: usize) -> Self {
+        Self {
+            line,
+            column,
+            height,
+            width,
+        }
+    }
+
+    /// Returns a range of lines the rectangle covers.
+    pub const fn lines(&self) -> Range<usize> {
+        self.line..self.line + self.height
+    }
+
+    /// Returns a range of columns the rectangle covers.
+    pub const fn columns(&self) -> Range<usize> {
+        self.column..self.column + self.width
+    }
+
+    /// Verifies whether a given point is inside the rectangle.
+    pub const fn contains(&self, line: usize, column: usize) -> bool {
+        line >= self.line
+            && line < self.line + self.height
+            && column >= self.column
+            && column < self.column + self.width
+    }
+}
+
+/// A place of a cell on a rendered grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CellLayout {
+    /// A position of a cell.
+    pub position: Position,
+    /// A whole area of a cell between its borders, including spans and padding.
+    pub area: Rect,
+    /// An area of a cell text, which is the area without padding.
+    pub text: Rect,
+}
+
+/// A kind of a border segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderKind {
+    /// A part of a horizontal line over a column.
+    Horizontal,
+    /// A part of a vertical line next to a row.
+    Vertical,
+    /// A crossing of a horizontal and a vertical line.
+    Intersection,
+}
+
+/// A place of a border segment on a rendered grid.
+///
+/// The position is built out of a line index and a cell index.
+/// Horizontal line `i` is the line above row `i`, vertical line `i` is the line left to column `i`.
+///
+/// - For [`BorderKind::Horizontal`] it's (horizontal line, column).
+/// - For [`BorderKind::Vertical`] it's (row, vertical line).
+/// - For [`BorderKind::Intersection`] it's (horizontal line, vertical line).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BorderLayout {
+    /// A kind of a segment.
+    pub kind: BorderKind,
+    /// A position of a segment.
+    pub position: Position,
+    /// An area of a segment.
+    pub area: Rect,
+}
+
+/// A layout of a rendered grid.
+///
+/// It contains places of every visible cell and every border segment,
+/// so a rendered text can be mapped back to cells.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GridLayout {
+    cells: Vec<CellLayout>,
+    borders: Vec<BorderLayout>,
+}
+
+impl GridLayout {
+    /// Calculates a layout of a grid with a given shape (count rows, count columns).
+    pub fn new<D>(cfg: &SpannedConfig, dims: &D, shape: (usize, usize)) -> Self
+    where
+        D: Dimension,
+    {
+        let (count_rows, count_columns) = shape;
+        if count_rows == 0 || count_columns == 0 {
+            return Self::default();
+        }
+
+        let margin = cfg.get_margin();
+
+        let (hlines, rows) = calculate_offsets(
+            margin.top.size,
+            count_rows,
+            |row| cfg.has_horizontal(row, count_rows),
+            |row| dims.get_height(row),
+        );
+        let (vlines, columns) = calculate_offsets(
+            margin.left.size,
+            count_columns,
+            |col| cfg.has_vertical(col, count_columns),
+            |col| dims.get_width(col),
+        );
+
+        let owners = find_owners(cfg, shape);
+        let owner = |row: usize, col: usize| owners[row * count_columns + col];
+
+        let mut cells = Vec::new();
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                let pos = (row, col);
+                if owner(row, col) != pos {
+                    continue;
+                }
+
+                let (end_row, end_col) = get_span_end(cfg, pos, shape);
+                let (last_row, last_col) = (end_row - 1, end_col - 1);
+
+                let line = rows[row];
+                let column = columns[col];
+                let height = rows[last_row] + dims.get_height(last_row) - line;
+                let width = columns[last_col] + dims.get_width(last_col) - column;
+                let area = Rect::new(line, column, height, width);
+
+                let pad = cfg.get_padding(Entity::Cell(row, col));
+                let text_height = height.saturating_sub(pad.top.size + pad.bottom.size);
+                let text_width = width.saturating_sub(pad.left.size + pad.right.size);
+                let text = Rect::new(
+                    line + cmp::min(pad.top.size, height),
+                    column + cmp::min(pad.left.size, width),
+                    text_height,
+                    text_width,
+                );
+
+                cells.push(CellLayout {
+                    position: pos,
+                    area,
+                    text,
+                });
+            }
+        }
+
+        let mut borders = Vec::new();
+        for (hline, line) in hlines.iter().enumerate() {
+            let line = match line {
+                Some(line) => *line,
+                None => continue,
+            };
+
+            for (vline, column) in vlines.iter().enumerate() {
+                let column = match column {
+                    Some(column) => *column,
+                    None => continue,
+                };
+
+                let is_inside_cell = hline > 0
+                    && vline > 0
+                    && hline < count_rows
+                    && vline < count_columns
+                    && owner(hline - 1, vline - 1) == owner(hline, vline);
+                if is_inside_cell {
+                    continue;
+                }
+
+                borders.push(BorderLayout {
+                    kind: BorderKind::Intersection,
+                    position: (hline, vline),
+                    area: Rect::new(line, column, 1, 1),
+                });
+            }
+
+            for (col, &column) in columns.iter().enumerate() {
+                let is_inside_cell =
+                    hline > 0 && hline < count_rows && owner(hline - 1, col) == owner(hline, col);
+                if is_inside_cell {
+                    continue;
+                }
+
+                borders.push(BorderLayout {
+                    kind: BorderKind::Horizontal,
+                    position: (hline, col),
+                    area: Rect::new(line, column, 1, dims.get_width(col)),
+                });
+            }
+        }
+
+        for (vline, column) in vlines.iter().enumerate() {
+            let column = match column {
+                Some(column) => *column,
+                None => continue,
+            };
+
+            for (row, &line) in rows.iter().enumerate() {
+                let is_inside_cell = vline > 0
+                    && vline < count_columns
+                    && owner(row, vline - 1) == owner(row, vline);
+                if is_inside_cell {
+                    continue;
+                }
+
+                borders.push(BorderLayout {
+                    kind: BorderKind::Vertical,
+                    position: (row, vline),
+                    area: Rect::new(line, column, dims.get_height(row), 1),
+                });
+            }
+        }
+
+        Self { cells, borders }
+    }
+
+    /// Returns a list of visible cells, ordered by position.
+    pub fn cells(&self) -> &[CellLayout] {
+        &self.cells
+    }
+
+    /// Returns a list of border segments.
+    pub fn borders(&self) -> &[BorderLayout] {
+        &self.borders
+    }
+
+    /// Returns a layout of a visible cell.
+    pub fn get_cell(&self, pos: Position) -> Option<&CellLayout> {
+        self.cells.iter().find(|cell| cell.position == pos)
+    }
+
+    /// Returns a cell which area contains a given point.
+    ///
+    /// It returns [`None`] if the point is on a border, a margin or outside the grid.
+    pub fn find_cell(&self, line: usize, column: usize) -> Option<&CellLayout> {
+        self.cells
+            .iter()
+            .find(|cell| cell.area.contains(line, column))
+    }
+
+    /// Returns a border segment which contains a given point.
+    pub fn find_border(&self, line: usize, column: usize) -> Option<&BorderLayout> {
+        self.borders
+            .iter()
+            .find(|border| border.area.contains(line, column))
+    }
+}
+
+/// Returns offsets of lines and of cells along a single axis.
+fn calculate_offsets<L, S>(
+    start: usize,
+    count: usize,
+    has_line: L,
+    get_size: S,
+) -> (Vec<Option<usize>>, Vec<usize>)
+where
+    L: Fn(usize) -> bool,
+    S: Fn(usize) -> usize,
+{
+    let mut lines = Vec::with_capacity(count + 1);
+    let mut cells = Vec::with_capacity(count);
+
+    let mut offset = start;
+    for i in 0..count {
+        if has_line(i) {
+            lines.push(Some(offset));
+            offset += 1;
+        } else {
+            lines.push(None);
+        }
+
+        cells.push(offset);
+        offset += get_size(i);
+    }
+
+    lines.push(has_line(count).then_some(offset));
+
+    (lines, cells)
+}
+
+/// Returns a position of a cell which is shown at each position.
+///
+/// It's different from the position itself only when a cell is covered by a span.
+fn find_owners(cfg: &SpannedConfig, shape: (usize, usize)) -> Vec<Position> {
+    let (count_rows, count_columns) = shape;
+
+    let mut owners = Vec::with_capacity(count_rows * count_columns);
+    for row in 0..count_rows {
+        for col in 0..count_columns {
+            owners.push((row, col));
+        }
+    }
+
+    for row in 0..count_rows {
+        for col in 0..count_columns {
+            let pos = (row, col);
+            if !cfg.is_cell_visible(pos) || owners[row * count_columns + col] != pos {
+                continue;
+            }
+
+            let (end_row, end_col) = get_span_end(cfg, pos, shape);
+            for r in row..end_row {
+                for c in col..end_col {
+                    owners[r * count_columns + c] = pos;
+                }
+            }
+        }
+    }
+
+    owners
+}
+
+/// Returns an exclusive end of a cell region.
+fn get_span_end(cfg: &SpannedConfig, pos: Position, shape: (usize, usize)) -> Position {
+    let rows = cfg.get_row_span(pos).unwrap_or(1);
+    let cols = cfg.get_column_span(pos).unwrap_or(1);
+
+    (
+        cmp::min(pos.0 + rows, shape.0),
+        cmp::min(pos.1 + cols, shape.1),
+    )
+}
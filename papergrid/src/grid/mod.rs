@@ -5,5 +5,8 @@ pub mod compact;
 #[cfg(feature = "std")]
 pub mod iterable;
 
+#[cfg(feature = "std")]
+pub mod layout;
+
 #[cfg(feature = "std")]
 pub mod peekable;
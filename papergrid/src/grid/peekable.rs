@@ -16,6 +16,7 @@ use crate::{
     },
     config::{AlignmentHorizontal, AlignmentVertical, Entity, Indent, Position, Sides},
     dimension::Dimension,
+    grid::layout::GridLayout,
     records::{ExactRecords, PeekableRecords, Records},
     util::string::get_line_width,
 };
@@ -80,6 +81,39 @@ impl<R, G, D, C> PeekableGrid<R, G, D, C> {
         self.build(&mut buf).expect("It's guaranteed to never happen otherwise it's considered an stdlib error or impl error");
         buf
     }
+
+    /// Builds a table and returns its [`GridLayout`],
+    /// which is a place of each cell and each border segment in the output.
+    pub fn build_with_layout<F>(self, f: F) -> Result<GridLayout, fmt::Error>
+    where
+        R: Records + PeekableRecords + ExactRecords,
+        D: Dimension,
+        C: Colors,
+        G: Borrow<SpannedConfig>,
+        F: Write,
+    {
+        let shape = (self.records.count_rows(), self.records.count_columns());
+        let layout = GridLayout::new(self.config.borrow(), &self.dimension, shape);
+
+        self.build(f)?;
+
+        Ok(layout)
+    }
+
+    /// Builds a table into string and returns its [`GridLayout`].
+    ///
+    /// Notice that it consumes self.
+    pub fn to_string_with_layout(self) -> (String, GridLayout)
+    where
+        R: Records + PeekableRecords + ExactRecords,
+        D: Dimension,
+        G: Borrow<SpannedConfig>,
+        C: Colors,
+    {
+        let mut buf = String::new();
+        let layout = self.build_with_layout(&mut buf).expect("It's guaranteed to never happen otherwise it's considered an stdlib error or impl error");
+        (buf, layout)
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -130,6 +164,20 @@ fn has_padding_color(cfg: &SpannedConfig) -> bool {
     has_pad || cfg.has_padding_color()
 }
 
+/// Returns a width of a line after it was trimmed.
+///
+/// A line width is taken from records, so it's measured the same way a cell was,
+/// and only the removed whitespaces are measured here.
+fn get_trimmed_line_width<R>(records: &R, pos: Position, index: usize, trimmed: &str) -> usize
+where
+    R: PeekableRecords,
+{
+    let line = records.get_line(pos, index);
+    let removed = get_line_width(line).saturating_sub(get_line_width(trimmed));
+
+    records.get_line_width(pos, index).saturating_sub(removed)
+}
+
 mod grid_basic {
     use super::*;
 
@@ -390,7 +438,7 @@ mod grid_basic {
         let line = records.get_line(pos, index);
         let (line, line_width) = if cfg.formatting.horizontal_trim {
             let line = string_trim(line);
-            let width = get_line_width(&line);
+            let width = get_trimmed_line_width(records, pos, index, &line);
             (line, width)
         } else {
             let width = records.get_line_width(pos, index);
@@ -404,8 +452,7 @@ mod grid_basic {
 
         let cell_width = if cfg.formatting.horizontal_trim {
             (0..records.count_lines(pos))
-                .map(|i| records.get_line(pos, i))
-                .map(|line| get_line_width(line.trim()))
+                .map(|i| get_trimmed_line_width(records, pos, i, records.get_line(pos, i).trim()))
                 .max()
                 .unwrap_or_default()
         } else {
@@ -909,7 +956,7 @@ mod grid_not_spanned {
         let line = records.get_line(pos, index);
         let (line, line_width) = if cfg.formatting.horizontal_trim {
             let line = string_trim(line);
-            let width = get_line_width(&line);
+            let width = get_trimmed_line_width(records, pos, index, &line);
             (line, width)
         } else {
             let width = records.get_line_width(pos, index);
@@ -924,8 +971,7 @@ mod grid_not_spanned {
 
         let cell_width = if cfg.formatting.horizontal_trim {
             (0..records.count_lines(pos))
-                .map(|i| records.get_line(pos, i))
-                .map(|line| get_line_width(line.trim()))
+                .map(|i| get_trimmed_line_width(records, pos, i, records.get_line(pos, i).trim()))
                 .max()
                 .unwrap_or_default()
         } else {
@@ -1775,7 +1821,7 @@ mod grid_spanned {
         let line = records.get_line(pos, index);
         let (line, line_width) = if text_cfg.formatting.horizontal_trim {
             let line = string_trim(line);
-            let width = get_line_width(&line);
+            let width = get_trimmed_line_width(records, pos, index, &line);
             (line, width)
         } else {
             let width = records.get_line_width(pos, index);
@@ -1790,8 +1836,7 @@ mod grid_spanned {
 
         let cell_width = if text_cfg.formatting.horizontal_trim {
             (0..records.count_lines(pos))
-                .map(|i| records.get_line(pos, i))
-                .map(|line| get_line_width(line.trim()))
+                .map(|i| get_trimmed_line_width(records, pos, i, records.get_line(pos, i).trim()))
                 .max()
                 .unwrap_or_default()
         } else {
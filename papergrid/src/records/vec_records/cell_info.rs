@@ -3,7 +3,10 @@ use std::{borrow::Cow, cmp::max};
 
 use crate::{
     records::vec_records::Cell,
-    util::string::{self, count_lines, get_line_width, get_lines},
+    util::{
+        string::{count_lines, get_lines},
+        width::{UnicodeWidth, WidthMetric},
+    },
 };
 
 /// The struct is a [Cell] implementation which keeps width information pre allocated.
@@ -20,7 +23,16 @@ impl<S> Text<S> {
     where
         S: AsRef<str>,
     {
-        create_cell_info(text)
+        create_cell_info(text, &UnicodeWidth)
+    }
+
+    /// Creates a new instance of the structure measuring its width by a given metric.
+    pub fn with_metric<M>(text: S, metric: &M) -> Self
+    where
+        S: AsRef<str>,
+        M: WidthMetric,
+    {
+        create_cell_info(text, metric)
     }
 
     /// Creates a new instance of the structure with a single line.
@@ -138,7 +150,7 @@ impl<'a> StrWithWidth<'a> {
     }
 }
 
-fn create_cell_info<S: AsRef<str>>(text: S) -> Text<S> {
+fn create_cell_info<S: AsRef<str>, M: WidthMetric>(text: S, metric: &M) -> Text<S> {
     let mut info = Text {
         text,
         lines: vec![],
@@ -149,7 +161,7 @@ fn create_cell_info<S: AsRef<str>>(text: S) -> Text<S> {
     // We check if there's only 1 line in which case we don't allocate lines Vec
     let count_lines = count_lines(info.text.as_ref());
     if count_lines < 2 {
-        info.width = string::get_text_width(info.text.as_ref());
+        info.width = metric.text_width(info.text.as_ref());
         return info;
     }
 
@@ -171,7 +183,7 @@ fn create_cell_info<S: AsRef<str>>(text: S) -> Text<S> {
 
     info.lines = vec![StrWithWidth::new(Cow::Borrowed(""), 0); count_lines];
     for (line, i) in get_lines(text).zip(info.lines.iter_mut()) {
-        i.width = get_line_width(&line);
+        i.width = metric.line_width(&line);
         i.text = line;
         info.width = max(info.width, i.width);
     }
@@ -0,0 +1,308 @@
+//! The module contains a segmentation of a text into extended grapheme clusters.
+//!
+//! It follows the rules of [UAX #29] in a simplified form,
+//! which doesn't require full unicode property tables.
+//!
+//! [UAX #29]: https://www.unicode.org/reports/tr29/
+
+const ZWJ: char = '\u{200D}';
+
+/// Returns a length in bytes of a first grapheme cluster of a text.
+pub(crate) fn cluster_len(text: &str) -> usize {
+    let mut chars = text.char_indices();
+    let first = match chars.next() {
+        Some((_, c)) => c,
+        None => return 0,
+    };
+
+    let mut end = first.len_utf8();
+
+    // GB3, GB4
+    if first == '\r' {
+        if text[end..].starts_with('\n') {
+            end += 1;
+        }
+
+        return end;
+    }
+
+    if is_control(first) {
+        return end;
+    }
+
+    let mut prev = first;
+    let mut count_regional = usize::from(is_regional_indicator(first));
+    let mut has_pictographic = is_extended_pictographic(first);
+    let mut conjunct = if is_indic_consonant(first) {
+        Conjunct::Consonant
+    } else {
+        Conjunct::None
+    };
+
+    for (i, c) in chars {
+        let is_joined = if is_control(c) {
+            // GB5
+            false
+        } else if is_extend(c) || c == ZWJ || is_spacing_mark(c) {
+            // GB9, GB9a
+            true
+        } else if prev == ZWJ && has_pictographic && is_extended_pictographic(c) {
+            // GB11
+            true
+        } else if count_regional == 1 && is_regional_indicator(c) {
+            // GB12, GB13
+            true
+        } else if conjunct == Conjunct::Linked && is_indic_consonant(c) {
+            // GB9c
+            true
+        } else {
+            // GB6, GB7, GB8
+            is_hangul_joined(prev, c)
+        };
+
+        if !is_joined {
+            break;
+        }
+
+        if is_regional_indicator(c) {
+            count_regional += 1;
+        }
+
+        has_pictographic |= is_extended_pictographic(c);
+
+        conjunct = match conjunct {
+            _ if is_indic_consonant(c) => Conjunct::Consonant,
+            Conjunct::Consonant | Conjunct::Linked if is_indic_linker(c) => Conjunct::Linked,
+            Conjunct::Consonant | Conjunct::Linked if is_extend(c) || c == ZWJ => conjunct,
+            _ => Conjunct::None,
+        };
+
+        prev = c;
+        end = i + c.len_utf8();
+    }
+
+    end
+}
+
+/// A state of an Indic conjunct cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conjunct {
+    None,
+    Consonant,
+    Linked,
+}
+
+fn is_control(c: char) -> bool {
+    if c == ZWJ || c == '\u{200C}' {
+        return false;
+    }
+
+    c.is_control()
+        || matches!(
+            c,
+            '\u{00AD}'
+                | '\u{061C}'
+                | '\u{180E}'
+                | '\u{200B}'
+                | '\u{200E}'..='\u{200F}'
+                | '\u{2028}'..='\u{202E}'
+                | '\u{2060}'..='\u{206F}'
+                | '\u{FEFF}'
+                | '\u{FFF0}'..='\u{FFFB}'
+        )
+}
+
+fn is_extend(c: char) -> bool {
+    // Combining marks, variation selectors and tags have zero width,
+    // emoji modifiers are the only wide extending characters.
+    let is_zero_width = unicode_width::UnicodeWidthChar::width(c) == Some(0);
+
+    (is_zero_width && !is_control(c)) || matches!(c, '\u{1F3FB}'..='\u{1F3FF}')
+}
+
+fn is_spacing_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0903}'
+            | '\u{093B}'
+            | '\u{093E}'..='\u{0940}'
+            | '\u{0949}'..='\u{094C}'
+            | '\u{094E}'..='\u{094F}'
+            | '\u{0982}'..='\u{0983}'
+            | '\u{09BF}'..='\u{09C0}'
+            | '\u{09C7}'..='\u{09C8}'
+            | '\u{09CB}'..='\u{09CC}'
+            | '\u{0A03}'
+            | '\u{0A3E}'..='\u{0A40}'
+            | '\u{0A83}'
+            | '\u{0ABE}'..='\u{0AC0}'
+            | '\u{0AC9}'
+            | '\u{0ACB}'..='\u{0ACC}'
+            | '\u{0B02}'..='\u{0B03}'
+            | '\u{0B40}'
+            | '\u{0B47}'..='\u{0B48}'
+            | '\u{0B4B}'..='\u{0B4C}'
+            | '\u{0BBF}'
+            | '\u{0BC1}'..='\u{0BC2}'
+            | '\u{0BC6}'..='\u{0BC8}'
+            | '\u{0BCA}'..='\u{0BCC}'
+            | '\u{0C01}'..='\u{0C03}'
+            | '\u{0C41}'..='\u{0C44}'
+            | '\u{0C82}'..='\u{0C83}'
+            | '\u{0CBE}'
+            | '\u{0CC0}'..='\u{0CC1}'
+            | '\u{0CC3}'..='\u{0CC4}'
+            | '\u{0CC7}'..='\u{0CC8}'
+            | '\u{0CCA}'..='\u{0CCB}'
+            | '\u{0D02}'..='\u{0D03}'
+            | '\u{0D3F}'..='\u{0D40}'
+            | '\u{0D46}'..='\u{0D48}'
+            | '\u{0D4A}'..='\u{0D4C}'
+            | '\u{0D82}'..='\u{0D83}'
+            | '\u{0DD0}'..='\u{0DD1}'
+            | '\u{0DD8}'..='\u{0DDE}'
+            | '\u{0DF2}'..='\u{0DF3}'
+            | '\u{0E33}'
+            | '\u{0EB3}'
+            | '\u{102B}'..='\u{102C}'
+            | '\u{1031}'
+            | '\u{1038}'
+            | '\u{103B}'..='\u{103C}'
+            | '\u{1056}'..='\u{1057}'
+            | '\u{17B6}'
+            | '\u{17BE}'..='\u{17C5}'
+            | '\u{17C7}'..='\u{17C8}'
+    )
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c, '\u{1F1E6}'..='\u{1F1FF}')
+}
+
+fn is_extended_pictographic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00A9}'
+            | '\u{00AE}'
+            | '\u{203C}'
+            | '\u{2049}'
+            | '\u{2122}'
+            | '\u{2139}'
+            | '\u{2194}'..='\u{21AA}'
+            | '\u{231A}'..='\u{23FF}'
+            | '\u{24C2}'
+            | '\u{25AA}'..='\u{25FE}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2934}'..='\u{2935}'
+            | '\u{2B05}'..='\u{2B55}'
+            | '\u{3030}'
+            | '\u{303D}'
+            | '\u{3297}'
+            | '\u{3299}'
+            | '\u{1F000}'..='\u{1F1E5}'
+            | '\u{1F200}'..='\u{1F3FA}'
+            | '\u{1F400}'..='\u{1FAFF}'
+            | '\u{1FC00}'..='\u{1FFFD}'
+    )
+}
+
+/// Checks consonants of scripts which build conjuncts by a virama.
+fn is_indic_consonant(c: char) -> bool {
+    matches!(
+        c,
+        // Devanagari
+        '\u{0915}'..='\u{0939}'
+            | '\u{0958}'..='\u{095F}'
+            | '\u{0978}'..='\u{097F}'
+            // Bengali
+            | '\u{0995}'..='\u{09A8}'
+            | '\u{09AA}'..='\u{09B0}'
+            | '\u{09B2}'
+            | '\u{09B6}'..='\u{09B9}'
+            | '\u{09DC}'..='\u{09DD}'
+            | '\u{09DF}'
+            | '\u{09F0}'..='\u{09F1}'
+            // Gujarati
+            | '\u{0A95}'..='\u{0AA8}'
+            | '\u{0AAA}'..='\u{0AB0}'
+            | '\u{0AB2}'..='\u{0AB3}'
+            | '\u{0AB5}'..='\u{0AB9}'
+            | '\u{0AF9}'
+            // Oriya
+            | '\u{0B15}'..='\u{0B28}'
+            | '\u{0B2A}'..='\u{0B30}'
+            | '\u{0B32}'..='\u{0B33}'
+            | '\u{0B35}'..='\u{0B39}'
+            | '\u{0B5C}'..='\u{0B5D}'
+            | '\u{0B5F}'
+            | '\u{0B71}'
+            // Telugu
+            | '\u{0C15}'..='\u{0C28}'
+            | '\u{0C2A}'..='\u{0C39}'
+            | '\u{0C58}'..='\u{0C5A}'
+            // Malayalam
+            | '\u{0D15}'..='\u{0D3A}'
+    )
+}
+
+fn is_indic_linker(c: char) -> bool {
+    matches!(
+        c,
+        '\u{094D}' | '\u{09CD}' | '\u{0ACD}' | '\u{0B4D}' | '\u{0C4D}' | '\u{0D4D}'
+    )
+}
+
+fn is_hangul_joined(prev: char, c: char) -> bool {
+    let is_l = |c: char| matches!(c, '\u{1100}'..='\u{115F}' | '\u{A960}'..='\u{A97C}');
+    let is_v = |c: char| matches!(c, '\u{1160}'..='\u{11A7}' | '\u{D7B0}'..='\u{D7C6}');
+    let is_t = |c: char| matches!(c, '\u{11A8}'..='\u{11FF}' | '\u{D7CB}'..='\u{D7FB}');
+    // returns whether a syllable is LV (it has no trailing consonant) or LVT
+    let syllable = |c: char| match c {
+        '\u{AC00}'..='\u{D7A3}' => {
+            let trailing_index = (c as u32 - 0xAC00) % 28;
+            Some(trailing_index == 0)
+        }
+        _ => None,
+    };
+
+    let is_lv = syllable(prev) == Some(true);
+    let is_lvt = syllable(prev) == Some(false);
+
+    (is_l(prev) && (is_l(c) || is_v(c) || syllable(c).is_some()))
+        || ((is_lv || is_v(prev)) && (is_v(c) || is_t(c)))
+        || ((is_lvt || is_t(prev)) && is_t(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cluster_len;
+
+    fn clusters(text: &str) -> Vec<&str> {
+        let mut list = Vec::new();
+        let mut text = text;
+        while !text.is_empty() {
+            let (lhs, rhs) = text.split_at(cluster_len(text));
+            list.push(lhs);
+            text = rhs;
+        }
+
+        list
+    }
+
+    #[test]
+    fn cluster_test() {
+        assert_eq!(clusters("abc"), ["a", "b", "c"]);
+        assert_eq!(clusters("a\r\nb"), ["a", "\r\n", "b"]);
+        assert_eq!(clusters("e\u{301}x"), ["e\u{301}", "x"]);
+        assert_eq!(clusters("👨‍👩‍👧👍🏽"), ["👨‍👩‍👧", "👍🏽"]);
+        assert_eq!(clusters("🇺🇦🇩🇪🇫"), ["🇺🇦", "🇩🇪", "🇫"]);
+        assert_eq!(clusters("❤\u{FE0F}!"), ["❤\u{FE0F}", "!"]);
+        assert_eq!(clusters("नमस्ते"), ["न", "म", "स्ते"]);
+        assert_eq!(clusters("क्षि"), ["क्षि"]);
+        assert_eq!(
+            clusters("\u{1100}\u{1161}\u{11A8}가"),
+            ["\u{1100}\u{1161}\u{11A8}", "가"]
+        );
+        assert_eq!(clusters("a\u{200B}b"), ["a", "\u{200B}", "b"]);
+    }
+}
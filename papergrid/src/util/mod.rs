@@ -1,3 +1,7 @@
 //! A module contains utility functions which grid relay on.
 
 pub mod string;
+pub mod width;
+
+#[cfg(feature = "grapheme")]
+mod grapheme;
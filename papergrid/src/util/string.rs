@@ -44,8 +44,71 @@ pub fn get_char_width(c: char) -> usize {
 }
 
 /// Returns a string width (accouting all characters).
+///
+/// With `grapheme` feature on it's a sum of widths of [`get_graphemes`],
+/// so it's always in line with how a string is split.
 pub fn get_string_width(text: &str) -> usize {
-    unicode_width::UnicodeWidthStr::width(text)
+    #[cfg(not(feature = "grapheme"))]
+    {
+        unicode_width::UnicodeWidthStr::width(text)
+    }
+
+    #[cfg(feature = "grapheme")]
+    {
+        get_graphemes(text).map(get_grapheme_width).sum()
+    }
+}
+
+/// Returns a width of a grapheme (an item of [`get_graphemes`]).
+pub fn get_grapheme_width(grapheme: &str) -> usize {
+    #[cfg(not(feature = "grapheme"))]
+    {
+        grapheme.chars().map(get_char_width).sum()
+    }
+
+    #[cfg(feature = "grapheme")]
+    {
+        // unicode-width knows a width of emoji sequences, flags and etc.
+        unicode_width::UnicodeWidthStr::width(grapheme)
+    }
+}
+
+/// Returns an iterator over user-perceived characters of a string.
+///
+/// With `grapheme` feature on a string is split into extended grapheme clusters,
+/// so emoji sequences, flags and combining marks are kept together.
+/// Otherwise each `char` is considered to be a separate character.
+pub fn get_graphemes(text: &str) -> Graphemes<'_> {
+    Graphemes { text }
+}
+
+/// Iterator over graphemes of a string.
+///
+/// See [`get_graphemes`].
+#[derive(Debug, Clone)]
+pub struct Graphemes<'a> {
+    text: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.text.is_empty() {
+            return None;
+        }
+
+        #[cfg(not(feature = "grapheme"))]
+        let length = self.text.chars().next().map_or(0, char::len_utf8);
+
+        #[cfg(feature = "grapheme")]
+        let length = super::grapheme::cluster_len(self.text);
+
+        let (grapheme, rest) = self.text.split_at(length);
+        self.text = rest;
+
+        Some(grapheme)
+    }
 }
 
 /// Calculates a number of lines.
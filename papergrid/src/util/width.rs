@@ -0,0 +1,274 @@
+//! The module contains a [`WidthMetric`] trait and its implementations.
+//!
+//! A metric defines how many columns a text takes on a screen.
+//! By default [`UnicodeWidth`] is used everywhere,
+//! but a different one can be used to match a particular terminal or a fixed-pitch target.
+
+use crate::util::string::{
+    get_grapheme_width, get_graphemes, get_line_width, get_string_width, get_text_width,
+};
+
+/// A metric which is used to calculate a width of a text.
+///
+/// The only required method is [`WidthMetric::grapheme_width`],
+/// all other methods are built on top of it.
+pub trait WidthMetric {
+    /// Returns a width of a grapheme (an item of [`get_graphemes`]).
+    fn grapheme_width(&self, grapheme: &str) -> usize;
+
+    /// Returns a string width (accouting all characters).
+    fn string_width(&self, text: &str) -> usize {
+        get_graphemes(text).map(|g| self.grapheme_width(g)).sum()
+    }
+
+    /// Returns a line width.
+    ///
+    /// ANSI sequences are ignored when `ansi` feature is on.
+    fn line_width(&self, line: &str) -> usize {
+        #[cfg(not(feature = "ansi"))]
+        {
+            self.string_width(line)
+        }
+
+        #[cfg(feature = "ansi")]
+        {
+            ansitok::parse_ansi(line)
+                .filter(|e| e.kind() == ansitok::ElementKind::Text)
+                .map(|e| self.string_width(&line[e.start()..e.end()]))
+                .sum()
+        }
+    }
+
+    /// Returns a max line width of a text.
+    fn text_width(&self, text: &str) -> usize {
+        text.lines()
+            .map(|line| self.line_width(line))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl<M> WidthMetric for &M
+where
+    M: WidthMetric + ?Sized,
+{
+    fn grapheme_width(&self, grapheme: &str) -> usize {
+        M::grapheme_width(self, grapheme)
+    }
+
+    fn string_width(&self, text: &str) -> usize {
+        M::string_width(self, text)
+    }
+
+    fn line_width(&self, line: &str) -> usize {
+        M::line_width(self, line)
+    }
+
+    fn text_width(&self, text: &str) -> usize {
+        M::text_width(self, text)
+    }
+}
+
+/// A default metric based on [`unicode-width`].
+///
+/// East Asian Ambiguous characters are considered to be narrow.
+///
+/// [`unicode-width`]: https://docs.rs/unicode-width
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnicodeWidth;
+
+impl WidthMetric for UnicodeWidth {
+    fn grapheme_width(&self, grapheme: &str) -> usize {
+        get_grapheme_width(grapheme)
+    }
+
+    fn string_width(&self, text: &str) -> usize {
+        get_string_width(text)
+    }
+
+    fn line_width(&self, line: &str) -> usize {
+        get_line_width(line)
+    }
+
+    fn text_width(&self, text: &str) -> usize {
+        get_text_width(text)
+    }
+}
+
+/// A metric based on [`unicode-width`] which considers East Asian Ambiguous characters to be wide.
+///
+/// It matches terminals which are set to render ambiguous characters (like `±` or `○`)
+/// in 2 columns, which is common in CJK locales.
+///
+/// [`unicode-width`]: https://docs.rs/unicode-width
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AmbiguousWide;
+
+impl WidthMetric for AmbiguousWide {
+    fn grapheme_width(&self, grapheme: &str) -> usize {
+        #[cfg(not(feature = "grapheme"))]
+        {
+            grapheme
+                .chars()
+                .map(|c| unicode_width::UnicodeWidthChar::width_cjk(c).unwrap_or_default())
+                .sum()
+        }
+
+        #[cfg(feature = "grapheme")]
+        {
+            unicode_width::UnicodeWidthStr::width_cjk(grapheme)
+        }
+    }
+
+    fn string_width(&self, text: &str) -> usize {
+        #[cfg(not(feature = "grapheme"))]
+        {
+            unicode_width::UnicodeWidthStr::width_cjk(text)
+        }
+
+        #[cfg(feature = "grapheme")]
+        {
+            get_graphemes(text).map(|g| self.grapheme_width(g)).sum()
+        }
+    }
+}
+
+/// A metric which considers each `char` to take exactly 1 column.
+///
+/// It can be used for fixed-pitch targets where every character has the same width.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CharCount;
+
+impl WidthMetric for CharCount {
+    fn grapheme_width(&self, grapheme: &str) -> usize {
+        grapheme.chars().count()
+    }
+
+    fn string_width(&self, text: &str) -> usize {
+        text.chars().count()
+    }
+}
+
+/// A metric which uses a custom table of grapheme widths,
+/// falling back to a different metric for graphemes which are not in the table.
+///
+/// It can be used to match terminal-specific widths of emoji.
+///
+/// Notice that graphemes which consist of several `char`s
+/// (like emoji sequences or flags) are matched only with `grapheme` feature on.
+///
+/// # Example
+///
+/// ```
+/// use papergrid::util::width::{CustomWidth, WidthMetric};
+///
+/// let metric = CustomWidth::default().set("✅", 1);
+///
+/// assert_eq!(metric.line_width("✅ done"), 6);
+/// assert_eq!(metric.line_width("🚀 done"), 7);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomWidth<M = UnicodeWidth> {
+    widths: std::collections::HashMap<String, usize>,
+    metric: M,
+}
+
+#[cfg(feature = "std")]
+impl Default for CustomWidth {
+    fn default() -> Self {
+        Self::new(UnicodeWidth)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M> CustomWidth<M> {
+    /// Creates a new [`CustomWidth`] with a given fallback metric.
+    pub fn new(metric: M) -> Self {
+        Self {
+            widths: std::collections::HashMap::new(),
+            metric,
+        }
+    }
+
+    /// Sets a width of a grapheme.
+    pub fn set<S>(mut self, grapheme: S, width: usize) -> Self
+    where
+        S: Into<String>,
+    {
+        let _ = self.widths.insert(grapheme.into(), width);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M> WidthMetric for CustomWidth<M>
+where
+    M: WidthMetric,
+{
+    fn grapheme_width(&self, grapheme: &str) -> usize {
+        match self.widths.get(grapheme) {
+            Some(&width) => width,
+            None => self.metric.grapheme_width(grapheme),
+        }
+    }
+
+    fn string_width(&self, text: &str) -> usize {
+        if self.widths.is_empty() {
+            return self.metric.string_width(text);
+        }
+
+        get_graphemes(text).map(|g| self.grapheme_width(g)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_width_test() {
+        assert_eq!(UnicodeWidth.line_width("±1"), 2);
+        assert_eq!(UnicodeWidth.line_width("Rust 💕"), 7);
+        assert_eq!(UnicodeWidth.text_width("Go 👍\nC 😎"), 5);
+    }
+
+    #[test]
+    fn ambiguous_wide_test() {
+        assert_eq!(AmbiguousWide.line_width("±1"), 3);
+        assert_eq!(AmbiguousWide.line_width("○ ○"), 5);
+        assert_eq!(AmbiguousWide.line_width("abc"), 3);
+        assert_eq!(AmbiguousWide.line_width("日本"), 4);
+        assert_eq!(AmbiguousWide.text_width("±\n±±"), 4);
+    }
+
+    #[test]
+    fn char_count_test() {
+        assert_eq!(CharCount.line_width("Rust 💕"), 6);
+        assert_eq!(CharCount.line_width("日本"), 2);
+        assert_eq!(CharCount.text_width("a\nbcd"), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn custom_width_test() {
+        let metric = CustomWidth::default().set("💕", 1).set("x", 3);
+        assert_eq!(metric.line_width("Rust 💕"), 6);
+        assert_eq!(metric.line_width("xx"), 6);
+        assert_eq!(metric.line_width("日本"), 4);
+
+        let metric = CustomWidth::new(AmbiguousWide).set("💕", 1);
+        assert_eq!(metric.line_width("±💕"), 3);
+
+        let metric = CustomWidth::new(CharCount);
+        assert_eq!(metric.line_width("日本"), 2);
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn colored_width_test() {
+        use owo_colors::OwoColorize;
+        assert_eq!(AmbiguousWide.line_width(&"±1".red().to_string()), 3);
+        assert_eq!(CharCount.text_width(&"日本\n語".blue().to_string()), 2);
+    }
+}
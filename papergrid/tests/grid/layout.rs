@@ -0,0 +1,223 @@
+#![cfg(feature = "std")]
+
+use papergrid::{
+    colors::NoColors,
+    config::{spanned::SpannedConfig, Borders, Entity, Indent, Sides},
+    dimension::{spanned::SpannedGridDimension, Estimate},
+    grid::{
+        layout::{BorderKind, GridLayout, Rect},
+        peekable::PeekableGrid,
+    },
+    records::vec_records::{Text, VecRecords},
+};
+
+use crate::util::DEFAULT_BORDERS;
+
+fn render(data: &[&[&str]], cfg: &SpannedConfig) -> (String, GridLayout) {
+    let data = data
+        .iter()
+        .map(|row| row.iter().map(Text::new).collect())
+        .collect();
+    let records = VecRecords::new(data);
+
+    let mut dims = SpannedGridDimension::default();
+    dims.estimate(&records, cfg);
+
+    PeekableGrid::new(&records, cfg, &dims, NoColors).to_string_with_layout()
+}
+
+fn config() -> SpannedConfig {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_borders(DEFAULT_BORDERS);
+    cfg.set_padding(
+        Entity::Global,
+        Sides::new(
+            Indent::spaced(1),
+            Indent::spaced(1),
+            Indent::default(),
+            Indent::default(),
+        ),
+    );
+    cfg
+}
+
+fn crop(text: &str, rect: Rect) -> String {
+    text.lines()
+        .skip(rect.line)
+        .take(rect.height)
+        .map(|line| {
+            line.chars()
+                .skip(rect.column)
+                .take(rect.width)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn layout_cells() {
+    let (text, layout) = render(&[&["id", "name"], &["1", "papergrid"]], &config());
+
+    assert_eq!(
+        text,
+        "+----+-----------+\n\
+         | id | name      |\n\
+         +----+-----------+\n\
+         | 1  | papergrid |\n\
+         +----+-----------+"
+    );
+
+    let cells = layout
+        .cells()
+        .iter()
+        .map(|cell| (cell.position, cell.area, cell.text))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        cells,
+        [
+            ((0, 0), Rect::new(1, 1, 1, 4), Rect::new(1, 2, 1, 2)),
+            ((0, 1), Rect::new(1, 6, 1, 11), Rect::new(1, 7, 1, 9)),
+            ((1, 0), Rect::new(3, 1, 1, 4), Rect::new(3, 2, 1, 2)),
+            ((1, 1), Rect::new(3, 6, 1, 11), Rect::new(3, 7, 1, 9)),
+        ]
+    );
+
+    let cell = layout.get_cell((1, 1)).unwrap();
+    assert_eq!(crop(&text, cell.text), "papergrid");
+    assert_eq!(cell.area.lines(), 3..4);
+    assert_eq!(cell.area.columns(), 6..17);
+}
+
+#[test]
+fn layout_borders() {
+    let (text, layout) = render(&[&["id", "name"], &["1", "papergrid"]], &config());
+
+    let count = |kind| {
+        layout
+            .borders()
+            .iter()
+            .filter(|border| border.kind == kind)
+            .count()
+    };
+
+    assert_eq!(count(BorderKind::Intersection), 9);
+    assert_eq!(count(BorderKind::Horizontal), 6);
+    assert_eq!(count(BorderKind::Vertical), 6);
+
+    for border in layout.borders() {
+        let expected = match border.kind {
+            BorderKind::Horizontal => "-".repeat(border.area.width),
+            BorderKind::Vertical => "|".to_string(),
+            BorderKind::Intersection => "+".to_string(),
+        };
+
+        assert_eq!(crop(&text, border.area), expected, "{:?}", border);
+    }
+
+    let border = layout.find_border(2, 5).unwrap();
+    assert_eq!(border.kind, BorderKind::Intersection);
+    assert_eq!(border.position, (1, 1));
+
+    let border = layout.find_border(3, 0).unwrap();
+    assert_eq!(border.kind, BorderKind::Vertical);
+    assert_eq!(border.position, (1, 0));
+}
+
+#[test]
+fn layout_find_cell() {
+    let (_, layout) = render(&[&["id", "name"], &["1", "papergrid"]], &config());
+
+    assert_eq!(layout.find_cell(1, 1).unwrap().position, (0, 0));
+    assert_eq!(layout.find_cell(3, 16).unwrap().position, (1, 1));
+    assert_eq!(layout.find_cell(3, 5), None);
+    assert_eq!(layout.find_cell(2, 3), None);
+    assert_eq!(layout.find_cell(10, 10), None);
+}
+
+#[test]
+fn layout_spans() {
+    let mut cfg = config();
+    cfg.set_column_span((0, 0), 2);
+    cfg.set_row_span((1, 1), 2);
+
+    let (text, layout) = render(
+        &[&["header", "", ""], &["a", "b", "c"], &["d", "", "f"]],
+        &cfg,
+    );
+
+    assert_eq!(
+        text,
+        "+----+---+---+\n\
+         | header |   |\n\
+         +----+---+---+\n\
+         | a  | b | c |\n\
+         +----+   +---+\n\
+         | d  |   | f |\n\
+         +----+---+---+"
+    );
+
+    let positions = layout
+        .cells()
+        .iter()
+        .map(|cell| cell.position)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        positions,
+        [(0, 0), (0, 2), (1, 0), (1, 1), (1, 2), (2, 0), (2, 2)]
+    );
+
+    let header = layout.get_cell((0, 0)).unwrap();
+    assert_eq!(header.area, Rect::new(1, 1, 1, 8));
+    assert_eq!(crop(&text, header.text), "header");
+
+    let spanned = layout.get_cell((1, 1)).unwrap();
+    assert_eq!(spanned.area, Rect::new(3, 6, 3, 3));
+    assert_eq!(layout.find_cell(4, 7).unwrap().position, (1, 1));
+
+    assert!(layout
+        .borders()
+        .iter()
+        .all(|border| border.position != (0, 1) || border.kind != BorderKind::Vertical));
+    assert!(layout
+        .borders()
+        .iter()
+        .all(|border| border.position != (2, 1) || border.kind != BorderKind::Horizontal));
+}
+
+#[test]
+fn layout_margin_and_no_borders() {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_borders(Borders {
+        vertical: Some('|'),
+        ..Default::default()
+    });
+    cfg.set_margin(Sides::new(
+        Indent::spaced(2),
+        Indent::spaced(1),
+        Indent::spaced(1),
+        Indent::default(),
+    ));
+
+    let (text, layout) = render(&[&["a", "bb"], &["ccc", "d"]], &cfg);
+
+    assert_eq!(text, "         \n  a  |bb \n  ccc|d  ");
+    assert_eq!(layout.get_cell((0, 0)).unwrap().area, Rect::new(1, 2, 1, 3));
+    assert_eq!(layout.get_cell((1, 1)).unwrap().area, Rect::new(2, 6, 1, 2));
+    assert_eq!(layout.borders().len(), 2);
+    assert!(layout
+        .borders()
+        .iter()
+        .all(|border| border.kind == BorderKind::Vertical));
+}
+
+#[test]
+fn layout_empty() {
+    let (text, layout) = render(&[], &config());
+
+    assert_eq!(text, "");
+    assert_eq!(layout, GridLayout::default());
+    assert!(layout.cells().is_empty());
+    assert!(layout.borders().is_empty());
+}
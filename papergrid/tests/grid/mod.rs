@@ -1,7 +1,9 @@
 mod column_span;
 mod format_configuration;
+mod layout;
 mod peekable_grid;
 mod render;
 mod row_span;
 mod settings;
 mod styling;
+mod width_metric;
@@ -0,0 +1,158 @@
+#![cfg(feature = "std")]
+
+use papergrid::{
+    colors::NoColors,
+    config::{
+        compact::CompactConfig, spanned::SpannedConfig, AlignmentHorizontal, Entity, Formatting,
+    },
+    dimension::{compact::CompactGridDimension, spanned::SpannedGridDimension, Estimate},
+    grid::{compact::CompactGrid, iterable::Grid, peekable::PeekableGrid},
+    records::{
+        vec_records::{Text, VecRecords},
+        IterRecords,
+    },
+    util::width::AmbiguousWide,
+};
+
+use crate::util::DEFAULT_BORDERS;
+use testing_table::test_table;
+
+test_table!(
+    grid_metric,
+    grid(&[["±1", "x"], ["abc", "y"]], config()),
+    "+---+-+"
+    "|±1|x|"
+    "+---+-+"
+    "|abc|y|"
+    "+---+-+"
+);
+
+test_table!(
+    grid_metric_multiline,
+    grid(&[["±1\n±±", "x"], ["abc", "y"]], config()),
+    "+----+-+"
+    "|±1 |x|"
+    "|±±| |"
+    "+----+-+"
+    "|abc |y|"
+    "+----+-+"
+);
+
+test_table!(
+    grid_metric_alignment,
+    grid(&[["±1\n±±", "x"], ["abcdef", "y"]], aligned(AlignmentHorizontal::Right)),
+    "+------+-+"
+    "|  ±1 |x|"
+    "|  ±±| |"
+    "+------+-+"
+    "|abcdef|y|"
+    "+------+-+"
+);
+
+test_table!(
+    grid_metric_trim,
+    grid(&[["  ±1  ", "x"], ["abc", "y"]], trimmed()),
+    "+-------+-+"
+    "|±1    |x|"
+    "+-------+-+"
+    "|abc    |y|"
+    "+-------+-+"
+);
+
+test_table!(
+    grid_metric_trim_multiline,
+    grid(&[[" ±1 \n ±±±", "x"], ["abc", "y"]], trimmed()),
+    "+-------+-+"
+    "|±1    |x|"
+    "|±±± | |"
+    "+-------+-+"
+    "|abc    |y|"
+    "+-------+-+"
+);
+
+test_table!(
+    grid_metric_column_span,
+    grid(&[["±1", "x"], ["abcdef", "y"]], spanned()),
+    "+------+-+"
+    "|  ±1   |"
+    "+------+-+"
+    "|abcdef|y|"
+    "+------+-+"
+);
+
+test_table!(
+    compact_grid_metric,
+    compact_grid(&[["±1", "x"], ["abc", "y"]]),
+    "+-----+---+"
+    "| ±1 | x |"
+    "|-----+---|"
+    "| abc | y |"
+    "+-----+---+"
+);
+
+test_table!(
+    peekable_grid_metric_trim,
+    peekable_grid(&[[" ±1 ", "x"], ["abc", "y"]], trimmed()),
+    "+-----+-+"
+    "|±1  |x|"
+    "+-----+-+"
+    "|abc  |y|"
+    "+-----+-+"
+);
+
+fn grid<const N: usize>(data: &[[&str; N]], cfg: SpannedConfig) -> String {
+    let records = IterRecords::new(data, N, Some(data.len()));
+    let mut dims = SpannedGridDimension::with_metric(AmbiguousWide);
+    dims.estimate(records, &cfg);
+
+    Grid::new(records, dims, cfg, NoColors).to_string()
+}
+
+fn compact_grid<const N: usize>(data: &[[&str; N]]) -> String {
+    let records = IterRecords::new(data, N, None);
+    let cfg = CompactConfig::new().set_borders(DEFAULT_BORDERS);
+    let mut dims = CompactGridDimension::with_metric(AmbiguousWide);
+    dims.estimate(records, &cfg);
+
+    CompactGrid::new(records, &dims, &cfg).to_string()
+}
+
+fn peekable_grid<const N: usize>(data: &[[&str; N]], cfg: SpannedConfig) -> String {
+    let data = data
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|text| Text::with_metric(*text, &AmbiguousWide))
+                .collect()
+        })
+        .collect();
+    let records = VecRecords::new(data);
+    let mut dims = SpannedGridDimension::with_metric(AmbiguousWide);
+    dims.estimate(&records, &cfg);
+
+    PeekableGrid::new(&records, &cfg, &dims, NoColors).to_string()
+}
+
+fn config() -> SpannedConfig {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_borders(DEFAULT_BORDERS);
+    cfg
+}
+
+fn aligned(alignment: AlignmentHorizontal) -> SpannedConfig {
+    let mut cfg = config();
+    cfg.set_alignment_horizontal(Entity::Global, alignment);
+    cfg
+}
+
+fn trimmed() -> SpannedConfig {
+    let mut cfg = config();
+    cfg.set_formatting(Entity::Global, Formatting::new(true, false, false));
+    cfg
+}
+
+fn spanned() -> SpannedConfig {
+    let mut cfg = aligned(AlignmentHorizontal::Center);
+    cfg.set_column_span((0, 0), 2);
+    cfg
+}
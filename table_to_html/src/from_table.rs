@@ -0,0 +1,425 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use tabled::{
+    export::{escape_xml, parse_ansi, TextStyle},
+    grid::{
+        ansi::ANSIBuf,
+        colors::Colors,
+        config::{AlignmentHorizontal, AlignmentVertical, Entity, Position},
+        records::{ExactRecords, PeekableRecords, Records},
+    },
+    Table,
+};
+
+use crate::{
+    html::{Attribute, HtmlElement, HtmlValue},
+    HtmlTable,
+};
+
+/// A way a style of a [`Table`] is put into HTML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CssMode {
+    /// Put styles into `style` attributes of elements.
+    Inline,
+    /// Put styles into a `<style>` block and refer to them by classes.
+    StyleBlock,
+}
+
+type Css = BTreeMap<String, String>;
+
+impl HtmlTable {
+    /// Creates a HTML table out of a [`Table`] keeping its look.
+    ///
+    /// It carries over:
+    ///
+    /// - colors of cells (ANSI sequences are translated to CSS),
+    /// - alignment of cells,
+    /// - column and row spans,
+    /// - border characters and colors (translated to CSS border styles).
+    ///
+    /// A first row is put into `<thead>`.
+    /// If it's a [`Panel`] spread over all columns it becomes a `<caption>`,
+    /// and the next row is put into `<thead>`.
+    ///
+    /// A text is escaped, and its ANSI styled parts are put into `<span>`s styled the same way as cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use table_to_html::{CssMode, HtmlTable};
+    /// use tabled::{settings::{Color, Style}, Table};
+    ///
+    /// let mut table = Table::new(["a"]);
+    /// table.with(Style::blank()).with(Color::FG_RED);
+    ///
+    /// let html = HtmlTable::from_table(&table, CssMode::StyleBlock);
+    ///
+    /// assert_eq!(
+    ///     html.to_string(),
+    ///     concat!(
+    ///         "<style>\n",
+    ///         "    .tabled-0 {\n",
+    ///         "      border-bottom: none;\n",
+    ///         "      border-left: none;\n",
+    ///         "      border-right: none;\n",
+    ///         "      border-top: none;\n",
+    ///         "      color: #cd0000;\n",
+    ///         "      text-align: left;\n",
+    ///         "    }\n",
+    ///         "    .tabled-1 {\n",
+    ///         "      border-bottom: none;\n",
+    ///         "      border-left: none;\n",
+    ///         "      border-right: none;\n",
+    ///         "      border-top: none;\n",
+    ///         "      color: #cd0000;\n",
+    ///         "    }\n",
+    ///         "    table {\n",
+    ///         "      border-collapse: collapse;\n",
+    ///         "    }\n",
+    ///         "</style>\n",
+    ///         "<table>\n",
+    ///         "    <thead>\n",
+    ///         "        <tr>\n",
+    ///         "            <th class=\"tabled-0\">\n",
+    ///         "                <div>\n",
+    ///         "                    <p>\n",
+    ///         "                        &amp;str\n",
+    ///         "                    </p>\n",
+    ///         "                </div>\n",
+    ///         "            </th>\n",
+    ///         "        </tr>\n",
+    ///         "    </thead>\n",
+    ///         "    <tbody>\n",
+    ///         "        <tr>\n",
+    ///         "            <td class=\"tabled-1\">\n",
+    ///         "                <div>\n",
+    ///         "                    <p>\n",
+    ///         "                        a\n",
+    ///         "                    </p>\n",
+    ///         "                </div>\n",
+    ///         "            </td>\n",
+    ///         "        </tr>\n",
+    ///         "    </tbody>\n",
+    ///         "</table>",
+    ///     ),
+    /// );
+    /// ```
+    ///
+    /// [`Panel`]: tabled::settings::Panel
+    pub fn from_table(table: &Table, mode: CssMode) -> Self {
+        let mut classes = Classes::default();
+        let mut style = |css: Css| -> Vec<Attribute> {
+            if css.is_empty() {
+                return Vec::new();
+            }
+
+            match mode {
+                CssMode::Inline => vec![Attribute::new("style", inline_css(&css))],
+                CssMode::StyleBlock => vec![Attribute::new("class", classes.get(css))],
+            }
+        };
+
+        let records = table.get_records();
+        let cfg = table.get_config();
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let mut first_row = 0;
+        let mut elements = Vec::new();
+
+        let is_panel = count_rows > 1
+            && count_columns > 1
+            && cfg.get_column_span((0, 0)).unwrap_or(1) >= count_columns;
+        if is_panel {
+            let css = cell_css(table, (0, 0), false);
+            let attrs = style(css);
+            let text = build_lines(records.get_text((0, 0)), &mut style).join("\n");
+            elements.push(HtmlElement::new(
+                "caption",
+                attrs,
+                Some(HtmlValue::Content(text)),
+            ));
+
+            first_row = 1;
+        }
+
+        let mut rows = Vec::new();
+        for row in first_row..count_rows {
+            let is_header = row == first_row;
+            let tag = if is_header { "th" } else { "td" };
+
+            let mut cells = Vec::new();
+            for col in 0..count_columns {
+                let pos = (row, col);
+                if !cfg.is_cell_visible(pos) {
+                    continue;
+                }
+
+                let mut attrs = style(cell_css(table, pos, is_header));
+                if let Some(span) = cfg.get_column_span(pos).filter(|&span| span > 1) {
+                    attrs.push(Attribute::new("colspan", span.to_string()));
+                }
+                if let Some(span) = cfg.get_row_span(pos).filter(|&span| span > 1) {
+                    attrs.push(Attribute::new("rowspan", span.to_string()));
+                }
+
+                let lines = build_lines(records.get_text(pos), &mut style);
+                cells.push(HtmlElement::new(tag, attrs, Some(build_content(lines))));
+            }
+
+            let tr = HtmlElement::new("tr", vec![], Some(HtmlValue::Elements(cells)));
+            rows.push((is_header, tr));
+        }
+
+        let mut rows = rows.into_iter().peekable();
+        if let Some((true, _)) = rows.peek() {
+            let (_, header) = rows.next().expect("checked");
+            elements.push(HtmlElement::new(
+                "thead",
+                vec![],
+                Some(HtmlValue::Elements(vec![header])),
+            ));
+        }
+
+        let body = rows.map(|(_, row)| row).collect();
+        elements.push(HtmlElement::new(
+            "tbody",
+            vec![],
+            Some(HtmlValue::Elements(body)),
+        ));
+
+        let mut table_css = Css::new();
+        let _ = table_css.insert(String::from("border-collapse"), String::from("collapse"));
+
+        let mut css = BTreeMap::new();
+        let table_attrs = match mode {
+            CssMode::Inline => vec![Attribute::new("style", inline_css(&table_css))],
+            CssMode::StyleBlock => {
+                let _ = css.insert(String::from("table"), table_css);
+                for (class, (_, declarations)) in classes.list.into_iter().enumerate() {
+                    let _ = css.insert(format!(".{}", class_name(class)), declarations);
+                }
+
+                Vec::new()
+            }
+        };
+
+        Self {
+            table: HtmlElement::new("table", table_attrs, Some(HtmlValue::Elements(elements))),
+            css,
+        }
+    }
+}
+
+impl From<&Table> for HtmlTable {
+    fn from(table: &Table) -> Self {
+        Self::from_table(table, CssMode::Inline)
+    }
+}
+
+/// A list of unique styles.
+#[derive(Debug, Default)]
+struct Classes {
+    list: Vec<(String, Css)>,
+}
+
+impl Classes {
+    fn get(&mut self, css: Css) -> String {
+        let key = inline_css(&css);
+        if let Some(i) = self.list.iter().position(|(k, _)| *k == key) {
+            return class_name(i);
+        }
+
+        self.list.push((key, css));
+        class_name(self.list.len() - 1)
+    }
+}
+
+fn class_name(i: usize) -> String {
+    format!("tabled-{}", i)
+}
+
+fn inline_css(css: &Css) -> String {
+    css.iter()
+        .map(|(key, value)| format!("{}: {};", key, value))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn build_content(lines: Vec<String>) -> HtmlValue {
+    HtmlValue::Elements(vec![HtmlElement::new(
+        "div",
+        vec![],
+        Some(HtmlValue::Elements(
+            lines
+                .into_iter()
+                .map(HtmlValue::Content)
+                .map(|content| HtmlElement::new("p", vec![], Some(content)))
+                .collect(),
+        )),
+    )])
+}
+
+/// Splits a text into escaped lines, where ANSI styled parts are put into `<span>`s.
+///
+/// The spans are kept inline, so no whitespace is added in between.
+fn build_lines<F>(text: &str, style: &mut F) -> Vec<String>
+where
+    F: FnMut(Css) -> Vec<Attribute>,
+{
+    let mut lines = vec![String::new()];
+    for run in parse_ansi(text) {
+        let mut attrs = None;
+        for (i, part) in run.text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(String::new());
+            }
+
+            if part.is_empty() {
+                continue;
+            }
+
+            let attrs = attrs.get_or_insert_with(|| style(text_css(&run.style)));
+            let line = lines.last_mut().expect("not empty");
+            if attrs.is_empty() {
+                line.push_str(&escape_xml(part));
+                continue;
+            }
+
+            line.push_str("<span");
+            for attr in attrs.iter() {
+                let _ = write!(line, " {}={:?}", attr.key(), attr.value());
+            }
+            let _ = write!(line, ">{}</span>", escape_xml(part));
+        }
+    }
+
+    lines
+}
+
+fn cell_css(table: &Table, pos: Position, is_header: bool) -> Css {
+    let cfg = table.get_config();
+    let count_rows = table.count_rows();
+    let count_columns = table.count_columns();
+    let entity = Entity::Cell(pos.0, pos.1);
+
+    let mut css = Css::new();
+
+    // a header is centered by browsers while a table cell is on the left
+    let alignment = *cfg.get_alignment_horizontal(entity);
+    if is_header || alignment != AlignmentHorizontal::Left {
+        let value = match alignment {
+            AlignmentHorizontal::Left => "left",
+            AlignmentHorizontal::Center => "center",
+            AlignmentHorizontal::Right => "right",
+        };
+        let _ = css.insert(String::from("text-align"), String::from(value));
+    }
+
+    match cfg.get_alignment_vertical(entity) {
+        AlignmentVertical::Top => {}
+        AlignmentVertical::Center => {
+            let _ = css.insert(String::from("vertical-align"), String::from("middle"));
+        }
+        AlignmentVertical::Bottom => {
+            let _ = css.insert(String::from("vertical-align"), String::from("bottom"));
+        }
+    }
+
+    if let Some(color) = cfg.get_colors().get_color(pos) {
+        css.extend(ansi_css(color, "color", "background-color"));
+    }
+
+    let end_row = pos.0 + cfg.get_row_span(pos).unwrap_or(1).max(1);
+    let end_col = pos.1 + cfg.get_column_span(pos).unwrap_or(1).max(1);
+    let horizontal = |row: usize| {
+        let c = cfg.get_horizontal((row, pos.1), count_rows);
+        let color = cfg.get_horizontal_color((row, pos.1), count_rows);
+        border_css(c, color)
+    };
+    let vertical = |col: usize| {
+        let c = cfg.get_vertical((pos.0, col), count_columns);
+        let color = cfg.get_vertical_color((pos.0, col), count_columns);
+        border_css(c, color)
+    };
+
+    let _ = css.insert(String::from("border-top"), horizontal(pos.0));
+    let _ = css.insert(String::from("border-bottom"), horizontal(end_row));
+    let _ = css.insert(String::from("border-left"), vertical(pos.1));
+    let _ = css.insert(String::from("border-right"), vertical(end_col));
+
+    css
+}
+
+/// Translates a border character into a CSS border.
+fn border_css(c: Option<char>, color: Option<&ANSIBuf>) -> String {
+    let style = match c {
+        None | Some(' ') => return String::from("none"),
+        Some('═' | '║' | '╔' | '╗' | '╚' | '╝' | '╠' | '╣' | '╦' | '╩' | '╬') => {
+            "3px double"
+        }
+        Some('━' | '┃' | '┏' | '┓' | '┗' | '┛' | '┣' | '┫' | '┳' | '┻' | '╋') => {
+            "2px solid"
+        }
+        Some('┈' | '┉' | '┊' | '┋' | '.' | ':' | '·') => "1px dotted",
+        Some('┄' | '┅' | '┆' | '┇' | '╌' | '╍' | '╎' | '╏' | '~') => "1px dashed",
+        Some(_) => "1px solid",
+    };
+
+    let color = color
+        .map(|color| ansi_css(color, "color", "background-color"))
+        .and_then(|css| css.get("color").cloned());
+
+    match color {
+        Some(color) => format!("{} {}", style, color),
+        None => String::from(style),
+    }
+}
+
+/// Translates an ANSI color into CSS declarations.
+fn ansi_css(color: &ANSIBuf, fg_key: &str, bg_key: &str) -> Css {
+    let style = TextStyle::from_ansi(color.get_prefix());
+    style_css(&style, fg_key, bg_key)
+}
+
+/// Translates a style of a text into CSS declarations.
+fn text_css(style: &TextStyle) -> Css {
+    style_css(style, "color", "background-color")
+}
+
+/// Translates a [`TextStyle`] into CSS declarations.
+fn style_css(style: &TextStyle, fg_key: &str, bg_key: &str) -> Css {
+    let mut css = Css::new();
+    let mut set = |key: &str, value: String| {
+        let _ = css.insert(key.to_owned(), value);
+    };
+
+    if let Some(color) = style.fg {
+        set(fg_key, color.to_hex());
+    }
+
+    if let Some(color) = style.bg {
+        set(bg_key, color.to_hex());
+    }
+
+    if style.bold {
+        set("font-weight", String::from("bold"));
+    }
+
+    if style.dim {
+        set("opacity", String::from("0.5"));
+    }
+
+    if style.italic {
+        set("font-style", String::from("italic"));
+    }
+
+    match (style.underline, style.strikethrough) {
+        (true, true) => set("text-decoration", String::from("underline line-through")),
+        (true, false) => set("text-decoration", String::from("underline")),
+        (false, true) => set("text-decoration", String::from("line-through")),
+        (false, false) => {}
+    }
+
+    css
+}
@@ -380,6 +380,8 @@
 
 pub mod html;
 
+mod from_table;
+
 use std::{
     collections::BTreeMap,
     fmt::{Display, Write},
@@ -401,6 +403,8 @@ pub type Margin = Sides<usize>;
 pub use tabled::grid::config::{Entity, Position};
 pub use tabled::settings::Alignment;
 
+pub use from_table::CssMode;
+
 /// The structure represents an HTML `<table>`.
 #[derive(Debug, Clone)]
 pub struct HtmlTable {
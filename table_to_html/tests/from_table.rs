@@ -0,0 +1,307 @@
+use table_to_html::{CssMode, HtmlTable};
+
+use tabled::{
+    settings::{object::Cell, Alignment, Color, Panel, Span, Style},
+    Table,
+};
+use testing_table::test_table;
+
+test_table!(
+    from_table_inline_borders,
+    {
+        let mut table = Table::new([("<a>", 1)]);
+        table.with(Style::modern());
+        HtmlTable::from(&table)
+    },
+    "<table style=\"border-collapse: collapse;\">"
+    "    <thead>"
+    "        <tr>"
+    "            <th style=\"border-bottom: 1px solid; border-left: 1px solid; border-right: 1px solid; border-top: 1px solid; text-align: left;\">"
+    "                <div>"
+    "                    <p>"
+    "                        &amp;str"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <th style=\"border-bottom: 1px solid; border-left: 1px solid; border-right: 1px solid; border-top: 1px solid; text-align: left;\">"
+    "                <div>"
+    "                    <p>"
+    "                        i32"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td style=\"border-bottom: 1px solid; border-left: 1px solid; border-right: 1px solid; border-top: 1px solid;\">"
+    "                <div>"
+    "                    <p>"
+    "                        &lt;a&gt;"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td style=\"border-bottom: 1px solid; border-left: 1px solid; border-right: 1px solid; border-top: 1px solid;\">"
+    "                <div>"
+    "                    <p>"
+    "                        1"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    from_table_panel_caption_and_colspan,
+    {
+        let mut table = Table::new([("a", 1), ("b", 2)]);
+        table
+            .with(Style::empty())
+            .with(Panel::header("Title"))
+            .modify(Cell::new(2, 0), Span::column(2))
+            .modify(Cell::new(2, 0), Alignment::right());
+        HtmlTable::from(&table)
+    },
+    "<table style=\"border-collapse: collapse;\">"
+    "    <caption style=\"border-bottom: none; border-left: none; border-right: none; border-top: none;\">"
+    "        Title"
+    "    </caption>"
+    "    <thead>"
+    "        <tr>"
+    "            <th style=\"border-bottom: none; border-left: none; border-right: none; border-top: none; text-align: left;\">"
+    "                <div>"
+    "                    <p>"
+    "                        &amp;str"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <th style=\"border-bottom: none; border-left: none; border-right: none; border-top: none; text-align: left;\">"
+    "                <div>"
+    "                    <p>"
+    "                        i32"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td style=\"border-bottom: none; border-left: none; border-right: none; border-top: none; text-align: right;\" colspan=\"2\">"
+    "                <div>"
+    "                    <p>"
+    "                        a"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td style=\"border-bottom: none; border-left: none; border-right: none; border-top: none;\">"
+    "                <div>"
+    "                    <p>"
+    "                        b"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td style=\"border-bottom: none; border-left: none; border-right: none; border-top: none;\">"
+    "                <div>"
+    "                    <p>"
+    "                        2"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    from_table_style_block_colors_and_rowspan,
+    {
+        let mut table = Table::new([("a", 1), ("b", 2)]);
+        table
+            .with(Style::extended())
+            .modify(Cell::new(1, 0), Span::row(2))
+            .modify(Cell::new(1, 1), Color::BG_BLUE | Color::BOLD)
+            .modify(Cell::new(2, 1), Color::rgb_fg(1, 2, 3));
+        HtmlTable::from_table(&table, CssMode::StyleBlock)
+    },
+    "<style>"
+    "    .tabled-0 {"
+    "      border-bottom: 3px double;"
+    "      border-left: 3px double;"
+    "      border-right: 3px double;"
+    "      border-top: 3px double;"
+    "      text-align: left;"
+    "    }"
+    "    .tabled-1 {"
+    "      border-bottom: 3px double;"
+    "      border-left: 3px double;"
+    "      border-right: 3px double;"
+    "      border-top: 3px double;"
+    "    }"
+    "    .tabled-2 {"
+    "      background-color: #0000ee;"
+    "      border-bottom: 3px double;"
+    "      border-left: 3px double;"
+    "      border-right: 3px double;"
+    "      border-top: 3px double;"
+    "      font-weight: bold;"
+    "    }"
+    "    .tabled-3 {"
+    "      border-bottom: 3px double;"
+    "      border-left: 3px double;"
+    "      border-right: 3px double;"
+    "      border-top: 3px double;"
+    "      color: #010203;"
+    "    }"
+    "    table {"
+    "      border-collapse: collapse;"
+    "    }"
+    "</style>"
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th class=\"tabled-0\">"
+    "                <div>"
+    "                    <p>"
+    "                        &amp;str"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "            <th class=\"tabled-0\">"
+    "                <div>"
+    "                    <p>"
+    "                        i32"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td class=\"tabled-1\" rowspan=\"2\">"
+    "                <div>"
+    "                    <p>"
+    "                        a"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "            <td class=\"tabled-2\">"
+    "                <div>"
+    "                    <p>"
+    "                        1"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "        <tr>"
+    "            <td class=\"tabled-3\">"
+    "                <div>"
+    "                    <p>"
+    "                        2"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    from_table_ansi_text_inline,
+    {
+        let mut table = Table::new(["\u{1b}[31m<red>\u{1b}[0m and \u{1b}[1;4mbold\nnext\u{1b}[0m"]);
+        table.with(Style::empty());
+        HtmlTable::from(&table)
+    },
+    "<table style=\"border-collapse: collapse;\">"
+    "    <thead>"
+    "        <tr>"
+    "            <th style=\"border-bottom: none; border-left: none; border-right: none; border-top: none; text-align: left;\">"
+    "                <div>"
+    "                    <p>"
+    "                        &amp;str"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td style=\"border-bottom: none; border-left: none; border-right: none; border-top: none;\">"
+    "                <div>"
+    "                    <p>"
+    "                        <span style=\"color: #cd0000;\">&lt;red&gt;</span> and <span style=\"font-weight: bold; text-decoration: underline;\">bold</span>"
+    "                    </p>"
+    "                    <p>"
+    "                        <span style=\"font-weight: bold; text-decoration: underline;\">next</span>"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
+
+test_table!(
+    from_table_ansi_text_style_block,
+    {
+        let mut table = Table::new(["\u{1b}[31m<red>\u{1b}[0m and \u{1b}[1;4mbold\nnext\u{1b}[0m"]);
+        table.with(Style::empty());
+        HtmlTable::from_table(&table, CssMode::StyleBlock)
+    },
+    "<style>"
+    "    .tabled-0 {"
+    "      border-bottom: none;"
+    "      border-left: none;"
+    "      border-right: none;"
+    "      border-top: none;"
+    "      text-align: left;"
+    "    }"
+    "    .tabled-1 {"
+    "      border-bottom: none;"
+    "      border-left: none;"
+    "      border-right: none;"
+    "      border-top: none;"
+    "    }"
+    "    .tabled-2 {"
+    "      color: #cd0000;"
+    "    }"
+    "    .tabled-3 {"
+    "      font-weight: bold;"
+    "      text-decoration: underline;"
+    "    }"
+    "    table {"
+    "      border-collapse: collapse;"
+    "    }"
+    "</style>"
+    "<table>"
+    "    <thead>"
+    "        <tr>"
+    "            <th class=\"tabled-0\">"
+    "                <div>"
+    "                    <p>"
+    "                        &amp;str"
+    "                    </p>"
+    "                </div>"
+    "            </th>"
+    "        </tr>"
+    "    </thead>"
+    "    <tbody>"
+    "        <tr>"
+    "            <td class=\"tabled-1\">"
+    "                <div>"
+    "                    <p>"
+    "                        <span class=\"tabled-2\">&lt;red&gt;</span> and <span class=\"tabled-3\">bold</span>"
+    "                    </p>"
+    "                    <p>"
+    "                        <span class=\"tabled-3\">next</span>"
+    "                    </p>"
+    "                </div>"
+    "            </td>"
+    "        </tr>"
+    "    </tbody>"
+    "</table>"
+);
@@ -0,0 +1,28 @@
+//! This example demonstrates using [`LatexTable`] to convert a [`Table`]
+//! into a [LaTeX](https://www.latex-project.org/) `tabular` environment.
+//!
+//! Note how the table settings are carried over:
+//!   Alignment of columns becomes a column specification
+//!   Horizontal lines of a style become `booktabs` rules
+//!   Column and Row spans become `\multicolumn` and `\multirow`
+
+use table_to_latex::LatexTable;
+use tabled::{
+    settings::{object::Columns, Alignment, Style},
+    Table,
+};
+
+fn main() {
+    let data = [
+        ("C", "Dennis Ritchie", 1972),
+        ("Rust", "Graydon Hoare", 2010),
+        ("Go", "Rob Pike", 2009),
+    ];
+
+    let mut table = Table::new(data);
+    table
+        .with(Style::markdown())
+        .modify(Columns::single(2), Alignment::right());
+
+    println!("{}", LatexTable::from(&table))
+}
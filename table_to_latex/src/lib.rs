@@ -0,0 +1,483 @@
+#![deny(unused_must_use)]
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![allow(clippy::uninlined_format_args)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/zhiburt/tabled/86ac146e532ce9f7626608d7fd05072123603a2e/assets/tabled-gear.svg"
+)]
+
+//! # table_to_latex
+//!
+//! The library provides a interface to build a LaTeX table (`tabular` environment).
+//!
+//! ## Example building a table from iterator
+//!
+//! ```rust
+//! use table_to_latex::LatexTable;
+//!
+//! let data = vec![
+//!     vec!["name", "based on", "size"],
+//!     vec!["Debian", "", "100%"],
+//!     vec!["Manjaro", "Arch", "50%"],
+//! ];
+//!
+//! let table = LatexTable::with_header(data);
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "\\begin{tabular}{lll}\n",
+//!         "\\toprule\n",
+//!         "name & based on & size \\\\\n",
+//!         "\\midrule\n",
+//!         "Debian &  & 100\\% \\\\\n",
+//!         "Manjaro & Arch & 50\\% \\\\\n",
+//!         "\\bottomrule\n",
+//!         "\\end{tabular}",
+//!     ),
+//! )
+//! ```
+//!
+//! ## Example building a table from [`Table`].
+//!
+//! Alignment, spans and horizontal lines of a [`Style`] are taken from a table.
+//!
+//! ```rust
+//! use table_to_latex::LatexTable;
+//! use tabled::{
+//!     settings::{object::Columns, Alignment, Span, Style},
+//!     Table,
+//! };
+//!
+//! let mut table = Table::new([("C", 1972), ("Rust", 2010)]);
+//! table
+//!     .with(Style::psql())
+//!     .modify(Columns::single(1), Alignment::right())
+//!     .modify((2, 0), Span::column(2));
+//!
+//! assert_eq!(
+//!     LatexTable::from(&table).to_string(),
+//!     concat!(
+//!         "\\begin{tabular}{lr}\n",
+//!         "\\&str & i32 \\\\\n",
+//!         "\\midrule\n",
+//!         "C & 1972 \\\\\n",
+//!         "\\multicolumn{2}{l}{Rust} \\\\\n",
+//!         "\\end{tabular}",
+//!     ),
+//! )
+//! ```
+//!
+//! Rules come from the `booktabs` package and row spans from the `multirow` package,
+//! so they must be loaded in a document.
+//! [`LatexTable::set_booktabs`] can be used to draw lines with `\hline` instead.
+//!
+//! [`Table`]: tabled::Table
+//! [`Style`]: tabled::settings::Style
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::{self, Display},
+};
+
+use tabled::{
+    grid::{
+        config::{AlignmentHorizontal, Entity},
+        records::{ExactRecords, PeekableRecords, Records},
+        util::string::get_lines,
+    },
+    Table,
+};
+
+pub use tabled::grid::config::Position;
+pub use tabled::settings::Alignment;
+
+/// The structure represents a LaTeX `tabular` environment.
+#[derive(Debug, Clone)]
+pub struct LatexTable {
+    data: Vec<Vec<String>>,
+    count_columns: usize,
+    alignments: Vec<AlignmentHorizontal>,
+    column_spans: HashMap<Position, usize>,
+    row_spans: HashMap<Position, usize>,
+    lines: BTreeSet<usize>,
+    booktabs: bool,
+}
+
+impl LatexTable {
+    /// Creates a new LaTeX table from a given elements.
+    ///
+    /// The table has no horizontal lines.
+    pub fn new<I, R, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let data = iter
+            .into_iter()
+            .map(|row| row.into_iter().map(|s| s.into()).collect())
+            .collect();
+
+        latex_table(data)
+    }
+
+    /// Creates a new LaTeX table from a given elements.
+    /// Assuming that the first row has column names.
+    ///
+    /// The header is separated by a rule, and the table is surrounded by rules.
+    pub fn with_header<I, R, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let mut table = Self::new(iter);
+
+        let count_rows = table.data.len();
+        if count_rows > 0 {
+            table.lines.extend([0, count_rows]);
+        }
+
+        if count_rows > 1 {
+            let _ = table.lines.insert(1);
+        }
+
+        table
+    }
+
+    /// Set a horizontal alignment for a given column.
+    ///
+    /// A vertical alignment is ignored.
+    pub fn set_alignment(&mut self, column: usize, alignment: Alignment) {
+        if let Some(alignment) = alignment.as_horizontal() {
+            if column < self.alignments.len() {
+                self.alignments[column] = alignment;
+            }
+        }
+    }
+
+    /// Set a column span for a given cell.
+    pub fn set_column_span(&mut self, pos: Position, size: usize) {
+        let _ = self.column_spans.insert(pos, size);
+    }
+
+    /// Set a row span for a given cell.
+    pub fn set_row_span(&mut self, pos: Position, size: usize) {
+        let _ = self.row_spans.insert(pos, size);
+    }
+
+    /// Set whether a horizontal line is drawn before a given row.
+    ///
+    /// A line at index equal to a number of rows is the last line.
+    pub fn set_horizontal_line(&mut self, row: usize, on: bool) {
+        if on {
+            let _ = self.lines.insert(row);
+        } else {
+            let _ = self.lines.remove(&row);
+        }
+    }
+
+    /// Set whether `booktabs` rules are used for horizontal lines (which is a default),
+    /// otherwise `\hline` and `\cline` are used.
+    pub fn set_booktabs(&mut self, on: bool) {
+        self.booktabs = on;
+    }
+
+    fn count_rows(&self) -> usize {
+        self.data.len()
+    }
+
+    fn get_text(&self, pos: Position) -> &str {
+        self.data
+            .get(pos.0)
+            .and_then(|row| row.get(pos.1))
+            .map(String::as_str)
+            .unwrap_or("")
+    }
+
+    /// Returns a position of a cell which is shown at each position.
+    fn find_owners(&self) -> Vec<Vec<Position>> {
+        let count_rows = self.count_rows();
+        let count_columns = self.count_columns;
+
+        let mut owners = (0..count_rows)
+            .map(|row| (0..count_columns).map(|col| (row, col)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                let pos = (row, col);
+                if owners[row][col] != pos {
+                    continue;
+                }
+
+                let (end_row, end_col) = self.get_span_end(pos);
+                for cells in &mut owners[row..end_row] {
+                    for owner in &mut cells[col..end_col] {
+                        *owner = pos;
+                    }
+                }
+            }
+        }
+
+        owners
+    }
+
+    /// Returns an exclusive end of a cell region.
+    fn get_span_end(&self, pos: Position) -> Position {
+        let rows = self.row_spans.get(&pos).copied().unwrap_or(1).max(1);
+        let cols = self.column_spans.get(&pos).copied().unwrap_or(1).max(1);
+
+        (
+            (pos.0 + rows).min(self.count_rows()),
+            (pos.1 + cols).min(self.count_columns),
+        )
+    }
+
+    fn fmt_line(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        line: usize,
+        owners: &[Vec<Position>],
+    ) -> fmt::Result {
+        let rule = |booktabs_rule| {
+            if self.booktabs {
+                booktabs_rule
+            } else {
+                "\\hline"
+            }
+        };
+
+        if line == 0 {
+            return writeln!(f, "{}", rule("\\toprule"));
+        }
+
+        if line == self.count_rows() {
+            return writeln!(f, "{}", rule("\\bottomrule"));
+        }
+
+        // a line must not cross cells spanned over it
+        let is_crossed = |col: usize| owners[line - 1][col] == owners[line][col];
+
+        if !(0..self.count_columns).any(is_crossed) {
+            return writeln!(f, "{}", rule("\\midrule"));
+        }
+
+        let rule = if self.booktabs {
+            "\\cmidrule"
+        } else {
+            "\\cline"
+        };
+
+        let mut segments = Vec::new();
+        let mut start = None;
+        for col in 0..=self.count_columns {
+            let is_drawn = col < self.count_columns && !is_crossed(col);
+            match (is_drawn, start) {
+                (true, None) => start = Some(col),
+                (false, Some(begin)) => {
+                    segments.push(format!("{}{{{}-{}}}", rule, begin + 1, col));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        if segments.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f, "{}", segments.join(" "))
+    }
+
+    fn fmt_row(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        row: usize,
+        owners: &[Vec<Position>],
+    ) -> fmt::Result {
+        let mut cells = Vec::new();
+
+        let mut col = 0;
+        while col < self.count_columns {
+            let owner = owners[row][col];
+            let (end_row, end_col) = self.get_span_end(owner);
+            let count_spanned_rows = end_row - owner.0;
+            let count_spanned_columns = end_col - col;
+            let align = alignment_spec(self.alignments[col]);
+
+            let mut text = String::new();
+            if owner.0 == row {
+                text = format_text(self.get_text(owner), align);
+
+                if count_spanned_rows > 1 {
+                    text = format!("\\multirow{{{}}}{{*}}{{{}}}", count_spanned_rows, text);
+                }
+            }
+
+            if count_spanned_columns > 1 {
+                text = format!(
+                    "\\multicolumn{{{}}}{{{}}}{{{}}}",
+                    count_spanned_columns, align, text
+                );
+            }
+
+            cells.push(text);
+            col = end_col;
+        }
+
+        writeln!(f, "{} \\\\", cells.join(" & "))
+    }
+}
+
+impl From<&Table> for LatexTable {
+    fn from(table: &Table) -> Self {
+        let records = table.get_records();
+        let cfg = table.get_config();
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let data = (0..count_rows)
+            .map(|row| {
+                (0..count_columns)
+                    .map(|col| strip_ansi(records.get_text((row, col))))
+                    .collect()
+            })
+            .collect();
+
+        let mut latex = latex_table(data);
+
+        // an alignment is taken from a first data row as a header is often styled differently
+        let alignment_row = if count_rows > 1 { 1 } else { 0 };
+        latex.alignments = (0..count_columns)
+            .map(|col| *cfg.get_alignment_horizontal(Entity::Cell(alignment_row, col)))
+            .collect();
+
+        let is_inside = |pos: &Position| pos.0 < count_rows && pos.1 < count_columns;
+        latex.column_spans = cfg
+            .get_column_spans()
+            .into_iter()
+            .filter(|(pos, _)| is_inside(pos))
+            .collect();
+        latex.row_spans = cfg
+            .get_row_spans()
+            .into_iter()
+            .filter(|(pos, _)| is_inside(pos))
+            .collect();
+
+        if count_rows > 0 {
+            latex.lines = (0..=count_rows)
+                .filter(|&row| cfg.has_horizontal(row, count_rows))
+                .collect();
+        }
+
+        latex
+    }
+}
+
+impl From<Table> for LatexTable {
+    fn from(table: Table) -> Self {
+        Self::from(&table)
+    }
+}
+
+impl Display for LatexTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let owners = self.find_owners();
+
+        f.write_str("\\begin{tabular}{")?;
+        for &alignment in &self.alignments {
+            f.write_str(alignment_spec(alignment))?;
+        }
+        f.write_str("}\n")?;
+
+        for row in 0..self.count_rows() {
+            if self.lines.contains(&row) {
+                self.fmt_line(f, row, &owners)?;
+            }
+
+            self.fmt_row(f, row, &owners)?;
+        }
+
+        let count_rows = self.count_rows();
+        if count_rows > 0 && self.lines.contains(&count_rows) {
+            self.fmt_line(f, count_rows, &owners)?;
+        }
+
+        f.write_str("\\end{tabular}")
+    }
+}
+
+fn latex_table(mut data: Vec<Vec<String>>) -> LatexTable {
+    let count_columns = data.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut data {
+        row.resize(count_columns, String::new());
+    }
+
+    LatexTable {
+        data,
+        count_columns,
+        alignments: vec![AlignmentHorizontal::Left; count_columns],
+        column_spans: HashMap::new(),
+        row_spans: HashMap::new(),
+        lines: BTreeSet::new(),
+        booktabs: true,
+    }
+}
+
+fn alignment_spec(alignment: AlignmentHorizontal) -> &'static str {
+    match alignment {
+        AlignmentHorizontal::Left => "l",
+        AlignmentHorizontal::Center => "c",
+        AlignmentHorizontal::Right => "r",
+    }
+}
+
+/// Escapes a text, and puts a multi-line text into a `\shortstack`
+/// as a cell can't have line breaks.
+fn format_text(text: &str, align: &str) -> String {
+    let lines = get_lines(text)
+        .map(|line| escape(&line))
+        .collect::<Vec<_>>();
+    if lines.len() > 1 {
+        return format!("\\shortstack[{}]{{{}}}", align, lines.join(" \\\\ "));
+    }
+
+    lines.join("")
+}
+
+#[cfg(feature = "ansi")]
+fn strip_ansi(text: &str) -> String {
+    ansi_str::AnsiStr::ansi_strip(text).into_owned()
+}
+
+#[cfg(not(feature = "ansi"))]
+fn strip_ansi(text: &str) -> String {
+    text.to_owned()
+}
+
+fn escape(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            '~' => buf.push_str("\\textasciitilde{}"),
+            '^' => buf.push_str("\\textasciicircum{}"),
+            '\\' => buf.push_str("\\textbackslash{}"),
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
@@ -0,0 +1,255 @@
+use table_to_latex::{Alignment, LatexTable};
+
+use tabled::{
+    settings::{object::Columns, Alignment as TableAlignment, Span, Style},
+    Table, Tabled,
+};
+use testing_table::test_table;
+
+#[derive(Tabled)]
+struct Language {
+    name: &'static str,
+    designed_by: &'static str,
+    invented_year: usize,
+}
+
+fn languages() -> Table {
+    Table::new([
+        Language {
+            name: "C",
+            designed_by: "Dennis Ritchie",
+            invented_year: 1972,
+        },
+        Language {
+            name: "Rust",
+            designed_by: "Graydon Hoare",
+            invented_year: 2010,
+        },
+        Language {
+            name: "Go",
+            designed_by: "Rob Pike",
+            invented_year: 2009,
+        },
+    ])
+}
+
+test_table!(
+    latex_iter,
+    LatexTable::new([["1", "2"], ["3", "4"]]),
+    "\\begin{tabular}{ll}"
+    "1 & 2 \\\\"
+    "3 & 4 \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_with_header,
+    LatexTable::with_header([["a", "b"], ["1", "2"]]),
+    "\\begin{tabular}{ll}"
+    "\\toprule"
+    "a & b \\\\"
+    "\\midrule"
+    "1 & 2 \\\\"
+    "\\bottomrule"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_iter_uneven_rows,
+    LatexTable::new(vec![vec!["1", "2", "3"], vec!["4"]]),
+    "\\begin{tabular}{lll}"
+    "1 & 2 & 3 \\\\"
+    "4 &  &  \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_empty,
+    LatexTable::new(Vec::<Vec<String>>::new()),
+    "\\begin{tabular}{}"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_set_alignment,
+    {
+        let mut table = LatexTable::new([["1", "2", "3"]]);
+        table.set_alignment(1, Alignment::center());
+        table.set_alignment(2, Alignment::right());
+        table.set_alignment(0, Alignment::bottom());
+        table
+    },
+    "\\begin{tabular}{lcr}"
+    "1 & 2 & 3 \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_escape,
+    LatexTable::new([["50% & $5", "a_b #1", "{x} ~ ^ \\"]]),
+    "\\begin{tabular}{lll}"
+    "50\\% \\& \\$5 & a\\_b \\#1 & \\{x\\} \\textasciitilde{} \\textasciicircum{} \\textbackslash{} \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_multiline,
+    LatexTable::new([["multi\nline", "single"]]),
+    "\\begin{tabular}{ll}"
+    "\\shortstack[l]{multi \\\\ line} & single \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_from_table,
+    LatexTable::from(&languages()),
+    "\\begin{tabular}{lll}"
+    "\\toprule"
+    "name & designed\\_by & invented\\_year \\\\"
+    "\\midrule"
+    "C & Dennis Ritchie & 1972 \\\\"
+    "\\midrule"
+    "Rust & Graydon Hoare & 2010 \\\\"
+    "\\midrule"
+    "Go & Rob Pike & 2009 \\\\"
+    "\\bottomrule"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_from_table_style,
+    LatexTable::from(languages().with(Style::markdown()).clone()),
+    "\\begin{tabular}{lll}"
+    "name & designed\\_by & invented\\_year \\\\"
+    "\\midrule"
+    "C & Dennis Ritchie & 1972 \\\\"
+    "Rust & Graydon Hoare & 2010 \\\\"
+    "Go & Rob Pike & 2009 \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_from_table_without_lines,
+    LatexTable::from(languages().with(Style::blank()).clone()),
+    "\\begin{tabular}{lll}"
+    "name & designed\\_by & invented\\_year \\\\"
+    "C & Dennis Ritchie & 1972 \\\\"
+    "Rust & Graydon Hoare & 2010 \\\\"
+    "Go & Rob Pike & 2009 \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_from_table_alignment,
+    LatexTable::from(
+        languages()
+            .with(Style::empty())
+            .modify(Columns::single(1), TableAlignment::center())
+            .modify(Columns::single(2), TableAlignment::right())
+            .clone()
+    ),
+    "\\begin{tabular}{lcr}"
+    "name & designed\\_by & invented\\_year \\\\"
+    "C & Dennis Ritchie & 1972 \\\\"
+    "Rust & Graydon Hoare & 2010 \\\\"
+    "Go & Rob Pike & 2009 \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_from_table_column_span,
+    LatexTable::from(
+        languages()
+            .with(Style::empty())
+            .modify((1, 1), Span::column(2))
+            .clone()
+    ),
+    "\\begin{tabular}{lll}"
+    "name & designed\\_by & invented\\_year \\\\"
+    "C & \\multicolumn{2}{l}{Dennis Ritchie} \\\\"
+    "Rust & Graydon Hoare & 2010 \\\\"
+    "Go & Rob Pike & 2009 \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_from_table_row_span,
+    LatexTable::from(languages().modify((1, 0), Span::row(2)).clone()),
+    "\\begin{tabular}{lll}"
+    "\\toprule"
+    "name & designed\\_by & invented\\_year \\\\"
+    "\\midrule"
+    "\\multirow{2}{*}{C} & Dennis Ritchie & 1972 \\\\"
+    "\\cmidrule{2-3}"
+    " & Graydon Hoare & 2010 \\\\"
+    "\\midrule"
+    "Go & Rob Pike & 2009 \\\\"
+    "\\bottomrule"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_from_table_row_span_hline,
+    {
+        let mut table = LatexTable::from(languages().modify((1, 1), Span::row(2)).clone());
+        table.set_booktabs(false);
+        table
+    },
+    "\\begin{tabular}{lll}"
+    "\\hline"
+    "name & designed\\_by & invented\\_year \\\\"
+    "\\hline"
+    "C & \\multirow{2}{*}{Dennis Ritchie} & 1972 \\\\"
+    "\\cline{1-1} \\cline{3-3}"
+    "Rust &  & 2010 \\\\"
+    "\\hline"
+    "Go & Rob Pike & 2009 \\\\"
+    "\\hline"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_both_spans,
+    {
+        let mut table = LatexTable::new([["1", "2", "3"], ["4", "5", "6"], ["7", "8", "9"]]);
+        table.set_column_span((0, 0), 2);
+        table.set_row_span((0, 0), 2);
+        table.set_horizontal_line(1, true);
+        table.set_horizontal_line(2, true);
+        table
+    },
+    "\\begin{tabular}{lll}"
+    "\\multicolumn{2}{l}{\\multirow{2}{*}{1}} & 3 \\\\"
+    "\\cmidrule{3-3}"
+    "\\multicolumn{2}{l}{} & 6 \\\\"
+    "\\midrule"
+    "7 & 8 & 9 \\\\"
+    "\\end{tabular}"
+);
+
+test_table!(
+    latex_full_span_has_no_line,
+    {
+        let mut table = LatexTable::new([["1"], ["2"]]);
+        table.set_row_span((0, 0), 2);
+        table.set_horizontal_line(1, true);
+        table
+    },
+    "\\begin{tabular}{l}"
+    "\\multirow{2}{*}{1} \\\\"
+    " \\\\"
+    "\\end{tabular}"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    latex_from_table_strips_ansi,
+    LatexTable::from(Table::new([("\u{1b}[31mC_lang\u{1b}[39m", "\u{1b}[1m100%\u{1b}[0m")])),
+    "\\begin{tabular}{ll}"
+    "\\toprule"
+    "\\&str & \\&str \\\\"
+    "\\midrule"
+    "C\\_lang & 100\\% \\\\"
+    "\\bottomrule"
+    "\\end{tabular}"
+);
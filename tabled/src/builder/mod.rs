@@ -1,7 +1,12 @@
 //! Builder module provides a [`Builder`] type which helps building
 //! a [`Table`] dynamically.
 //!
-//! It also contains [`IndexBuilder`] which can help to build a table with index.
+//! It also contains [`IndexBuilder`] which can help to build a table with index,
+//! and [`PivotBuilder`] which builds a cross table out of `(row, column, value)` records.
+//!
+//! [`TableParser`] goes the other way round, it reads a rendered table back into a [`Table`].
+//!
+//! With `serde` feature on there's also a `SerdeBuilder` which builds a table out of any `Serialize` type.
 //!
 //! # Examples
 //!
@@ -109,7 +114,16 @@
 //! [`Table`]: crate::Table
 
 mod index_builder;
+mod pivot_builder;
+#[cfg(feature = "serde")]
+mod serde_builder;
 mod table_builder;
+mod table_parser;
 
 pub use index_builder::IndexBuilder;
+pub use pivot_builder::PivotBuilder;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use serde_builder::{SerdeBuilder, SerdeError};
 pub use table_builder::Builder;
+pub use table_parser::{ParseError, TableParser};
@@ -0,0 +1,260 @@
+use std::{collections::HashMap, iter::FromIterator};
+
+use crate::{grid::records::vec_records::Text, Table};
+
+use super::Builder;
+
+/// [`PivotBuilder`] creates a cross table out of `(row key, column key, value)` records.
+///
+/// Unique row keys go down the first column and unique column keys go across the first row,
+/// in order of their first appearance.
+/// A value is put on an intersection of its keys.
+///
+/// # Example
+///
+/// ```
+/// use tabled::builder::PivotBuilder;
+///
+/// let mut builder = PivotBuilder::new();
+/// builder.set_corner("crate");
+/// builder.set_empty("-");
+/// builder.push_record("tabled", "linux", "ok");
+/// builder.push_record("tabled", "macos", "ok");
+/// builder.push_record("papergrid", "linux", "ok");
+/// builder.push_record("papergrid", "windows", "failed");
+///
+/// let table = builder.build().to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-----------+-------+-------+---------+\n\
+///      | crate     | linux | macos | windows |\n\
+///      +-----------+-------+-------+---------+\n\
+///      | tabled    | ok    | ok    | -       |\n\
+///      +-----------+-------+-------+---------+\n\
+///      | papergrid | ok    | -     | failed  |\n\
+///      +-----------+-------+-------+---------+"
+/// )
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct PivotBuilder {
+    /// Unique row keys.
+    rows: Keys,
+    /// Unique column keys.
+    columns: Keys,
+    /// Values by positions of their keys.
+    values: HashMap<(usize, usize), Vec<String>>,
+    /// A content of the top left cell.
+    corner: String,
+    /// A content of intersections which has no values.
+    empty_text: String,
+}
+
+impl PivotBuilder {
+    /// Creates a [`PivotBuilder`] instance.
+    ///
+    /// ```
+    /// use tabled::builder::PivotBuilder;
+    ///
+    /// let builder = PivotBuilder::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a value on an intersection of a row and a column.
+    ///
+    /// ```
+    /// use tabled::builder::PivotBuilder;
+    ///
+    /// let mut builder = PivotBuilder::new();
+    /// builder.push_record("tabled", "linux", "ok");
+    /// ```
+    pub fn push_record<R, C, V>(&mut self, row: R, column: C, value: V)
+    where
+        R: Into<String>,
+        C: Into<String>,
+        V: Into<String>,
+    {
+        let row = self.rows.find_or_insert(row.into());
+        let column = self.columns.find_or_insert(column.into());
+
+        self.values
+            .entry((row, column))
+            .or_default()
+            .push(value.into());
+    }
+
+    /// Sets a content of the top left cell.
+    ///
+    /// By default it's empty.
+    ///
+    /// ```
+    /// use tabled::builder::PivotBuilder;
+    ///
+    /// let mut builder = PivotBuilder::new();
+    /// builder.set_corner("crate \\ os");
+    /// ```
+    pub fn set_corner<T>(&mut self, text: T)
+    where
+        T: Into<String>,
+    {
+        self.corner = text.into();
+    }
+
+    /// Sets a content of cells which have no values.
+    ///
+    /// By default it's empty.
+    ///
+    /// ```
+    /// use tabled::builder::PivotBuilder;
+    ///
+    /// let mut builder = PivotBuilder::new();
+    /// builder.set_empty("-");
+    /// ```
+    pub fn set_empty<T>(&mut self, text: T)
+    where
+        T: Into<String>,
+    {
+        self.empty_text = text.into();
+    }
+
+    /// Returns an amount of unique row keys.
+    pub fn count_rows(&self) -> usize {
+        self.rows.list.len()
+    }
+
+    /// Returns an amount of unique column keys.
+    pub fn count_columns(&self) -> usize {
+        self.columns.list.len()
+    }
+
+    /// Build creates a [`Table`] instance.
+    ///
+    /// If there are a few values on the same intersection the last one is used.
+    /// Use [`PivotBuilder::build_with`] to combine them differently.
+    pub fn build(self) -> Table {
+        Builder::from(self).build()
+    }
+
+    /// Build creates a [`Table`] instance,
+    /// using a given function to combine all values of an intersection.
+    ///
+    /// ```
+    /// use tabled::builder::PivotBuilder;
+    ///
+    /// let mut builder = PivotBuilder::new();
+    /// builder.push_record("tabled", "linux", "10");
+    /// builder.push_record("tabled", "linux", "5");
+    /// builder.push_record("tabled", "macos", "7");
+    ///
+    /// let table = builder.build_with(|values| {
+    ///     let sum = values.iter().map(|v| v.parse::<u32>().unwrap()).sum::<u32>();
+    ///     sum.to_string()
+    /// });
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------+-------+-------+\n\
+    ///      |        | linux | macos |\n\
+    ///      +--------+-------+-------+\n\
+    ///      | tabled | 15    | 7     |\n\
+    ///      +--------+-------+-------+"
+    /// )
+    /// ```
+    pub fn build_with<F>(self, aggregate: F) -> Table
+    where
+        F: Fn(&[String]) -> String,
+    {
+        build_pivot(self, aggregate).build()
+    }
+}
+
+impl<R, C, V> FromIterator<(R, C, V)> for PivotBuilder
+where
+    R: Into<String>,
+    C: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<T: IntoIterator<Item = (R, C, V)>>(iter: T) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+}
+
+impl<R, C, V> Extend<(R, C, V)> for PivotBuilder
+where
+    R: Into<String>,
+    C: Into<String>,
+    V: Into<String>,
+{
+    fn extend<T: IntoIterator<Item = (R, C, V)>>(&mut self, iter: T) {
+        for (row, column, value) in iter {
+            self.push_record(row, column, value);
+        }
+    }
+}
+
+impl From<PivotBuilder> for Builder {
+    fn from(builder: PivotBuilder) -> Self {
+        build_pivot(builder, |values| values.last().cloned().unwrap_or_default())
+    }
+}
+
+fn build_pivot<F>(mut b: PivotBuilder, aggregate: F) -> Builder
+where
+    F: Fn(&[String]) -> String,
+{
+    // we can skip the conversion if there's no records
+    if b.rows.list.is_empty() {
+        return Builder::default();
+    }
+
+    let count_columns = b.columns.list.len();
+    let mut data = Vec::with_capacity(b.rows.list.len() + 1);
+
+    let mut header = Vec::with_capacity(count_columns + 1);
+    header.push(Text::new(b.corner));
+    header.extend(b.columns.list.into_iter().map(Text::new));
+    data.push(header);
+
+    for (row, key) in b.rows.list.into_iter().enumerate() {
+        let mut record = Vec::with_capacity(count_columns + 1);
+        record.push(Text::new(key));
+
+        for column in 0..count_columns {
+            let text = match b.values.remove(&(row, column)) {
+                Some(values) => aggregate(&values),
+                None => b.empty_text.clone(),
+            };
+
+            record.push(Text::new(text));
+        }
+
+        data.push(record);
+    }
+
+    Builder::from_vec(data)
+}
+
+/// A list of unique keys in order of insertion.
+#[derive(Debug, Default, Clone)]
+struct Keys {
+    list: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl Keys {
+    fn find_or_insert(&mut self, key: String) -> usize {
+        if let Some(&i) = self.index.get(&key) {
+            return i;
+        }
+
+        let i = self.list.len();
+        let _ = self.index.insert(key.clone(), i);
+        self.list.push(key);
+
+        i
+    }
+}
@@ -0,0 +1,684 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use serde::ser::{self, Serialize};
+
+use crate::Table;
+
+use super::Builder;
+
+/// [`SerdeBuilder`] creates a [`Table`] out of any type which implements [`Serialize`].
+///
+/// A sequence (or a tuple) is split into rows, anything else becomes a single row.
+///
+/// Fields of a row become columns named by their serialized names,
+/// so `#[serde(rename)]`, `#[serde(skip)]` and alike are respected.
+/// Nested structs and maps are flattened,
+/// their columns are named by a path of fields joined by a separator (`.` by default),
+/// similar to `#[tabled(inline("prefix"))]`.
+/// Tuples are flattened as well, by indexes of their elements.
+///
+/// Sequences inside a row are put in a single cell, joined by `, `.
+/// [`None`] and unit values are rendered as empty cells.
+///
+/// Columns go in order of their first appearance, so rows may have different fields;
+/// missing cells are left empty.
+///
+/// # Example
+///
+/// ```
+/// use serde::Serialize;
+/// use tabled::builder::SerdeBuilder;
+///
+/// #[derive(Serialize)]
+/// struct Release {
+///     #[serde(rename = "crate")]
+///     name: &'static str,
+///     version: Version,
+///     features: Vec<&'static str>,
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Version {
+///     major: u8,
+///     minor: u8,
+/// }
+///
+/// let releases = vec![
+///     Release { name: "tabled", version: Version { major: 0, minor: 17 }, features: vec!["std", "derive"] },
+///     Release { name: "papergrid", version: Version { major: 0, minor: 13 }, features: vec![] },
+/// ];
+///
+/// let table = SerdeBuilder::new().build(&releases).unwrap();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-----------+---------------+---------------+-------------+\n\
+///      | crate     | version.major | version.minor | features    |\n\
+///      +-----------+---------------+---------------+-------------+\n\
+///      | tabled    | 0             | 17            | std, derive |\n\
+///      +-----------+---------------+---------------+-------------+\n\
+///      | papergrid | 0             | 13            |             |\n\
+///      +-----------+---------------+---------------+-------------+"
+/// )
+/// ```
+#[derive(Debug, Clone)]
+pub struct SerdeBuilder {
+    /// A separator of nested field names.
+    separator: String,
+}
+
+impl SerdeBuilder {
+    /// Creates a [`SerdeBuilder`] instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a separator which is used to join names of nested fields.
+    ///
+    /// By default it's `.`.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use tabled::builder::SerdeBuilder;
+    ///
+    /// let mut limits = BTreeMap::new();
+    /// limits.insert("cpu", (2, 4));
+    /// limits.insert("memory", (512, 1024));
+    ///
+    /// let table = SerdeBuilder::new().separator("_").build(&limits).unwrap();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-------+----------+----------+\n\
+    ///      | cpu_0 | cpu_1 | memory_0 | memory_1 |\n\
+    ///      +-------+-------+----------+----------+\n\
+    ///      | 2     | 4     | 512      | 1024     |\n\
+    ///      +-------+-------+----------+----------+"
+    /// )
+    /// ```
+    pub fn separator<S>(mut self, separator: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Builds a [`Table`] out of a value.
+    pub fn build<T>(self, value: &T) -> Result<Table, SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.build_records(value).map(Builder::build)
+    }
+
+    /// Builds a [`Builder`] out of a value, so it can be adjusted further.
+    pub fn build_records<T>(self, value: &T) -> Result<Builder, SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(ValueSerializer)?;
+
+        let rows = match value {
+            Value::Seq(list) | Value::Tuple(list) => list,
+            value => vec![value],
+        };
+
+        let mut columns = Columns::default();
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut record = Vec::new();
+            flatten(
+                row,
+                String::new(),
+                &self.separator,
+                &mut columns,
+                &mut record,
+            );
+            records.push(record);
+        }
+
+        if columns.list.is_empty() {
+            return Ok(Builder::default());
+        }
+
+        let mut builder = Builder::with_capacity(records.len() + 1, columns.list.len());
+        builder.push_record(columns.list.clone());
+
+        for record in records {
+            let mut row = vec![String::new(); columns.list.len()];
+            for (col, text) in record {
+                row[col] = text;
+            }
+
+            builder.push_record(row);
+        }
+
+        Ok(builder)
+    }
+}
+
+impl Default for SerdeBuilder {
+    fn default() -> Self {
+        Self {
+            separator: String::from("."),
+        }
+    }
+}
+
+/// An error which may be returned by [`Serialize`] implementation of a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerdeError {
+    message: String,
+}
+
+impl fmt::Display for SerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+impl Error for SerdeError {}
+
+impl ser::Error for SerdeError {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        Self {
+            message: msg.to_string(),
+        }
+    }
+}
+
+/// A list of unique column names in order of appearance.
+#[derive(Debug, Default)]
+struct Columns {
+    list: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl Columns {
+    fn find_or_insert(&mut self, name: String) -> usize {
+        if let Some(&i) = self.index.get(&name) {
+            return i;
+        }
+
+        let i = self.list.len();
+        let _ = self.index.insert(name.clone(), i);
+        self.list.push(name);
+
+        i
+    }
+}
+
+fn flatten(
+    value: Value,
+    path: String,
+    separator: &str,
+    columns: &mut Columns,
+    record: &mut Vec<(usize, String)>,
+) {
+    match value {
+        Value::Map(fields) => {
+            for (key, value) in fields {
+                let path = join_path(&path, &key, separator);
+                flatten(value, path, separator, columns, record);
+            }
+        }
+        Value::Tuple(list) => {
+            for (i, value) in list.into_iter().enumerate() {
+                let path = join_path(&path, &i.to_string(), separator);
+                flatten(value, path, separator, columns, record);
+            }
+        }
+        value => {
+            let col = columns.find_or_insert(path);
+            let mut text = String::new();
+            value.write_inline(&mut text, false);
+            record.push((col, text));
+        }
+    }
+}
+
+fn join_path(path: &str, key: &str, separator: &str) -> String {
+    if path.is_empty() {
+        return key.to_owned();
+    }
+
+    format!("{}{}{}", path, separator, key)
+}
+
+/// An intermediate representation of a serialized value.
+#[derive(Debug)]
+enum Value {
+    Text(String),
+    Seq(Vec<Value>),
+    Tuple(Vec<Value>),
+    Map(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn into_text(self) -> String {
+        match self {
+            Value::Text(text) => text,
+            value => {
+                let mut text = String::new();
+                value.write_inline(&mut text, true);
+                text
+            }
+        }
+    }
+
+    // Renders a value in a single cell,
+    // nested lists and maps are wrapped into brackets.
+    fn write_inline(self, buf: &mut String, nested: bool) {
+        match self {
+            Value::Text(text) => buf.push_str(&text),
+            Value::Seq(list) | Value::Tuple(list) => {
+                if nested {
+                    buf.push('[');
+                }
+
+                for (i, value) in list.into_iter().enumerate() {
+                    if i > 0 {
+                        buf.push_str(", ");
+                    }
+
+                    value.write_inline(buf, true);
+                }
+
+                if nested {
+                    buf.push(']');
+                }
+            }
+            Value::Map(fields) => {
+                buf.push('{');
+
+                for (i, (key, value)) in fields.into_iter().enumerate() {
+                    if i > 0 {
+                        buf.push_str(", ");
+                    }
+
+                    buf.push_str(&key);
+                    buf.push_str(": ");
+                    value.write_inline(buf, true);
+                }
+
+                buf.push('}');
+            }
+        }
+    }
+}
+
+struct ValueSerializer;
+
+impl ValueSerializer {
+    fn text<T>(value: T) -> Result<Value, SerdeError>
+    where
+        T: ToString,
+    {
+        Ok(Value::Text(value.to_string()))
+    }
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = VariantSerializer<SeqSerializer>;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = VariantSerializer<MapSerializer>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, SerdeError> {
+        Self::text(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, SerdeError> {
+        let list = v.iter().map(|b| Value::Text(b.to_string())).collect();
+        Ok(Value::Seq(list))
+    }
+
+    fn serialize_none(self) -> Result<Value, SerdeError> {
+        Self::text("")
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Value, SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, SerdeError> {
+        Self::text("")
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value, SerdeError> {
+        Self::text("")
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Value, SerdeError> {
+        Self::text(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<Value, SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(self)?;
+        Ok(Value::Map(vec![(variant.to_owned(), value)]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, SerdeError> {
+        Ok(SeqSerializer::new(len.unwrap_or_default(), false))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, SerdeError> {
+        Ok(SeqSerializer::new(len, true))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, SerdeError> {
+        Ok(SeqSerializer::new(len, true))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer<SeqSerializer>, SerdeError> {
+        Ok(VariantSerializer::new(
+            variant,
+            SeqSerializer::new(len, true),
+        ))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer, SerdeError> {
+        Ok(MapSerializer::new(len.unwrap_or_default()))
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<MapSerializer, SerdeError> {
+        Ok(MapSerializer::new(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer<MapSerializer>, SerdeError> {
+        Ok(VariantSerializer::new(variant, MapSerializer::new(len)))
+    }
+}
+
+struct SeqSerializer {
+    list: Vec<Value>,
+    is_tuple: bool,
+}
+
+impl SeqSerializer {
+    fn new(capacity: usize, is_tuple: bool) -> Self {
+        Self {
+            list: Vec::with_capacity(capacity),
+            is_tuple,
+        }
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(ValueSerializer)?;
+        self.list.push(value);
+        Ok(())
+    }
+
+    fn finish(self) -> Value {
+        if self.is_tuple {
+            Value::Tuple(self.list)
+        } else {
+            Value::Seq(self.list)
+        }
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(self.finish())
+    }
+}
+
+struct MapSerializer {
+    fields: Vec<(String, Value)>,
+    key: Option<String>,
+}
+
+impl MapSerializer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            fields: Vec::with_capacity(capacity),
+            key: None,
+        }
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = key.serialize(ValueSerializer)?;
+        self.key = Some(key.into_text());
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let key = self.key.take().unwrap_or_default();
+        let value = value.serialize(ValueSerializer)?;
+        self.fields.push((key, value));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(Value::Map(self.fields))
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let value = value.serialize(ValueSerializer)?;
+        self.fields.push((key.to_owned(), value));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(Value::Map(self.fields))
+    }
+}
+
+struct VariantSerializer<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl<S> VariantSerializer<S> {
+    fn new(variant: &'static str, inner: S) -> Self {
+        Self { variant, inner }
+    }
+
+    fn finish(variant: &'static str, value: Value) -> Value {
+        Value::Map(vec![(variant.to_owned(), value)])
+    }
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer<SeqSerializer> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        Ok(Self::finish(self.variant, self.inner.finish()))
+    }
+}
+
+impl ser::SerializeStructVariant for VariantSerializer<MapSerializer> {
+    type Ok = Value;
+    type Error = SerdeError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), SerdeError>
+    where
+        T: Serialize + ?Sized,
+    {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<Value, SerdeError> {
+        let value = ser::SerializeStruct::end(self.inner)?;
+        Ok(Self::finish(self.variant, value))
+    }
+}
@@ -0,0 +1,361 @@
+use std::{collections::BTreeSet, error::Error, fmt};
+
+use crate::{
+    grid::util::string::get_char_width,
+    settings::{style::Style, themes::Theme},
+    util::string::strip_ansi,
+    Table,
+};
+
+use super::Builder;
+
+/// [`TableParser`] reads a rendered table back into a [`Table`].
+///
+/// It must know a [`Style`] the table was rendered with,
+/// it works with any of the presets like [`Style::ascii`], [`Style::modern`], [`Style::psql`],
+/// [`Style::markdown`], [`Style::rounded`] and [`Style::re_structured_text`].
+///
+/// Cells may have a few lines if the style has horizontal lines between rows
+/// (otherwise each line is considered to be a row).
+/// Lines of a cell are trimmed.
+///
+/// A cell which goes over a few columns gets a column span.
+/// Row spans are not detected.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{builder::{Builder, TableParser}, settings::Style};
+///
+/// let text = "\
+///     ┌──────┬─────────────┐\n\
+///     │ name │ description │\n\
+///     ├──────┴─────────────┤\n\
+///     │ tabled             │\n\
+///     ├──────┬─────────────┤\n\
+///     │ gd   │ a graph     │\n\
+///     │      │ drawing     │\n\
+///     └──────┴─────────────┘";
+///
+/// let table = TableParser::new(Style::modern()).parse(text).unwrap();
+/// let data: Vec<Vec<String>> = Builder::from(table.clone()).into();
+///
+/// assert_eq!(
+///     data,
+///     [
+///         ["name", "description"],
+///         ["tabled", ""],
+///         ["gd", "a graph\ndrawing"],
+///     ]
+/// );
+///
+/// assert_eq!(table.get_config().get_column_span((1, 0)), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableParser {
+    theme: Theme,
+    /// Characters of vertical lines.
+    verticals: Vec<char>,
+    /// Characters of horizontal lines.
+    horizontals: Vec<char>,
+    /// Characters of intersections and corners.
+    intersections: Vec<char>,
+    /// Whether each row is followed by a horizontal line.
+    has_row_lines: bool,
+}
+
+impl TableParser {
+    /// Creates a [`TableParser`] for tables rendered with a given [`Style`].
+    pub fn new<T, B, L, R, H, V, const HSIZE: usize, const VSIZE: usize>(
+        style: Style<T, B, L, R, H, V, HSIZE, VSIZE>,
+    ) -> Self {
+        let borders = style.get_borders();
+        let lines = style.get_horizontals();
+
+        let verticals = [borders.left, borders.right, borders.vertical];
+
+        let mut horizontals = vec![borders.top, borders.bottom, borders.horizontal];
+        horizontals.extend(lines.iter().map(|(_, line)| line.main));
+
+        let mut intersections = vec![
+            borders.top_left,
+            borders.top_right,
+            borders.top_intersection,
+            borders.bottom_left,
+            borders.bottom_right,
+            borders.bottom_intersection,
+            borders.intersection,
+            borders.left_intersection,
+            borders.right_intersection,
+        ];
+        for (_, line) in lines.iter() {
+            intersections.extend([line.intersection, line.left, line.right]);
+        }
+
+        Self {
+            theme: Theme::from_style(style),
+            verticals: verticals.iter().flatten().copied().collect(),
+            horizontals: horizontals.into_iter().flatten().collect(),
+            intersections: intersections.into_iter().flatten().collect(),
+            has_row_lines: borders.horizontal.is_some(),
+        }
+    }
+
+    /// Parses a table.
+    ///
+    /// The returned [`Table`] has the same borders as the parsed one.
+    pub fn parse(&self, text: &str) -> Result<Table, ParseError> {
+        let text = strip_ansi(text);
+        let lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| Line::new(i, line))
+            .collect::<Vec<_>>();
+
+        let (columns, width) = self.find_columns(&lines);
+
+        let mut rows = Vec::new();
+        let mut block: Vec<&Line> = Vec::new();
+        for line in &lines {
+            if self.is_horizontal_line(line) {
+                self.push_rows(&mut rows, &block, &columns, width)?;
+                block.clear();
+                continue;
+            }
+
+            block.push(line);
+        }
+
+        self.push_rows(&mut rows, &block, &columns, width)?;
+
+        let mut spans = Vec::new();
+        let mut data = Vec::with_capacity(rows.len());
+        for (row, cells) in rows.into_iter().enumerate() {
+            let mut record = Vec::with_capacity(columns.len());
+            for (span, text) in cells {
+                if span > 1 {
+                    spans.push(((row, record.len()), span));
+                }
+
+                record.push(text);
+                for _ in 1..span {
+                    record.push(String::new());
+                }
+            }
+
+            data.push(record);
+        }
+
+        let mut table = Builder::from(data).build();
+        let _ = table.with(self.theme.clone());
+
+        for (pos, span) in spans {
+            table.get_config_mut().set_column_span(pos, span);
+        }
+
+        Ok(table)
+    }
+
+    fn is_horizontal_line(&self, line: &Line) -> bool {
+        let is_border = |c: &char| self.horizontals.contains(c) || self.intersections.contains(c);
+
+        line.chars.iter().all(|(_, c)| is_border(c))
+            && line.chars.iter().any(|(_, c)| self.horizontals.contains(c))
+    }
+
+    fn is_vertical(&self, line: &Line, pos: usize) -> bool {
+        // trailing spaces may be trimmed
+        let c = line.get(pos).unwrap_or(' ');
+        self.verticals.contains(&c)
+    }
+
+    /// Returns a list of columns as a range of positions,
+    /// and a width of the table.
+    fn find_columns(&self, lines: &[Line]) -> (Vec<(usize, usize)>, usize) {
+        // a content may be wider than the table only if the text is broken
+        let mut width = lines
+            .iter()
+            .filter(|line| self.is_horizontal_line(line))
+            .map(|line| line.width)
+            .max();
+        if width.is_none() {
+            width = lines.iter().map(|line| line.width).max();
+        }
+        let width = width.unwrap_or(0);
+
+        let mut splits = BTreeSet::new();
+        for line in lines.iter().filter(|line| self.is_horizontal_line(line)) {
+            for &(pos, c) in &line.chars {
+                let is_intersection =
+                    self.intersections.contains(&c) && !self.horizontals.contains(&c);
+                if is_intersection {
+                    let _ = splits.insert(pos);
+                }
+            }
+        }
+
+        // a space can't be used to find vertical lines as it may be a part of a content
+        let has_visible_verticals = !self.verticals.contains(&' ');
+        if has_visible_verticals {
+            let content = lines
+                .iter()
+                .filter(|line| !self.is_horizontal_line(line))
+                .collect::<Vec<_>>();
+
+            if !content.is_empty() {
+                for pos in 0..width {
+                    if content.iter().all(|line| self.is_vertical(line, pos)) {
+                        let _ = splits.insert(pos);
+                    }
+                }
+            }
+        }
+
+        let mut columns = Vec::new();
+        let mut start = 0;
+        for &pos in &splits {
+            if pos > start || (pos == start && !columns.is_empty()) {
+                columns.push((start, pos));
+            }
+
+            start = pos + 1;
+        }
+
+        if start < width || columns.is_empty() {
+            columns.push((start, width));
+        }
+
+        (columns, width)
+    }
+
+    fn push_rows(
+        &self,
+        rows: &mut Vec<Vec<(usize, String)>>,
+        block: &[&Line],
+        columns: &[(usize, usize)],
+        width: usize,
+    ) -> Result<(), ParseError> {
+        // there's always at least 1 column
+        let has_left = columns[0].0 > 0;
+        let has_right = columns[columns.len() - 1].1 < width;
+        let is_broken = |line: &Line| {
+            line.width > width
+                || (has_left && !self.is_vertical(line, 0))
+                || (has_right && !self.is_vertical(line, width - 1))
+        };
+
+        if let Some(line) = block.iter().find(|line| is_broken(line)) {
+            return Err(ParseError::new(line.index));
+        }
+
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        if self.has_row_lines {
+            rows.push(self.parse_row(block, columns));
+        } else {
+            for line in block {
+                rows.push(self.parse_row(&[line], columns));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_row(&self, lines: &[&Line], columns: &[(usize, usize)]) -> Vec<(usize, String)> {
+        let mut cells = Vec::new();
+
+        let mut start = 0;
+        for (col, &(_, end)) in columns.iter().enumerate() {
+            let is_last = col + 1 == columns.len();
+            let is_split = is_last || lines.iter().all(|line| self.is_vertical(line, end));
+            if !is_split {
+                continue;
+            }
+
+            let from = columns[start].0;
+            let text = lines
+                .iter()
+                .map(|line| line.substring(from, end))
+                .collect::<Vec<_>>();
+            let text = text
+                .iter()
+                .map(|line| line.trim())
+                .skip_while(|line| line.is_empty())
+                .collect::<Vec<_>>();
+            let count_lines = text.len() - text.iter().rev().take_while(|l| l.is_empty()).count();
+            let text = text[..count_lines].join("\n");
+
+            cells.push((col + 1 - start, text));
+            start = col + 1;
+        }
+
+        cells
+    }
+}
+
+/// An error which is returned by [`TableParser`] in case a text doesn't match a table layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+}
+
+impl ParseError {
+    fn new(line: usize) -> Self {
+        Self { line }
+    }
+
+    /// Returns an index of a line which doesn't match.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} doesn't match the table layout", self.line)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line with positions of its characters.
+struct Line {
+    index: usize,
+    chars: Vec<(usize, char)>,
+    width: usize,
+}
+
+impl Line {
+    fn new(index: usize, text: &str) -> Self {
+        let mut chars = Vec::with_capacity(text.len());
+        let mut width = 0;
+        for c in text.chars() {
+            chars.push((width, c));
+            width += get_char_width(c);
+        }
+
+        Self {
+            index,
+            chars,
+            width,
+        }
+    }
+
+    fn get(&self, pos: usize) -> Option<char> {
+        self.chars
+            .iter()
+            .find(|&&(p, c)| p == pos && get_char_width(c) > 0)
+            .map(|&(_, c)| c)
+    }
+
+    fn substring(&self, start: usize, end: usize) -> String {
+        self.chars
+            .iter()
+            .filter(|&&(pos, _)| pos >= start && pos < end)
+            .map(|&(_, c)| c)
+            .collect()
+    }
+}
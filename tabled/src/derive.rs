@@ -51,6 +51,58 @@
 /// }
 /// ```
 ///
+/// ### Column groups
+///
+/// You can tie a field to a named group by `#[tabled(group = "")]` (it can be set a few times).
+/// Then [`Tabled::headers_for`](crate::Tabled::headers_for) and
+/// [`Tabled::fields_for`](crate::Tabled::fields_for) return only fields
+/// which have no group or belong to any of the given groups.
+///
+/// `headers` and `fields` still return all fields.
+///
+/// ```
+/// use tabled::{builder::Builder, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Package {
+///    name: &'static str,
+///    #[tabled(group = "verbose")]
+///    license: &'static str,
+///    #[tabled(group = "verbose", group = "debug")]
+///    size: usize,
+/// }
+///
+/// let packages = [Package { name: "tabled", license: "MIT", size: 100 }];
+///
+/// let build = |groups: &[&str]| {
+///     let mut builder = Builder::new();
+///     builder.push_record(Package::headers_for(groups));
+///     for package in &packages {
+///         builder.push_record(package.fields_for(groups));
+///     }
+///
+///     builder.build().to_string()
+/// };
+///
+/// assert_eq!(
+///     build(&[]),
+///     "+--------+\n\
+///      | name   |\n\
+///      +--------+\n\
+///      | tabled |\n\
+///      +--------+"
+/// );
+///
+/// assert_eq!(
+///     build(&["verbose"]),
+///     "+--------+---------+------+\n\
+///      | name   | license | size |\n\
+///      +--------+---------+------+\n\
+///      | tabled | MIT     | 100  |\n\
+///      +--------+---------+------+"
+/// );
+/// ```
+///
 /// ### Set column order
 ///
 /// You can change the order in which they will be displayed in table.
@@ -134,6 +186,100 @@
 /// }
 /// ```
 ///
+/// ### Column styles
+///
+/// A column alignment, color and width can be set next to a field,
+/// using `#[tabled(align = "right")]`, `#[tabled(color = "red bold")]`
+/// and `#[tabled(max_width = 10)]` (add `wrap` to wrap a content instead of truncating it).
+///
+/// They are returned by [`Tabled::column_styles`](crate::Tabled::column_styles)
+/// and [`Table::new`](crate::Table::new) applies them, so they follow `order` and `inline` fields.
+/// The styles are applied to data rows only, so a header is left as is.
+///
+/// A color is a list of names separated by spaces,
+/// which are `black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan` and `white`
+/// with optional `bg_` and `bright_` prefixes, and `bold` or `underline`.
+///
+/// ```
+/// use tabled::{Table, Tabled};
+/// use testing_table::assert_table;
+///
+/// #[derive(Tabled)]
+/// struct Package {
+///     #[tabled(align = "center")]
+///     name: &'static str,
+///     #[tabled(max_width = 8, wrap)]
+///     description: &'static str,
+///     #[tabled(align = "right")]
+///     downloads: usize,
+/// }
+///
+/// let packages = [
+///     Package { name: "tabled", description: "A table library", downloads: 1000 },
+///     Package { name: "gd", description: "Go", downloads: 1 },
+/// ];
+///
+/// assert_table!(
+///     Table::new(packages),
+///     "+--------+-------------+-----------+"
+///     "| name   | description | downloads |"
+///     "+--------+-------------+-----------+"
+///     "| tabled | A table     |      1000 |"
+///     "|        | library     |           |"
+///     "+--------+-------------+-----------+"
+///     "|   gd   | Go          |         1 |"
+///     "+--------+-------------+-----------+"
+/// );
+/// ```
+///
+/// ### Options and collections
+///
+/// An `Option` can be shown with a placeholder for `None` by `#[tabled(option = "-")]`.
+///
+/// Any iterable field can be joined into a single line by `#[tabled(join = ", ")]`,
+/// or rendered as a nested [`Table`](crate::Table) by `#[tabled(table)]` if its items implement `Tabled`.
+///
+/// ```
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// struct Package {
+///     name: &'static str,
+///     #[tabled(option = "-")]
+///     license: Option<&'static str>,
+///     #[tabled(join = ", ")]
+///     keywords: Vec<&'static str>,
+///     #[tabled(table)]
+///     authors: Vec<Author>,
+/// }
+///
+/// #[derive(Tabled)]
+/// struct Author {
+///     name: &'static str,
+/// }
+///
+/// let package = Package {
+///     name: "tabled",
+///     license: None,
+///     keywords: vec!["table", "print"],
+///     authors: vec![Author { name: "zhiburt" }],
+/// };
+///
+/// assert_eq!(
+///     package.fields(),
+///     [
+///         "tabled",
+///         "-",
+///         "table, print",
+///         "+---------+\n\
+///          | name    |\n\
+///          +---------+\n\
+///          | zhiburt |\n\
+///          +---------+",
+///     ]
+/// );
+/// ```
+///
 /// ### Format headers
 ///
 /// Beside `#[tabled(rename = "")]` you can change a format of a column name using
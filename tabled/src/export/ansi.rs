@@ -0,0 +1,276 @@
+use crate::grid::util::string::get_char_width;
+
+/// A part of a text with the same style.
+///
+/// It's produced by [`parse_ansi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextRun {
+    /// A style of the text.
+    pub style: TextStyle,
+    /// A text without ANSI sequences.
+    pub text: String,
+    /// A width of the text.
+    pub width: usize,
+}
+
+/// A style set by ANSI SGR sequences.
+///
+/// # Example
+///
+/// ```
+/// use tabled::export::{Rgb, TextStyle};
+///
+/// let style = TextStyle::from_ansi("\u{1b}[1;31m");
+///
+/// assert!(style.bold);
+/// assert_eq!(style.fg, Some(Rgb(0xcd, 0x00, 0x00)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TextStyle {
+    /// A foreground color.
+    pub fg: Option<Rgb>,
+    /// A background color.
+    pub bg: Option<Rgb>,
+    /// A bold mode.
+    pub bold: bool,
+    /// A dim mode.
+    pub dim: bool,
+    /// An italic mode.
+    pub italic: bool,
+    /// An underline mode.
+    pub underline: bool,
+    /// A strikethrough mode.
+    pub strikethrough: bool,
+    /// An inverse mode.
+    pub inverse: bool,
+}
+
+impl TextStyle {
+    /// Returns a style set by ANSI sequences of a text,
+    /// e.g. by a prefix of a [`Color`].
+    ///
+    /// [`Color`]: crate::settings::Color
+    pub fn from_ansi(text: &str) -> Self {
+        let mut style = Self::default();
+        scan_ansi(text, &mut style, |_, _| {});
+        style
+    }
+
+    /// Applies parameters of a SGR sequence to a style.
+    ///
+    /// Unsupported parameters are ignored.
+    pub fn apply_sgr(&mut self, params: &str) {
+        let mut codes = params
+            .split([';', ':'])
+            .map(|code| code.parse::<u16>().unwrap_or(0));
+
+        // an empty sequence is a reset
+        if params.is_empty() {
+            *self = Self::default();
+            return;
+        }
+
+        while let Some(code) = codes.next() {
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                7 => self.inverse = true,
+                9 => self.strikethrough = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.inverse = false,
+                29 => self.strikethrough = false,
+                30..=37 => self.fg = Some(PALETTE[(code - 30) as usize]),
+                38 => self.fg = parse_extended_color(&mut codes),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some(PALETTE[(code - 40) as usize]),
+                48 => self.bg = parse_extended_color(&mut codes),
+                49 => self.bg = None,
+                90..=97 => self.fg = Some(PALETTE[(code - 90 + 8) as usize]),
+                100..=107 => self.bg = Some(PALETTE[(code - 100 + 8) as usize]),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// A RGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// Returns a color of a 256 colors palette as xterm shows it.
+    pub fn from_palette(i: u8) -> Self {
+        match i {
+            0..=15 => PALETTE[i as usize],
+            16..=231 => {
+                const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+                let i = i - 16;
+                Rgb(
+                    LEVELS[(i / 36) as usize],
+                    LEVELS[(i / 6 % 6) as usize],
+                    LEVELS[(i % 6) as usize],
+                )
+            }
+            _ => {
+                let level = 8 + (i - 232) * 10;
+                Rgb(level, level, level)
+            }
+        }
+    }
+
+    /// Returns a color in a `#rrggbb` form.
+    pub fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Standard colors as xterm shows them.
+const PALETTE: [Rgb; 16] = [
+    Rgb(0x00, 0x00, 0x00),
+    Rgb(0xcd, 0x00, 0x00),
+    Rgb(0x00, 0xcd, 0x00),
+    Rgb(0xcd, 0xcd, 0x00),
+    Rgb(0x00, 0x00, 0xee),
+    Rgb(0xcd, 0x00, 0xcd),
+    Rgb(0x00, 0xcd, 0xcd),
+    Rgb(0xe5, 0xe5, 0xe5),
+    Rgb(0x7f, 0x7f, 0x7f),
+    Rgb(0xff, 0x00, 0x00),
+    Rgb(0x00, 0xff, 0x00),
+    Rgb(0xff, 0xff, 0x00),
+    Rgb(0x5c, 0x5c, 0xff),
+    Rgb(0xff, 0x00, 0xff),
+    Rgb(0x00, 0xff, 0xff),
+    Rgb(0xff, 0xff, 0xff),
+];
+
+/// Splits a text into runs of the same style, dropping ANSI sequences.
+///
+/// Unsupported sequences are ignored.
+///
+/// # Example
+///
+/// ```
+/// use tabled::export::{parse_ansi, TextStyle};
+///
+/// let runs = parse_ansi("\u{1b}[1mHello\u{1b}[0m World");
+///
+/// assert_eq!(runs.len(), 2);
+/// assert_eq!(runs[0].text, "Hello");
+/// assert!(runs[0].style.bold);
+/// assert_eq!(runs[1].text, " World");
+/// assert_eq!(runs[1].style, TextStyle::default());
+/// ```
+pub fn parse_ansi(text: &str) -> Vec<TextRun> {
+    let mut runs: Vec<TextRun> = Vec::new();
+    let mut style = TextStyle::default();
+
+    scan_ansi(text, &mut style, |style, c| {
+        let width = get_char_width(c);
+        match runs.last_mut() {
+            Some(run) if run.style == *style => {
+                run.text.push(c);
+                run.width += width;
+            }
+            _ => runs.push(TextRun {
+                style: *style,
+                text: c.to_string(),
+                width,
+            }),
+        }
+    });
+
+    runs
+}
+
+/// Escapes characters which are special in XML and HTML.
+///
+/// # Example
+///
+/// ```
+/// use tabled::export::escape_xml;
+///
+/// assert_eq!(escape_xml("<a href=\"#\">&</a>"), "&lt;a href=&quot;#&quot;&gt;&amp;&lt;/a&gt;");
+/// ```
+pub fn escape_xml(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' => buf.push_str("&quot;"),
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+/// Goes through a text applying SGR sequences to a style,
+/// and calling a function for each character which is not a part of a sequence.
+fn scan_ansi<F>(text: &str, style: &mut TextStyle, mut f: F)
+where
+    F: FnMut(&TextStyle, char),
+{
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            f(style, c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('[') => {
+                let mut params = String::new();
+                for c in chars.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&c) {
+                        if c == 'm' {
+                            style.apply_sgr(&params);
+                        }
+
+                        break;
+                    }
+
+                    params.push(c);
+                }
+            }
+            Some(']') => {
+                // an OSC sequence (like a hyperlink) ends with BEL or ST
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+
+                    if c == '\u{1b}' && chars.peek() == Some(&'\\') {
+                        let _ = chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parses a color of `38;5;n` and `38;2;r;g;b` forms.
+fn parse_extended_color<I>(codes: &mut I) -> Option<Rgb>
+where
+    I: Iterator<Item = u16>,
+{
+    let mut next = || codes.next().map(|code| code.min(255) as u8);
+
+    match next()? {
+        5 => next().map(Rgb::from_palette),
+        2 => Some(Rgb(next()?, next()?, next()?)),
+        _ => None,
+    }
+}
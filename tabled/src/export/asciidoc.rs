@@ -0,0 +1,116 @@
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, Entity},
+        records::{ExactRecords, PeekableRecords, Records},
+        util::string::get_lines,
+    },
+    util::string::strip_ansi,
+    Table,
+};
+
+/// Builds an AsciiDoc table.
+///
+/// The first row is used as a header.
+pub(crate) fn build_asciidoc(table: &Table) -> String {
+    if table.is_empty() {
+        return String::new();
+    }
+
+    let records = table.get_records();
+    let cfg = table.get_config();
+
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+
+    // an alignment is taken from a first data row as a header is often styled differently
+    let alignment_row = if count_rows > 1 { 1 } else { 0 };
+    let alignments = (0..count_columns)
+        .map(|col| *cfg.get_alignment_horizontal(Entity::Cell(alignment_row, col)))
+        .collect::<Vec<_>>();
+
+    let cols = alignments
+        .iter()
+        .map(|&alignment| alignment_spec(alignment))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut buf = String::new();
+    buf.push_str("[cols=\"");
+    buf.push_str(&cols);
+    buf.push('"');
+    if count_rows > 1 {
+        buf.push_str(",options=\"header\"");
+    }
+    buf.push_str("]\n");
+    buf.push_str("|===\n");
+
+    for row in 0..count_rows {
+        if row == 1 {
+            buf.push('\n');
+        }
+
+        let mut is_first = true;
+        for (col, &column_alignment) in alignments.iter().enumerate() {
+            let pos = (row, col);
+            if !cfg.is_cell_visible(pos) {
+                continue;
+            }
+
+            if !is_first {
+                buf.push(' ');
+            }
+            is_first = false;
+
+            let col_span = cfg
+                .get_column_span(pos)
+                .unwrap_or(1)
+                .min(count_columns - col);
+            let row_span = cfg.get_row_span(pos).unwrap_or(1).min(count_rows - row);
+            match (col_span > 1, row_span > 1) {
+                (true, true) => buf.push_str(&format!("{}.{}+", col_span, row_span)),
+                (true, false) => buf.push_str(&format!("{}+", col_span)),
+                (false, true) => buf.push_str(&format!(".{}+", row_span)),
+                (false, false) => {}
+            }
+
+            let alignment = *cfg.get_alignment_horizontal(Entity::Cell(row, col));
+            if alignment != column_alignment {
+                buf.push_str(alignment_spec(alignment));
+            }
+
+            buf.push('|');
+            push_text(&mut buf, &strip_ansi(records.get_text(pos)));
+        }
+
+        buf.push('\n');
+    }
+
+    buf.push_str("|===");
+
+    buf
+}
+
+fn alignment_spec(alignment: AlignmentHorizontal) -> &'static str {
+    match alignment {
+        AlignmentHorizontal::Left => "<",
+        AlignmentHorizontal::Center => "^",
+        AlignmentHorizontal::Right => ">",
+    }
+}
+
+/// Escapes `|` and joins lines with a hard line break.
+fn push_text(buf: &mut String, text: &str) {
+    for (i, line) in get_lines(text).enumerate() {
+        if i > 0 {
+            buf.push_str(" +\n");
+        }
+
+        for c in line.chars() {
+            if c == '|' {
+                buf.push('\\');
+            }
+
+            buf.push(c);
+        }
+    }
+}
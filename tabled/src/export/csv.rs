@@ -0,0 +1,278 @@
+use std::io;
+
+use crate::{
+    grid::{
+        config::Position,
+        records::{ExactRecords, IntoRecords, PeekableRecords, Records},
+    },
+    util::string::strip_ansi,
+    Table,
+};
+
+/// [`CsvWriter`] exports a [`Table`] or any [`Records`] as CSV or TSV.
+///
+/// By default it uses `,` as a delimiter, `"` as a quote,
+/// quotes only fields which need it and keeps a first row (a header).
+/// Each record ends with a new line.
+///
+/// Spanned cells of a [`Table`] are expanded according to [`SpanFill`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{export::CsvWriter, Table};
+///
+/// let table = Table::new([("Rust", "Graydon Hoare, et al."), ("Go", "Rob \"Commander\" Pike")]);
+///
+/// assert_eq!(
+///     CsvWriter::new().format(&table),
+///     "&str,&str\n\
+///      Rust,\"Graydon Hoare, et al.\"\n\
+///      Go,\"Rob \"\"Commander\"\" Pike\"\n"
+/// );
+///
+/// assert_eq!(
+///     CsvWriter::tsv().header(false).format(&table),
+///     "Rust\tGraydon Hoare, et al.\n\
+///      Go\t\"Rob \"\"Commander\"\" Pike\"\n"
+/// );
+/// ```
+///
+/// A [`Builder`] can be exported as records.
+///
+/// ```
+/// use tabled::{builder::Builder, export::CsvWriter, grid::records::IterRecords};
+///
+/// let mut builder = Builder::new();
+/// builder.push_record(["name", "year"]);
+/// builder.push_record(["C", "1972"]);
+///
+/// let data: Vec<Vec<String>> = builder.into();
+/// let records = IterRecords::new(&data, 2, None);
+///
+/// assert_eq!(CsvWriter::new().format_records(records), "name,year\nC,1972\n");
+/// ```
+///
+/// [`Builder`]: crate::builder::Builder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvWriter {
+    delimiter: char,
+    quote: char,
+    quote_style: QuoteStyle,
+    header: bool,
+    span_fill: SpanFill,
+    strip_ansi: bool,
+}
+
+/// A rule of quoting fields used by [`CsvWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuoteStyle {
+    /// Quote only fields which contain a delimiter, a quote or a new line.
+    Necessary,
+    /// Quote all fields.
+    Always,
+    /// Never quote fields.
+    ///
+    /// It may produce an invalid output, if a field contains special characters.
+    Never,
+}
+
+/// A way [`CsvWriter`] fills cells which are covered by a span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpanFill {
+    /// Leave covered cells empty.
+    Empty,
+    /// Copy a text of a spanned cell into all covered cells.
+    Duplicate,
+}
+
+impl CsvWriter {
+    /// Creates a CSV writer, which uses `,` as a delimiter.
+    pub const fn new() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+            quote_style: QuoteStyle::Necessary,
+            header: true,
+            span_fill: SpanFill::Empty,
+            strip_ansi: false,
+        }
+    }
+
+    /// Creates a TSV writer, which uses `\t` as a delimiter.
+    pub const fn tsv() -> Self {
+        Self::new().delimiter('\t')
+    }
+
+    /// Sets a delimiter.
+    pub const fn delimiter(mut self, c: char) -> Self {
+        self.delimiter = c;
+        self
+    }
+
+    /// Sets a quote character.
+    pub const fn quote(mut self, c: char) -> Self {
+        self.quote = c;
+        self
+    }
+
+    /// Sets a quoting rule.
+    pub const fn quote_style(mut self, style: QuoteStyle) -> Self {
+        self.quote_style = style;
+        self
+    }
+
+    /// Sets whether a first row is written.
+    pub const fn header(mut self, on: bool) -> Self {
+        self.header = on;
+        self
+    }
+
+    /// Sets how cells covered by a span are filled.
+    pub const fn span_fill(mut self, fill: SpanFill) -> Self {
+        self.span_fill = fill;
+        self
+    }
+
+    /// Sets whether ANSI sequences are removed from fields.
+    #[cfg(feature = "ansi")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ansi")))]
+    pub const fn strip_ansi(mut self, on: bool) -> Self {
+        self.strip_ansi = on;
+        self
+    }
+
+    /// Builds a CSV out of a [`Table`].
+    pub fn format(&self, table: &Table) -> String {
+        let records = table.get_records();
+        let cfg = table.get_config();
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        // a position of a cell which is shown at each position
+        let mut owners = (0..count_rows)
+            .map(|row| (0..count_columns).map(|col| (row, col)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let is_inside = |pos: Position| pos.0 < count_rows && pos.1 < count_columns;
+        for (pos, span) in cfg.get_column_spans() {
+            if span < 2 || !is_inside(pos) {
+                continue;
+            }
+
+            let end = std::cmp::min(pos.1 + span, count_columns);
+            for owner in &mut owners[pos.0][pos.1 + 1..end] {
+                *owner = pos;
+            }
+        }
+        for (pos, span) in cfg.get_row_spans() {
+            if span < 2 || !is_inside(pos) {
+                continue;
+            }
+
+            let col_span = cfg.get_column_span(pos).unwrap_or(1).max(1);
+            let end_row = std::cmp::min(pos.0 + span, count_rows);
+            let end_col = std::cmp::min(pos.1 + col_span, count_columns);
+            for cells in &mut owners[pos.0 + 1..end_row] {
+                for owner in &mut cells[pos.1..end_col] {
+                    *owner = pos;
+                }
+            }
+        }
+
+        let get_text = |owner: Position, pos: Position| {
+            if owner != pos && self.span_fill == SpanFill::Empty {
+                return "";
+            }
+
+            records.get_text(owner)
+        };
+
+        let mut buf = String::new();
+        let skip = if self.header { 0 } else { 1 };
+        for (row, cells) in owners.iter().enumerate().skip(skip) {
+            let fields = cells
+                .iter()
+                .enumerate()
+                .map(|(col, &owner)| get_text(owner, (row, col)));
+
+            self.push_record(&mut buf, fields);
+        }
+
+        buf
+    }
+
+    /// Builds a CSV out of [`Records`].
+    pub fn format_records<R>(&self, records: R) -> String
+    where
+        R: Records,
+        <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    {
+        let mut buf = String::new();
+        let skip = if self.header { 0 } else { 1 };
+        for cells in records.iter_rows().into_iter().skip(skip) {
+            let cells = cells.into_iter().collect::<Vec<_>>();
+            self.push_record(&mut buf, cells.iter().map(|cell| cell.as_ref()));
+        }
+
+        buf
+    }
+
+    /// Writes a CSV out of a [`Table`] into a writer.
+    pub fn write<W>(&self, mut writer: W, table: &Table) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        writer.write_all(self.format(table).as_bytes())
+    }
+
+    fn push_record<'a, I>(&self, buf: &mut String, fields: I)
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        for (i, field) in fields.enumerate() {
+            if i > 0 {
+                buf.push(self.delimiter);
+            }
+
+            if self.strip_ansi {
+                self.push_field(buf, &strip_ansi(field));
+            } else {
+                self.push_field(buf, field);
+            }
+        }
+
+        buf.push('\n');
+    }
+
+    fn push_field(&self, buf: &mut String, field: &str) {
+        let is_quoted = match self.quote_style {
+            QuoteStyle::Always => true,
+            QuoteStyle::Never => false,
+            QuoteStyle::Necessary => field
+                .chars()
+                .any(|c| c == self.delimiter || c == self.quote || c == '\n' || c == '\r'),
+        };
+
+        if !is_quoted {
+            buf.push_str(field);
+            return;
+        }
+
+        buf.push(self.quote);
+        for c in field.chars() {
+            if c == self.quote {
+                buf.push(self.quote);
+            }
+
+            buf.push(c);
+        }
+        buf.push(self.quote);
+    }
+}
+
+impl Default for CsvWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
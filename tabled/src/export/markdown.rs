@@ -0,0 +1,158 @@
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, Entity},
+        records::{ExactRecords, PeekableRecords, Records},
+        util::string::get_line_width,
+    },
+    util::string::strip_ansi,
+    Table,
+};
+
+/// A minimum number of dashes in a delimiter row.
+const MIN_WIDTH: usize = 3;
+
+/// Builds a GitHub Flavored Markdown table.
+///
+/// The first row is used as a header.
+pub(crate) fn build_markdown(table: &Table) -> String {
+    if table.is_empty() {
+        return String::new();
+    }
+
+    let records = table.get_records();
+    let cfg = table.get_config();
+
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+
+    let cells = (0..count_rows)
+        .map(|row| {
+            (0..count_columns)
+                .map(|col| {
+                    // a spanned cell is shown only once, covered cells are left empty
+                    if !cfg.is_cell_visible((row, col)) {
+                        return String::new();
+                    }
+
+                    escape_text(&strip_ansi(records.get_text((row, col))))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // an alignment is taken from a first data row as a header is often styled differently
+    let alignment_row = if count_rows > 1 { 1 } else { 0 };
+    let alignments = (0..count_columns)
+        .map(|col| *cfg.get_alignment_horizontal(Entity::Cell(alignment_row, col)))
+        .collect::<Vec<_>>();
+
+    let widths = (0..count_columns)
+        .map(|col| {
+            cells
+                .iter()
+                .map(|row| get_line_width(&row[col]))
+                .max()
+                .unwrap_or(0)
+                .max(MIN_WIDTH)
+        })
+        .collect::<Vec<_>>();
+
+    let mut buf = String::new();
+    for (row, cells) in cells.iter().enumerate() {
+        if row == 1 {
+            push_delimiter_row(&mut buf, &widths, &alignments);
+        }
+
+        push_row(&mut buf, cells, &widths, &alignments);
+    }
+
+    if count_rows == 1 {
+        push_delimiter_row(&mut buf, &widths, &alignments);
+    }
+
+    // remove a trailing new line as a table doesn't have it
+    let _ = buf.pop();
+
+    buf
+}
+
+fn push_row(
+    buf: &mut String,
+    cells: &[String],
+    widths: &[usize],
+    alignments: &[AlignmentHorizontal],
+) {
+    buf.push('|');
+
+    for ((text, &width), &alignment) in cells.iter().zip(widths).zip(alignments) {
+        let available = width - get_line_width(text);
+        let (left, right) = match alignment {
+            AlignmentHorizontal::Left => (0, available),
+            AlignmentHorizontal::Right => (available, 0),
+            AlignmentHorizontal::Center => (available / 2, available - available / 2),
+        };
+
+        buf.push(' ');
+        push_chars(buf, ' ', left);
+        buf.push_str(text);
+        push_chars(buf, ' ', right);
+        buf.push_str(" |");
+    }
+
+    buf.push('\n');
+}
+
+fn push_delimiter_row(buf: &mut String, widths: &[usize], alignments: &[AlignmentHorizontal]) {
+    buf.push('|');
+
+    for (&width, &alignment) in widths.iter().zip(alignments) {
+        buf.push(' ');
+
+        match alignment {
+            AlignmentHorizontal::Left => {
+                push_chars(buf, '-', width);
+            }
+            AlignmentHorizontal::Right => {
+                push_chars(buf, '-', width - 1);
+                buf.push(':');
+            }
+            AlignmentHorizontal::Center => {
+                buf.push(':');
+                push_chars(buf, '-', width - 2);
+                buf.push(':');
+            }
+        }
+
+        buf.push_str(" |");
+    }
+
+    buf.push('\n');
+}
+
+fn push_chars(buf: &mut String, c: char, n: usize) {
+    for _ in 0..n {
+        buf.push(c);
+    }
+}
+
+/// Escapes characters which would break a table,
+/// and replaces new lines with `<br>` as a cell must be a single line.
+fn escape_text(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    let mut lines = text.lines().peekable();
+    while let Some(line) = lines.next() {
+        for c in line.chars() {
+            if c == '|' {
+                buf.push('\\');
+            }
+
+            buf.push(c);
+        }
+
+        if lines.peek().is_some() {
+            buf.push_str("<br>");
+        }
+    }
+
+    buf
+}
@@ -0,0 +1,33 @@
+//! Module contains exporters of a [`Table`] into other formats.
+//!
+//! Unlike [`Style`]s which only draw a table to look like a format,
+//! exporters produce a valid document of the format.
+//!
+//! - [`Table::to_markdown`] builds a GitHub Flavored Markdown table.
+//! - [`Table::to_rst`] builds a reStructuredText grid table.
+//! - [`Table::to_asciidoc`] builds an AsciiDoc table.
+//! - [`CsvWriter`] builds a CSV or a TSV.
+//! - [`SvgWriter`] builds an SVG image of a table as it looks in a terminal.
+//!
+//! It also provides helpers to translate ANSI styled text for such formats, see [`parse_ansi`].
+//!
+//! [`Table`]: crate::Table
+//! [`Style`]: crate::settings::Style
+//! [`Table::to_markdown`]: crate::Table::to_markdown
+//! [`Table::to_rst`]: crate::Table::to_rst
+//! [`Table::to_asciidoc`]: crate::Table::to_asciidoc
+
+mod ansi;
+mod asciidoc;
+mod csv;
+mod markdown;
+mod rst;
+mod svg;
+
+pub use ansi::{escape_xml, parse_ansi, Rgb, TextRun, TextStyle};
+pub use csv::{CsvWriter, QuoteStyle, SpanFill};
+pub use svg::SvgWriter;
+
+pub(crate) use asciidoc::build_asciidoc;
+pub(crate) use markdown::build_markdown;
+pub(crate) use rst::build_rst;
@@ -0,0 +1,201 @@
+use crate::{
+    grid::{
+        config::Position,
+        records::{ExactRecords, PeekableRecords, Records},
+        util::string::{get_line_width, get_lines},
+    },
+    util::string::strip_ansi,
+    Table,
+};
+
+/// Builds a reStructuredText grid table.
+///
+/// The first row is used as a header.
+pub(crate) fn build_rst(table: &Table) -> String {
+    if table.is_empty() {
+        return String::new();
+    }
+
+    let records = table.get_records();
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+
+    let cells = collect_cells(table);
+
+    let mut widths = vec![1; count_columns];
+    let mut heights = vec![1; count_rows];
+    for cell in cells.iter().filter(|cell| cell.span == (1, 1)) {
+        let (row, col) = cell.pos;
+        widths[col] = widths[col].max(cell.width());
+        heights[row] = heights[row].max(cell.lines.len());
+    }
+
+    // spanned cells take a border between rows or columns as a space,
+    // so only the rest is added to the last row or column
+    for cell in cells.iter().filter(|cell| cell.span.1 > 1) {
+        let (_, col) = cell.pos;
+        let end = col + cell.span.1;
+        let available = widths[col..end].iter().sum::<usize>() + 3 * (cell.span.1 - 1);
+        if cell.width() > available {
+            widths[end - 1] += cell.width() - available;
+        }
+    }
+    for cell in cells.iter().filter(|cell| cell.span.0 > 1) {
+        let (row, _) = cell.pos;
+        let end = row + cell.span.0;
+        let available = heights[row..end].iter().sum::<usize>() + cell.span.0 - 1;
+        if cell.lines.len() > available {
+            heights[end - 1] += cell.lines.len() - available;
+        }
+    }
+
+    let xs = offsets(&widths, 3);
+    let ys = offsets(&heights, 1);
+
+    let mut canvas = vec![vec![String::from(" "); xs[count_columns] + 1]; ys[count_rows] + 1];
+
+    // a header is separated by `=`
+    let header_line = if count_rows > 1 { Some(ys[1]) } else { None };
+
+    for cell in &cells {
+        let (row, col) = cell.pos;
+        let (top, bottom) = (ys[row], ys[row + cell.span.0]);
+        let (left, right) = (xs[col], xs[col + cell.span.1]);
+
+        for y in [top, bottom] {
+            let c = if Some(y) == header_line { "=" } else { "-" };
+            for x in &mut canvas[y][left + 1..right] {
+                *x = String::from(c);
+            }
+        }
+
+        for x in [left, right] {
+            for line in &mut canvas[top + 1..bottom] {
+                line[x] = String::from("|");
+            }
+        }
+
+        let width = right - left - 3;
+        for (i, text) in cell.lines.iter().enumerate() {
+            let line = &mut canvas[top + 1 + i];
+
+            let mut content = text.clone();
+            push_chars(&mut content, ' ', width - get_line_width(text));
+            line[left + 2] = content;
+
+            for x in &mut line[left + 3..right - 1] {
+                x.clear();
+            }
+        }
+    }
+
+    for &y in &ys {
+        for &x in &xs {
+            canvas[y][x] = junction(&canvas, y, x);
+        }
+    }
+
+    let mut buf = String::new();
+    for (i, line) in canvas.iter().enumerate() {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        for s in line {
+            buf.push_str(s);
+        }
+    }
+
+    buf
+}
+
+struct RstCell {
+    pos: Position,
+    span: (usize, usize),
+    lines: Vec<String>,
+}
+
+impl RstCell {
+    fn width(&self) -> usize {
+        self.lines
+            .iter()
+            .map(|line| get_line_width(line))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn collect_cells(table: &Table) -> Vec<RstCell> {
+    let records = table.get_records();
+    let cfg = table.get_config();
+
+    let count_rows = records.count_rows();
+    let count_columns = records.count_columns();
+
+    let mut cells = Vec::new();
+    for row in 0..count_rows {
+        for col in 0..count_columns {
+            let pos = (row, col);
+            if !cfg.is_cell_visible(pos) {
+                continue;
+            }
+
+            let row_span = cfg.get_row_span(pos).unwrap_or(1).max(1);
+            let col_span = cfg.get_column_span(pos).unwrap_or(1).max(1);
+            let span = (
+                row_span.min(count_rows - row),
+                col_span.min(count_columns - col),
+            );
+
+            let text = strip_ansi(records.get_text(pos));
+            let lines = get_lines(&text)
+                .map(|line| line.trim_end().to_owned())
+                .collect();
+
+            cells.push(RstCell { pos, span, lines });
+        }
+    }
+
+    cells
+}
+
+fn offsets(sizes: &[usize], border: usize) -> Vec<usize> {
+    let mut list = Vec::with_capacity(sizes.len() + 1);
+    list.push(0);
+
+    let mut offset = 0;
+    for size in sizes {
+        offset += size + border;
+        list.push(offset);
+    }
+
+    list
+}
+
+/// Returns a character of a crossing of borders.
+fn junction(canvas: &[Vec<String>], y: usize, x: usize) -> String {
+    let is_horizontal = |s: &String| s == "-" || s == "=";
+    let is_vertical = |s: &String| s == "|";
+
+    let line = &canvas[y];
+    let left = x
+        .checked_sub(1)
+        .map(|x| &line[x])
+        .filter(|s| is_horizontal(s));
+    let right = line.get(x + 1).filter(|s| is_horizontal(s));
+    let up = y > 0 && is_vertical(&canvas[y - 1][x]);
+    let down = y + 1 < canvas.len() && is_vertical(&canvas[y + 1][x]);
+
+    match (left.or(right), up || down) {
+        (Some(_), true) => String::from("+"),
+        (Some(horizontal), false) => horizontal.clone(),
+        (None, true) => String::from("|"),
+        (None, false) => String::from(" "),
+    }
+}
+
+fn push_chars(buf: &mut String, c: char, n: usize) {
+    for _ in 0..n {
+        buf.push(c);
+    }
+}
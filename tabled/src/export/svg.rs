@@ -0,0 +1,247 @@
+use std::fmt::Write;
+
+use crate::Table;
+
+use super::ansi::{escape_xml, parse_ansi, Rgb, TextRun};
+
+/// [`SvgWriter`] renders a [`Table`] into a standalone SVG document.
+///
+/// A table is rendered as it's printed into a terminal,
+/// so borders, padding, margins and spans look the same.
+/// Each character takes a fixed cell of a monospace grid,
+/// a wide character takes 2 cells.
+///
+/// ANSI colors (set by [`Color`] or put into a text) are translated into fills,
+/// bold, dim, italic, underline, strikethrough and inverse modes are supported.
+///
+/// The output doesn't depend on an environment, so it can be used in snapshot tests.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{export::SvgWriter, settings::{Color, Style}, Table};
+///
+/// let mut table = Table::new(["<a>"]);
+/// table.with(Style::blank()).with(Color::FG_RED);
+///
+/// assert_eq!(
+///     SvgWriter::new().format(&table),
+///     concat!(
+///         "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"48\" height=\"32\" viewBox=\"0 0 48 32\">\n",
+///         "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n",
+///         "<g font-family=\"monospace\" font-size=\"14\" fill=\"#000000\" xml:space=\"preserve\">\n",
+///         "<text x=\"8\" y=\"12\" textLength=\"32\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#cd0000\">&amp;str</text>\n",
+///         "<text x=\"8\" y=\"28\" textLength=\"24\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#cd0000\">&lt;a&gt;</text>\n",
+///         "</g>\n",
+///         "</svg>",
+///     ),
+/// );
+/// ```
+///
+/// [`Color`]: crate::settings::Color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvgWriter {
+    font_family: String,
+    font_size: usize,
+    cell_width: usize,
+    cell_height: usize,
+    foreground: String,
+    background: Option<String>,
+}
+
+impl SvgWriter {
+    /// Creates a writer with a 14px monospace font on a white background.
+    pub fn new() -> Self {
+        Self {
+            font_family: String::from("monospace"),
+            font_size: 14,
+            cell_width: 8,
+            cell_height: 16,
+            foreground: String::from("#000000"),
+            background: Some(String::from("#ffffff")),
+        }
+    }
+
+    /// Sets a font family.
+    pub fn font_family<S>(mut self, family: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.font_family = family.into();
+        self
+    }
+
+    /// Sets a font size in pixels.
+    pub fn font_size(mut self, size: usize) -> Self {
+        self.font_size = size;
+        self
+    }
+
+    /// Sets a size of a single character cell in pixels.
+    pub fn cell_size(mut self, width: usize, height: usize) -> Self {
+        self.cell_width = width;
+        self.cell_height = height;
+        self
+    }
+
+    /// Sets a default text color.
+    ///
+    /// It can be any SVG color like `#aabbcc` or `black`.
+    pub fn foreground<S>(mut self, color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.foreground = color.into();
+        self
+    }
+
+    /// Sets a background color.
+    ///
+    /// It can be any SVG color like `#aabbcc` or `black`.
+    pub fn background<S>(mut self, color: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Makes a background transparent.
+    pub fn transparent(mut self) -> Self {
+        self.background = None;
+        self
+    }
+
+    /// Renders a [`Table`] into SVG.
+    pub fn format(&self, table: &Table) -> String {
+        self.format_text(&table.to_string())
+    }
+
+    /// Renders a text with ANSI sequences into SVG.
+    ///
+    /// It can be used for tables which were already rendered.
+    pub fn format_text(&self, text: &str) -> String {
+        let lines = text.lines().map(parse_ansi).collect::<Vec<_>>();
+
+        let count_columns = lines
+            .iter()
+            .map(|runs| runs.iter().map(|run| run.width).sum::<usize>())
+            .max()
+            .unwrap_or(0);
+
+        let width = count_columns * self.cell_width;
+        let height = lines.len() * self.cell_height;
+
+        let mut buf = String::new();
+        let _ = writeln!(
+            buf,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+            width, height, width, height
+        );
+
+        if let Some(background) = &self.background {
+            let _ = writeln!(
+                buf,
+                "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+                escape_xml(background)
+            );
+        }
+
+        let _ = writeln!(
+            buf,
+            "<g font-family=\"{}\" font-size=\"{}\" fill=\"{}\" xml:space=\"preserve\">",
+            escape_xml(&self.font_family),
+            self.font_size,
+            escape_xml(&self.foreground)
+        );
+
+        for (i, runs) in lines.iter().enumerate() {
+            self.push_line(&mut buf, i, runs);
+        }
+
+        buf.push_str("</g>\n</svg>");
+
+        buf
+    }
+
+    fn push_line(&self, buf: &mut String, line: usize, runs: &[TextRun]) {
+        let y = line * self.cell_height;
+        // put a baseline at 3/4 of a cell so descenders fit
+        let baseline = y + self.cell_height - self.cell_height / 4;
+
+        let mut column = 0;
+        for run in runs {
+            let x = column * self.cell_width;
+            let width = run.width * self.cell_width;
+            column += run.width;
+
+            let (fg, bg) = if run.style.inverse {
+                let fg = run.style.bg.map(Rgb::to_hex);
+                let bg = run.style.fg.map(Rgb::to_hex);
+                let fg = fg.unwrap_or_else(|| self.get_background().to_owned());
+                let bg = bg.unwrap_or_else(|| self.foreground.clone());
+                (Some(fg), Some(bg))
+            } else {
+                (run.style.fg.map(Rgb::to_hex), run.style.bg.map(Rgb::to_hex))
+            };
+
+            if let Some(bg) = bg {
+                let _ = writeln!(
+                    buf,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    x,
+                    y,
+                    width,
+                    self.cell_height,
+                    escape_xml(&bg)
+                );
+            }
+
+            let is_decorated = run.style.underline || run.style.strikethrough;
+            if run.text.trim().is_empty() && !is_decorated {
+                continue;
+            }
+
+            let _ = write!(
+                buf,
+                "<text x=\"{}\" y=\"{}\" textLength=\"{}\" lengthAdjust=\"spacingAndGlyphs\"",
+                x, baseline, width
+            );
+
+            if let Some(fg) = fg {
+                let _ = write!(buf, " fill=\"{}\"", escape_xml(&fg));
+            }
+
+            if run.style.bold {
+                buf.push_str(" font-weight=\"bold\"");
+            }
+
+            if run.style.italic {
+                buf.push_str(" font-style=\"italic\"");
+            }
+
+            if run.style.dim {
+                buf.push_str(" opacity=\"0.5\"");
+            }
+
+            match (run.style.underline, run.style.strikethrough) {
+                (true, true) => buf.push_str(" text-decoration=\"underline line-through\""),
+                (true, false) => buf.push_str(" text-decoration=\"underline\""),
+                (false, true) => buf.push_str(" text-decoration=\"line-through\""),
+                (false, false) => {}
+            }
+
+            let _ = writeln!(buf, ">{}</text>", escape_xml(&run.text));
+        }
+    }
+
+    fn get_background(&self) -> &str {
+        self.background.as_deref().unwrap_or("#ffffff")
+    }
+}
+
+impl Default for SvgWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
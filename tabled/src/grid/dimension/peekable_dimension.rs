@@ -3,15 +3,23 @@ use crate::grid::{
     dimension::{Dimension, Estimate},
     records::vec_records::{Text, VecRecords},
     records::Records,
+    util::{
+        string::get_line_width,
+        width::{UnicodeWidth, WidthMetric},
+    },
 };
 
 /// PeekableDimension is a [`Dimension`] implementation for a [`Table`]
 ///
+/// By default it relies on widths cached in [`Text`],
+/// but it can be set to measure cells by a given [`WidthMetric`].
+///
 /// [`Table`]: crate::Table
-#[derive(Debug, Default, Clone)]
-pub struct PeekableDimension {
+#[derive(Debug, Clone)]
+pub struct PeekableDimension<M = UnicodeWidth> {
     width: Vec<usize>,
     height: Vec<usize>,
+    metric: Option<M>,
 }
 
 impl PeekableDimension {
@@ -22,7 +30,29 @@ impl PeekableDimension {
 
     /// Calculates width of columns.
     pub fn width<T: AsRef<str>>(records: &VecRecords<Text<T>>, cfg: &SpannedConfig) -> Vec<usize> {
-        estimation::build_width(records, cfg)
+        estimation::build_width(records, cfg, None::<&UnicodeWidth>)
+    }
+}
+
+impl Default for PeekableDimension {
+    fn default() -> Self {
+        Self {
+            width: Vec::new(),
+            height: Vec::new(),
+            metric: None,
+        }
+    }
+}
+
+impl<M> PeekableDimension<M> {
+    /// Creates a new dimension which measures cells by a given [`WidthMetric`]
+    /// instead of using cached widths.
+    pub fn with_metric(metric: M) -> Self {
+        Self {
+            width: Vec::new(),
+            height: Vec::new(),
+            metric: Some(metric),
+        }
     }
 
     /// Return width and height lists.
@@ -31,7 +61,10 @@ impl PeekableDimension {
     }
 }
 
-impl Dimension for PeekableDimension {
+impl<M> Dimension for PeekableDimension<M>
+where
+    M: WidthMetric,
+{
     fn get_width(&self, column: usize) -> usize {
         self.width[column]
     }
@@ -39,14 +72,22 @@ impl Dimension for PeekableDimension {
     fn get_height(&self, row: usize) -> usize {
         self.height[row]
     }
+
+    fn get_line_width(&self, line: &str) -> usize {
+        match &self.metric {
+            Some(metric) => metric.line_width(line),
+            None => get_line_width(line),
+        }
+    }
 }
 
-impl<T> Estimate<&VecRecords<Text<T>>, SpannedConfig> for PeekableDimension
+impl<T, M> Estimate<&VecRecords<Text<T>>, SpannedConfig> for PeekableDimension<M>
 where
     T: AsRef<str>,
+    M: WidthMetric,
 {
     fn estimate(&mut self, records: &VecRecords<Text<T>>, cfg: &SpannedConfig) {
-        let (width, height) = estimation::build_dimensions(records, cfg);
+        let (width, height) = estimation::build_dimensions(records, cfg, self.metric.as_ref());
         self.width = width;
         self.height = height;
     }
@@ -60,9 +101,10 @@ mod estimation {
 
     use super::*;
 
-    pub(super) fn build_dimensions<T: AsRef<str>>(
+    pub(super) fn build_dimensions<T: AsRef<str>, M: WidthMetric>(
         records: &VecRecords<Text<T>>,
         cfg: &SpannedConfig,
+        metric: Option<&M>,
     ) -> (Vec<usize>, Vec<usize>) {
         let count_columns = records.count_columns();
 
@@ -81,7 +123,7 @@ mod estimation {
                 }
 
                 let height = cell.count_lines();
-                let width = cell.width();
+                let width = get_cell_width(cell, metric);
 
                 let pad = cfg.get_padding(pos.into());
                 let width = width + pad.left.size + pad.right.size;
@@ -113,6 +155,13 @@ mod estimation {
         (widths, heights)
     }
 
+    fn get_cell_width<T: AsRef<str>, M: WidthMetric>(cell: &Text<T>, metric: Option<&M>) -> usize {
+        match metric {
+            Some(metric) => metric.text_width(cell.text()),
+            None => cell.width(),
+        }
+    }
+
     fn adjust_hspans(
         cfg: &SpannedConfig,
         len: usize,
@@ -292,9 +341,10 @@ mod estimation {
         heights
     }
 
-    pub(super) fn build_width<T: AsRef<str>>(
+    pub(super) fn build_width<T: AsRef<str>, M: WidthMetric>(
         records: &VecRecords<Text<T>>,
         cfg: &SpannedConfig,
+        metric: Option<&M>,
     ) -> Vec<usize> {
         let count_columns = records.count_columns();
 
@@ -308,7 +358,7 @@ mod estimation {
                     continue;
                 }
 
-                let width = cell.width();
+                let width = get_cell_width(cell, metric);
                 match cfg.get_column_span(pos) {
                     Some(n) if n > 1 => {
                         let _ = vspans.insert(pos, (n, width));
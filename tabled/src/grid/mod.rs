@@ -15,6 +15,10 @@ pub use papergrid::util;
 
 pub use papergrid::grid::compact::CompactGrid;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use papergrid::grid::layout;
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use papergrid::grid::iterable::Grid;
@@ -288,6 +288,9 @@ mod tabled;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod builder;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod export;
 pub mod grid;
 pub mod settings;
 pub mod tables;
@@ -0,0 +1,396 @@
+//! This module contains an [`Aggregate`] setting which appends a row computed from columns of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{
+//!     builder::Builder,
+//!     settings::{Aggregate, Style, location::Locator, style::HorizontalLine},
+//! };
+//! use testing_table::assert_table;
+//!
+//! let mut builder = Builder::default();
+//! builder.push_record(["service", "requests", "cost"]);
+//! builder.push_record(["auth", "1200", "3.5"]);
+//! builder.push_record(["search", "5400", "12.25"]);
+//! builder.push_record(["billing", "300", "1"]);
+//!
+//! let mut table = builder.build();
+//! table.with(Style::rounded());
+//! table.with(
+//!     Aggregate::sum(Locator::column("cost"))
+//!         .label("Total")
+//!         .precision(2)
+//!         .separator(HorizontalLine::inherit(Style::modern())),
+//! );
+//!
+//! assert_table!(
+//!     table,
+//!     "╭─────────┬──────────┬───────╮"
+//!     "│ service │ requests │ cost  │"
+//!     "├─────────┼──────────┼───────┤"
+//!     "│ auth    │ 1200     │ 3.5   │"
+//!     "│ search  │ 5400     │ 12.25 │"
+//!     "│ billing │ 300      │ 1     │"
+//!     "├─────────┼──────────┼───────┤"
+//!     "│ Total   │          │ 16.75 │"
+//!     "╰─────────┴──────────┴───────╯"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+mod reducer;
+
+pub use reducer::{Count, DistinctCount, Max, Mean, Min, NonNumeric, Reducer, Sum, Values};
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, HorizontalLine, SpannedConfig},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    },
+    settings::{location::Location, rows::find_fixed_rows, TableOption},
+    util::string::strip_ansi,
+};
+
+/// Aggregate appends a row which cells are computed out of data rows of located columns.
+///
+/// Values are parsed as numbers out of cells text,
+/// [`NonNumeric`] defines what happens with cells which are not numbers.
+///
+/// The first row is considered to be a header, so it's not aggregated.
+/// It can be changed by [`Aggregate::header`].
+/// Rows which are a panel (a single cell spread across all columns, like [`Panel::header`] or [`Panel::footer`])
+/// are not aggregated either.
+/// Trailing rows can be excluded by [`Aggregate::footer`],
+/// which is handy when a few aggregations are appended one after another.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{Aggregate, object::Columns}};
+/// use testing_table::assert_table;
+///
+/// let data = [("Rust", 3), ("C", 10), ("Go", 2)];
+///
+/// let mut table = Table::new(data);
+/// table.with(Aggregate::sum(Columns::single(1)).label("sum"));
+/// table.with(Aggregate::max(Columns::single(1)).label("max").footer(1));
+///
+/// assert_table!(
+///     table,
+///     "+------+-----+"
+///     "| &str | i32 |"
+///     "+------+-----+"
+///     "| Rust | 3   |"
+///     "+------+-----+"
+///     "| C    | 10  |"
+///     "+------+-----+"
+///     "| Go   | 2   |"
+///     "+------+-----+"
+///     "| sum  | 15  |"
+///     "+------+-----+"
+///     "| max  | 10  |"
+///     "+------+-----+"
+/// );
+/// ```
+///
+/// [`Panel::header`]: crate::settings::Panel::header
+/// [`Panel::footer`]: crate::settings::Panel::footer
+#[derive(Debug, Clone)]
+pub struct Aggregate<L, R> {
+    locator: L,
+    reducer: R,
+    label: Option<(usize, String)>,
+    placeholder: String,
+    precision: Option<usize>,
+    strategy: NonNumeric,
+    separator: Option<HorizontalLine<char>>,
+    header: usize,
+    footer: usize,
+}
+
+impl Aggregate<(), ()> {
+    /// Aggregate located columns by a given [`Reducer`].
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// [`Columns`]: crate::settings::object::Columns
+    /// [`Column`]: crate::settings::object::Column
+    /// [`FirstColumn`]: crate::settings::object::FirstColumn
+    /// [`LastColumn`]: crate::settings::object::LastColumn
+    /// [`ByColumnName`]: crate::settings::location::ByColumnName
+    pub fn new<L, R>(locator: L, reducer: R) -> Aggregate<L, R>
+    where
+        R: Reducer,
+    {
+        Aggregate {
+            locator,
+            reducer,
+            label: None,
+            placeholder: String::new(),
+            precision: None,
+            strategy: NonNumeric::default(),
+            separator: None,
+            header: 1,
+            footer: 0,
+        }
+    }
+
+    /// Aggregate located columns by a closure over numbers of a column.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Aggregate, Style, object::Columns}};
+    /// use testing_table::assert_table;
+    ///
+    /// let mut table = Table::new([1, 9, 2]);
+    /// table.with(Style::markdown());
+    /// table.with(Aggregate::by(Columns::first(), |numbers| numbers.iter().product()));
+    ///
+    /// assert_table!(
+    ///     table,
+    ///     "| i32 |"
+    ///     "|-----|"
+    ///     "| 1   |"
+    ///     "| 9   |"
+    ///     "| 2   |"
+    ///     "| 18  |"
+    /// );
+    /// ```
+    pub fn by<L, F>(locator: L, f: F) -> Aggregate<L, F>
+    where
+        F: Fn(&[f64]) -> f64,
+    {
+        Self::new(locator, f)
+    }
+
+    /// Sum up numbers of located columns.
+    pub fn sum<L>(locator: L) -> Aggregate<L, Sum> {
+        Self::new(locator, Sum)
+    }
+
+    /// Calculate an arithmetic mean of numbers of located columns.
+    pub fn mean<L>(locator: L) -> Aggregate<L, Mean> {
+        Self::new(locator, Mean)
+    }
+
+    /// Find a minimum number of located columns.
+    pub fn min<L>(locator: L) -> Aggregate<L, Min> {
+        Self::new(locator, Min)
+    }
+
+    /// Find a maximum number of located columns.
+    pub fn max<L>(locator: L) -> Aggregate<L, Max> {
+        Self::new(locator, Max)
+    }
+
+    /// Count non empty cells of located columns.
+    pub fn count<L>(locator: L) -> Aggregate<L, Count> {
+        Self::new(locator, Count)
+    }
+
+    /// Count distinct non empty cells of located columns.
+    pub fn distinct<L>(locator: L) -> Aggregate<L, DistinctCount> {
+        Self::new(locator, DistinctCount)
+    }
+}
+
+impl<L, R> Aggregate<L, R> {
+    /// Set a text of the first cell of the appended row.
+    ///
+    /// The label takes place of an aggregated value if the first column is aggregated.
+    pub fn label<S>(self, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.label_at(0, text)
+    }
+
+    /// Set a text of a given cell of the appended row.
+    ///
+    /// The label takes place of an aggregated value if the column is aggregated.
+    pub fn label_at<S>(mut self, column: usize, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.label = Some((column, text.into()));
+        self
+    }
+
+    /// Set a text which is used when a value can't be computed,
+    /// e.g. a mean of a column with no numbers.
+    ///
+    /// By default it's an empty string.
+    pub fn placeholder<S>(mut self, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.placeholder = text.into();
+        self
+    }
+
+    /// Set an amount of digits after a decimal point.
+    ///
+    /// By default a number is printed as is.
+    pub fn precision(mut self, digits: usize) -> Self {
+        self.precision = Some(digits);
+        self
+    }
+
+    /// Set a strategy of handling cells which are not numbers.
+    ///
+    /// By default such cells are skipped.
+    pub fn non_numeric(mut self, strategy: NonNumeric) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Set a horizontal line which separates the appended row from the rest of the table.
+    ///
+    /// It accepts a [`style::HorizontalLine`], so a line can be inherited from a [`Style`].
+    ///
+    /// [`style::HorizontalLine`]: crate::settings::style::HorizontalLine
+    /// [`Style`]: crate::settings::Style
+    pub fn separator<T>(mut self, line: T) -> Self
+    where
+        T: Into<HorizontalLine<char>>,
+    {
+        self.separator = Some(line.into());
+        self
+    }
+
+    /// Set an amount of leading rows which are considered to be a header,
+    /// and therefore are not aggregated.
+    ///
+    /// Panel rows are not counted.
+    ///
+    /// By default it's `1`.
+    pub fn header(mut self, count_rows: usize) -> Self {
+        self.header = count_rows;
+        self
+    }
+
+    /// Set an amount of trailing rows which are considered to be a footer,
+    /// and therefore are not aggregated.
+    ///
+    /// Panel rows are not counted.
+    ///
+    /// By default it's `0`.
+    pub fn footer(mut self, count_rows: usize) -> Self {
+        self.footer = count_rows;
+        self
+    }
+}
+
+impl<L, A> Aggregate<L, A> {
+    pub(crate) fn get_label(&self) -> Option<&str> {
+        self.label.as_ref().map(|(_, text)| text.as_str())
+    }
+
+    pub(crate) fn locate_columns<R>(&mut self, records: &R) -> Vec<usize>
+    where
+        L: Location<R, Coordinate = usize>,
+        R: Records,
+    {
+        let count_cols = records.count_columns();
+        self.locator
+            .locate(records)
+            .into_iter()
+            .filter(|&col| col < count_cols)
+            .collect()
+    }
+
+    // Reduces given rows of the columns, leaving the rest of cells empty.
+    pub(crate) fn reduce_rows<R>(
+        &self,
+        records: &R,
+        cfg: &SpannedConfig,
+        columns: &[usize],
+        rows: &[usize],
+    ) -> Vec<String>
+    where
+        A: Reducer,
+        R: Records + PeekableRecords,
+    {
+        let mut cells = vec![String::new(); records.count_columns()];
+        for &col in columns {
+            let texts = rows
+                .iter()
+                .filter(|&&row| cfg.is_cell_visible((row, col)))
+                .map(|&row| strip_ansi(records.get_text((row, col))));
+            let values = Values::new(texts, self.strategy);
+
+            cells[col] = match self.reducer.reduce(&values) {
+                Some(value) => format_number(value, self.precision),
+                None => self.placeholder.clone(),
+            };
+        }
+
+        cells
+    }
+}
+
+impl<L, A, R, D> TableOption<R, ColoredConfig, D> for Aggregate<L, A>
+where
+    L: Location<R, Coordinate = usize>,
+    A: Reducer,
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    fn change(mut self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+        if count_cols == 0 {
+            return;
+        }
+
+        let columns = self.locate_columns(records);
+        let rows = find_data_rows(cfg, count_rows, count_cols, self.header, self.footer);
+
+        let mut cells = self.reduce_rows(records, cfg, &columns, &rows);
+        if let Some((col, text)) = self.label {
+            if col < count_cols {
+                cells[col] = text;
+            }
+        }
+
+        records.push_row();
+        for (col, text) in cells.into_iter().enumerate() {
+            records.set((count_rows, col), text);
+        }
+
+        if let Some(line) = self.separator {
+            cfg.insert_horizontal_line(count_rows, line);
+        }
+    }
+}
+
+fn find_data_rows(
+    cfg: &SpannedConfig,
+    count_rows: usize,
+    count_cols: usize,
+    count_header: usize,
+    count_footer: usize,
+) -> Vec<usize> {
+    let fixed = find_fixed_rows(cfg, count_rows, count_cols, count_header);
+    let mut rows = (0..count_rows)
+        .filter(|&row| !fixed[row])
+        .collect::<Vec<_>>();
+
+    let count_footer = core::cmp::min(count_footer, rows.len());
+    rows.truncate(rows.len() - count_footer);
+
+    rows
+}
+
+fn format_number(value: f64, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{value:.precision$}"),
+        None => value.to_string(),
+    }
+}
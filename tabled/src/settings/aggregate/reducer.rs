@@ -0,0 +1,234 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use crate::util::string::parse_number;
+
+/// A trait which reduces values of a column into a single number.
+///
+/// It's implemented for closures `Fn(&[f64]) -> f64`,
+/// which are called with numbers of a column.
+pub trait Reducer {
+    /// Reduces values of a column.
+    ///
+    /// `None` means that a value can't be computed,
+    /// in which case a placeholder is used.
+    fn reduce(&self, values: &Values<'_>) -> Option<f64>;
+}
+
+impl<F> Reducer for F
+where
+    F: Fn(&[f64]) -> f64,
+{
+    fn reduce(&self, values: &Values<'_>) -> Option<f64> {
+        values.numbers().map(self)
+    }
+}
+
+/// A strategy of handling cells which are not numbers.
+///
+/// Empty cells are never considered, regardless of the strategy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NonNumeric {
+    /// Cells which are not numbers are ignored.
+    #[default]
+    Skip,
+    /// Cells which are not numbers are considered to be `0`.
+    Zero,
+    /// Numbers are not available if any cell is not a number.
+    Fail,
+}
+
+/// Values of a column which are being reduced.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Values<'a> {
+    texts: Vec<Cow<'a, str>>,
+    numbers: Option<Vec<f64>>,
+}
+
+impl<'a> Values<'a> {
+    /// Creates a set of values out of cells text.
+    ///
+    /// Empty cells are ignored.
+    pub fn new<I, S>(texts: I, strategy: NonNumeric) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'a, str>>,
+    {
+        let texts = texts
+            .into_iter()
+            .map(Into::into)
+            .filter(|text: &Cow<'a, str>| !text.trim().is_empty())
+            .collect::<Vec<_>>();
+
+        let mut numbers = Some(Vec::with_capacity(texts.len()));
+        for text in &texts {
+            let number = match (parse_number(text), strategy) {
+                (Some(number), _) => number,
+                (None, NonNumeric::Skip) => continue,
+                (None, NonNumeric::Zero) => 0.0,
+                (None, NonNumeric::Fail) => {
+                    numbers = None;
+                    break;
+                }
+            };
+
+            if let Some(numbers) = &mut numbers {
+                numbers.push(number);
+            }
+        }
+
+        Self { texts, numbers }
+    }
+
+    /// Returns a text of cells.
+    pub fn texts(&self) -> impl Iterator<Item = &str> {
+        self.texts.iter().map(|text| text.as_ref())
+    }
+
+    /// Returns numbers parsed from cells.
+    ///
+    /// It's `None` if [`NonNumeric::Fail`] is used and there's a cell which is not a number.
+    pub fn numbers(&self) -> Option<&[f64]> {
+        self.numbers.as_deref()
+    }
+}
+
+/// A reducer which sums up numbers.
+///
+/// ```
+/// use tabled::settings::aggregate::{NonNumeric, Reducer, Sum, Values};
+///
+/// let values = Values::new(["1", "2.5", "x"], NonNumeric::Skip);
+/// assert_eq!(Sum.reduce(&values), Some(3.5));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sum;
+
+impl Reducer for Sum {
+    fn reduce(&self, values: &Values<'_>) -> Option<f64> {
+        values.numbers().map(|numbers| numbers.iter().sum())
+    }
+}
+
+/// A reducer which calculates an arithmetic mean of numbers.
+///
+/// ```
+/// use tabled::settings::aggregate::{Mean, NonNumeric, Reducer, Values};
+///
+/// let values = Values::new(["1", "2", "x"], NonNumeric::Zero);
+/// assert_eq!(Mean.reduce(&values), Some(1.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mean;
+
+impl Reducer for Mean {
+    fn reduce(&self, values: &Values<'_>) -> Option<f64> {
+        let numbers = values.numbers()?;
+        if numbers.is_empty() {
+            return None;
+        }
+
+        let sum = numbers.iter().sum::<f64>();
+        Some(sum / numbers.len() as f64)
+    }
+}
+
+/// A reducer which finds a minimum number.
+///
+/// ```
+/// use tabled::settings::aggregate::{Min, NonNumeric, Reducer, Values};
+///
+/// let values = Values::new(["3", "-1", "2"], NonNumeric::Skip);
+/// assert_eq!(Min.reduce(&values), Some(-1.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min;
+
+impl Reducer for Min {
+    fn reduce(&self, values: &Values<'_>) -> Option<f64> {
+        values.numbers()?.iter().copied().reduce(f64::min)
+    }
+}
+
+/// A reducer which finds a maximum number.
+///
+/// ```
+/// use tabled::settings::aggregate::{Max, NonNumeric, Reducer, Values};
+///
+/// let values = Values::new(["3", "-1", "2"], NonNumeric::Skip);
+/// assert_eq!(Max.reduce(&values), Some(3.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max;
+
+impl Reducer for Max {
+    fn reduce(&self, values: &Values<'_>) -> Option<f64> {
+        values.numbers()?.iter().copied().reduce(f64::max)
+    }
+}
+
+/// A reducer which counts non empty cells.
+///
+/// ```
+/// use tabled::settings::aggregate::{Count, NonNumeric, Reducer, Values};
+///
+/// let values = Values::new(["a", "", "b", "a"], NonNumeric::Skip);
+/// assert_eq!(Count.reduce(&values), Some(3.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Count;
+
+impl Reducer for Count {
+    fn reduce(&self, values: &Values<'_>) -> Option<f64> {
+        Some(values.texts().count() as f64)
+    }
+}
+
+/// A reducer which counts distinct non empty cells.
+///
+/// ```
+/// use tabled::settings::aggregate::{DistinctCount, NonNumeric, Reducer, Values};
+///
+/// let values = Values::new(["a", "", "b", "a"], NonNumeric::Skip);
+/// assert_eq!(DistinctCount.reduce(&values), Some(2.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DistinctCount;
+
+impl Reducer for DistinctCount {
+    fn reduce(&self, values: &Values<'_>) -> Option<f64> {
+        let distinct = values.texts().collect::<HashSet<_>>();
+        Some(distinct.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values() {
+        let values = Values::new(["1", " 2 ", "", "x"], NonNumeric::Skip);
+        assert_eq!(values.texts().collect::<Vec<_>>(), ["1", " 2 ", "x"]);
+        assert_eq!(values.numbers(), Some(&[1.0, 2.0][..]));
+
+        let values = Values::new(["1", "x", ""], NonNumeric::Zero);
+        assert_eq!(values.numbers(), Some(&[1.0, 0.0][..]));
+
+        let values = Values::new(["1", "x"], NonNumeric::Fail);
+        assert_eq!(values.numbers(), None);
+
+        let values = Values::new(["1", ""], NonNumeric::Fail);
+        assert_eq!(values.numbers(), Some(&[1.0][..]));
+    }
+
+    #[test]
+    fn test_reducers_on_empty() {
+        let values = Values::new(Vec::<&str>::new(), NonNumeric::Skip);
+        assert_eq!(Sum.reduce(&values), Some(0.0));
+        assert_eq!(Mean.reduce(&values), None);
+        assert_eq!(Min.reduce(&values), None);
+        assert_eq!(Max.reduce(&values), None);
+        assert_eq!(Count.reduce(&values), Some(0.0));
+        assert_eq!(DistinctCount.reduce(&values), Some(0.0));
+    }
+}
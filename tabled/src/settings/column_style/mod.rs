@@ -0,0 +1,146 @@
+//! This module contains a [`ColumnStyle`] setting, which combines common column settings.
+
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, ColoredConfig, Entity},
+        records::{ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
+    },
+    settings::{CellOption, Color, Width},
+};
+
+/// [`ColumnStyle`] is a set of an alignment, a color and a width limit of a column.
+///
+/// It's what [`Tabled::column_styles`] returns,
+/// so the `#[tabled(align, color, max_width, wrap)]` attributes of the derive end up here.
+/// [`Table::new`] applies the styles automatically to all rows but a header.
+///
+/// It can be used as any other [`CellOption`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     grid::config::AlignmentHorizontal,
+///     settings::{object::Columns, ColumnStyle},
+///     Table,
+/// };
+/// use testing_table::assert_table;
+///
+/// let data = [("tabled", 1000), ("papergrid", 10)];
+///
+/// let style = ColumnStyle::new()
+///     .alignment(AlignmentHorizontal::Right)
+///     .max_width(3);
+///
+/// let mut table = Table::new(data);
+/// table.modify(Columns::single(1), style);
+///
+/// assert_table!(
+///     table,
+///     "+-----------+-----+"
+///     "| &str      | i32 |"
+///     "+-----------+-----+"
+///     "| tabled    | 100 |"
+///     "+-----------+-----+"
+///     "| papergrid |  10 |"
+///     "+-----------+-----+"
+/// );
+/// ```
+///
+/// [`Tabled::column_styles`]: crate::Tabled::column_styles
+/// [`Table::new`]: crate::Table::new
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ColumnStyle {
+    alignment: Option<AlignmentHorizontal>,
+    color: Option<Color>,
+    max_width: Option<usize>,
+    wrap: bool,
+}
+
+impl ColumnStyle {
+    /// Creates an empty [`ColumnStyle`], which changes nothing.
+    pub const fn new() -> Self {
+        Self {
+            alignment: None,
+            color: None,
+            max_width: None,
+            wrap: false,
+        }
+    }
+
+    /// Sets a horizontal alignment of a column.
+    pub fn alignment(mut self, alignment: AlignmentHorizontal) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets a color of a column.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets a maximum width of a column.
+    ///
+    /// By default a content is truncated, see [`ColumnStyle::wrap`].
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets whether a content bigger than [`ColumnStyle::max_width`] is wrapped instead of being truncated.
+    pub fn wrap(mut self, on: bool) -> Self {
+        self.wrap = on;
+        self
+    }
+
+    /// Returns a horizontal alignment.
+    pub fn get_alignment(&self) -> Option<AlignmentHorizontal> {
+        self.alignment
+    }
+
+    /// Returns a color.
+    pub fn get_color(&self) -> Option<&Color> {
+        self.color.as_ref()
+    }
+
+    /// Returns a maximum width.
+    pub fn get_max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+
+    /// Returns whether a content is wrapped.
+    pub fn is_wrap(&self) -> bool {
+        self.wrap
+    }
+
+    /// Verifies whether the style changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.alignment.is_none() && self.color.is_none() && self.max_width.is_none()
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for ColumnStyle
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        if let Some(alignment) = self.alignment {
+            cfg.set_alignment_horizontal(entity, alignment);
+        }
+
+        if let Some(width) = self.max_width {
+            if self.wrap {
+                Width::wrap(width).change(records, cfg, entity);
+            } else {
+                Width::truncate(width).change(records, cfg, entity);
+            }
+        }
+
+        if let Some(color) = self.color {
+            color.change(records, cfg, entity);
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! This module contains a [`Filter`] setting which removes rows of a [`Table`] by a condition.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{builder::Builder, settings::{Filter, Style, location::Locator}};
+//! use testing_table::assert_table;
+//!
+//! let mut builder = Builder::default();
+//! builder.push_record(["job", "status"]);
+//! builder.push_record(["build", "ok"]);
+//! builder.push_record(["test", "failed"]);
+//! builder.push_record(["lint", "ok"]);
+//! builder.push_record(["deploy", "failed"]);
+//!
+//! let mut table = builder.build();
+//! table.with(Style::psql());
+//! table.with(Filter::column(Locator::column("status"), |text| text == "failed"));
+//!
+//! assert_table!(
+//!     table,
+//!     " job    | status "
+//!     "--------+--------"
+//!     " test   | failed "
+//!     " deploy | failed "
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, SpannedConfig},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    },
+    settings::{
+        location::Location,
+        rows::{find_fixed_rows, move_cell_config},
+        TableOption,
+    },
+};
+
+/// Filter removes data rows which don't satisfy a condition.
+///
+/// A condition is checked against cells of columns found by a given [`Location`],
+/// like [`ByColumnName`] or [`Columns`].
+/// A row is kept if a condition is satisfied by any of these cells.
+///
+/// The first row is considered to be a header, so it's always kept.
+/// It can be changed by [`Filter::header`].
+/// Rows which are a panel (a single cell spread across all columns, like [`Panel::header`] or [`Panel::footer`])
+/// are always kept as well.
+///
+/// Spans, horizontal lines and a per cell configuration (padding, alignment, formatting, colors)
+/// are moved together with the rows which remain.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{Filter, object::Columns}};
+/// use testing_table::assert_table;
+///
+/// let data = [("Rust", 2010), ("C", 1972), ("Go", 2009)];
+///
+/// let mut table = Table::new(data);
+/// table.with(Filter::column(Columns::single(1), |text| text.parse::<i32>().unwrap() > 2000));
+///
+/// assert_table!(
+///     table,
+///     "+------+------+"
+///     "| &str | i32  |"
+///     "+------+------+"
+///     "| Rust | 2010 |"
+///     "+------+------+"
+///     "| Go   | 2009 |"
+///     "+------+------+"
+/// );
+/// ```
+///
+/// [`Location`]: crate::settings::location::Location
+/// [`ByColumnName`]: crate::settings::location::ByColumnName
+/// [`Columns`]: crate::settings::object::Columns
+/// [`Panel::header`]: crate::settings::Panel::header
+/// [`Panel::footer`]: crate::settings::Panel::footer
+#[derive(Debug, Clone)]
+pub struct Filter<L, F> {
+    locator: L,
+    condition: F,
+    header: usize,
+}
+
+impl Filter<(), ()> {
+    /// Keep rows which cells in the located columns satisfy a condition.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// Notice that the condition is not called for header and panel rows.
+    ///
+    /// [`Columns`]: crate::settings::object::Columns
+    /// [`Column`]: crate::settings::object::Column
+    /// [`FirstColumn`]: crate::settings::object::FirstColumn
+    /// [`LastColumn`]: crate::settings::object::LastColumn
+    /// [`ByColumnName`]: crate::settings::location::ByColumnName
+    pub fn column<L, F>(locator: L, condition: F) -> Filter<L, F>
+    where
+        F: Fn(&str) -> bool,
+    {
+        Filter {
+            locator,
+            condition,
+            header: 1,
+        }
+    }
+}
+
+impl<L, F> Filter<L, F> {
+    /// Set an amount of leading rows which are considered to be a header,
+    /// and therefore are always kept.
+    ///
+    /// Panel rows are not counted.
+    ///
+    /// By default it's `1`.
+    pub fn header(mut self, count_rows: usize) -> Self {
+        self.header = count_rows;
+        self
+    }
+}
+
+impl<L, F, R, D> TableOption<R, ColoredConfig, D> for Filter<L, F>
+where
+    L: Location<R, Coordinate = usize>,
+    F: Fn(&str) -> bool,
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    fn change(mut self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+        if count_rows == 0 || count_cols == 0 {
+            return;
+        }
+
+        let columns = self
+            .locator
+            .locate(records)
+            .into_iter()
+            .filter(|&col| col < count_cols)
+            .collect::<Vec<_>>();
+
+        let mut keep = find_fixed_rows(cfg, count_rows, count_cols, self.header);
+        for (row, is_kept) in keep.iter_mut().enumerate() {
+            if *is_kept {
+                continue;
+            }
+
+            *is_kept = columns
+                .iter()
+                .any(|&col| (self.condition)(records.get_text((row, col))));
+        }
+
+        if keep.iter().all(|is_kept| *is_kept) {
+            return;
+        }
+
+        filter_rows(records, cfg, &keep);
+    }
+}
+
+fn filter_rows<R>(records: &mut R, cfg: &mut ColoredConfig, keep: &[bool])
+where
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    let count_rows = keep.len();
+
+    // a new index of each kept row
+    let mut index = vec![None; count_rows];
+    let mut count_kept = 0;
+    for (row, &is_kept) in keep.iter().enumerate() {
+        if is_kept {
+            index[row] = Some(count_kept);
+            count_kept += 1;
+        }
+    }
+
+    let origin = cfg.clone();
+
+    move_row_spans(records, cfg, keep, &index);
+    move_column_spans(cfg, &index);
+    move_horizontal_lines(cfg, keep);
+
+    for row in (0..count_rows).rev() {
+        if !keep[row] {
+            records.remove_row(row);
+        }
+    }
+
+    let count_cols = records.count_columns();
+    for (row, new_row) in index.iter().enumerate() {
+        match new_row {
+            Some(new_row) if *new_row != row => {
+                move_cell_config(cfg, &origin, row, *new_row, count_cols);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn move_row_spans<R>(
+    records: &mut R,
+    cfg: &mut SpannedConfig,
+    keep: &[bool],
+    index: &[Option<usize>],
+) where
+    R: RecordsMut<String> + PeekableRecords,
+{
+    let spans = cfg.get_row_spans();
+    cfg.remove_row_spans();
+
+    for ((row, col), span) in spans {
+        let end = core::cmp::min(row + span, keep.len());
+        let kept = (row..end).filter(|&row| keep[row]).collect::<Vec<_>>();
+        let first = match kept.first() {
+            Some(&first) => first,
+            None => continue,
+        };
+
+        // the content of a span is located in its first cell,
+        // so we move it if the first row is removed
+        if first != row {
+            let text = records.get_text((row, col)).to_owned();
+            records.set((first, col), text);
+        }
+
+        if let Some(new_row) = index[first] {
+            cfg.set_row_span((new_row, col), kept.len());
+        }
+    }
+}
+
+fn move_column_spans(cfg: &mut SpannedConfig, index: &[Option<usize>]) {
+    let spans = cfg.get_column_spans();
+    cfg.remove_column_spans();
+
+    for ((row, col), span) in spans {
+        if let Some(new_row) = index[row] {
+            cfg.set_column_span((new_row, col), span);
+        }
+    }
+}
+
+// A horizontal line is moved right above the next kept row.
+// If a few lines end up on the same place the first one is used.
+fn move_horizontal_lines(cfg: &mut SpannedConfig, keep: &[bool]) {
+    let count_rows = keep.len();
+
+    let mut lines = cfg.get_horizontal_lines().into_iter().collect::<Vec<_>>();
+    lines.sort_by_key(|(line, _)| *line);
+
+    for (line, _) in &lines {
+        cfg.remove_horizontal_line(*line, count_rows);
+    }
+
+    let mut last = None;
+    for (line, value) in lines {
+        let line = core::cmp::min(line, count_rows);
+        let new_line = keep[..line].iter().filter(|is_kept| **is_kept).count();
+        if last == Some(new_line) {
+            continue;
+        }
+
+        cfg.insert_horizontal_line(new_line, value);
+        last = Some(new_line);
+    }
+}
@@ -0,0 +1,389 @@
+//! This module contains a [`Group`] setting which groups rows of a [`Table`] by key columns.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{
+//!     builder::Builder,
+//!     settings::{Aggregate, Group, Style, object::Columns, style::BorderSpanCorrection},
+//! };
+//! use testing_table::assert_table;
+//!
+//! let mut builder = Builder::default();
+//! builder.push_record(["customer", "project", "hours"]);
+//! builder.push_record(["acme", "web", "10"]);
+//! builder.push_record(["globex", "api", "4"]);
+//! builder.push_record(["acme", "api", "2"]);
+//!
+//! let mut table = builder.build();
+//! table.with(Style::modern());
+//! table.with(Group::column(0).subtotal(Aggregate::sum(Columns::single(2)).label("total")));
+//! table.with(BorderSpanCorrection);
+//!
+//! assert_table!(
+//!     table,
+//!     "┌──────────┬─────────┬───────┐"
+//!     "│ customer │ project │ hours │"
+//!     "├──────────┼─────────┼───────┤"
+//!     "│ acme     │ web     │ 10    │"
+//!     "│          ├─────────┼───────┤"
+//!     "│          │ api     │ 2     │"
+//!     "├──────────┼─────────┼───────┤"
+//!     "│ total    │         │ 12    │"
+//!     "├──────────┼─────────┼───────┤"
+//!     "│ globex   │ api     │ 4     │"
+//!     "├──────────┼─────────┼───────┤"
+//!     "│ total    │         │ 4     │"
+//!     "└──────────┴─────────┴───────┘"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, SpannedConfig},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    },
+    settings::{
+        aggregate::Reducer,
+        location::Location,
+        rows::{find_fixed_rows, move_cell_config},
+        Aggregate, TableOption,
+    },
+    util::string::strip_ansi,
+};
+
+/// Group puts data rows with equal values of key columns together,
+/// and spans a key cell vertically across its group.
+///
+/// Groups go in order of their first appearance, and rows inside a group keep their relative order.
+/// Use [`Sort`] beforehand to order groups differently.
+///
+/// When a few key columns are given, groups are nested:
+/// rows are grouped by the first column, then inside each group by the second one and so on.
+///
+/// A subtotal row can be appended after each group by [`Group::subtotal`].
+///
+/// A per cell configuration (padding, alignment, formatting, colors) is moved together with the rows.
+///
+/// The first row is considered to be a header, so it's kept in place.
+/// It can be changed by [`Group::header`].
+/// Rows which are a panel (a single cell spread across all columns, like [`Panel::header`] or [`Panel::footer`])
+/// are kept as well; the ones which go after data rows are placed after groups.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{Group, Style, style::BorderSpanCorrection}};
+/// use testing_table::assert_table;
+///
+/// let data = [("acme", "web"), ("globex", "api"), ("acme", "api")];
+///
+/// let mut table = Table::new(data);
+/// table.with(Style::modern());
+/// table.with(Group::column(0));
+/// table.with(BorderSpanCorrection);
+///
+/// assert_table!(
+///     table,
+///     "┌────────┬──────┐"
+///     "│ &str   │ &str │"
+///     "├────────┼──────┤"
+///     "│ acme   │ web  │"
+///     "│        ├──────┤"
+///     "│        │ api  │"
+///     "├────────┼──────┤"
+///     "│ globex │ api  │"
+///     "└────────┴──────┘"
+/// );
+/// ```
+///
+/// [`Sort`]: crate::settings::Sort
+/// [`Panel::header`]: crate::settings::Panel::header
+/// [`Panel::footer`]: crate::settings::Panel::footer
+#[derive(Debug, Clone)]
+pub struct Group<S> {
+    keys: Vec<usize>,
+    subtotal: S,
+    header: usize,
+}
+
+impl Group<()> {
+    /// Group rows by a given column.
+    pub fn column(column: usize) -> Self {
+        Self::columns([column])
+    }
+
+    /// Group rows by given columns, each next column makes a nested group.
+    pub fn columns<I>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Self {
+            keys: columns.into_iter().collect(),
+            subtotal: (),
+            header: 1,
+        }
+    }
+}
+
+impl<S> Group<S> {
+    /// Append a row computed by an [`Aggregate`] after each group.
+    ///
+    /// A label of the [`Aggregate`] is put into the key column of a group.
+    pub fn subtotal<L, A>(self, aggregate: Aggregate<L, A>) -> Group<Aggregate<L, A>> {
+        Group {
+            keys: self.keys,
+            subtotal: aggregate,
+            header: self.header,
+        }
+    }
+
+    /// Set an amount of leading rows which are considered to be a header,
+    /// and therefore kept in place.
+    ///
+    /// Panel rows are not counted.
+    ///
+    /// By default it's `1`.
+    pub fn header(mut self, count_rows: usize) -> Self {
+        self.header = count_rows;
+        self
+    }
+}
+
+impl<R, D> TableOption<R, ColoredConfig, D> for Group<()>
+where
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let subtotal = None::<fn(&R, &SpannedConfig, &[usize], usize) -> Vec<String>>;
+        group_rows(records, cfg, &self.keys, self.header, subtotal);
+    }
+}
+
+impl<L, A, R, D> TableOption<R, ColoredConfig, D> for Group<Aggregate<L, A>>
+where
+    L: Location<R, Coordinate = usize>,
+    A: Reducer,
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    fn change(mut self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let columns = self.subtotal.locate_columns(records);
+        let aggregate = &self.subtotal;
+        let subtotal = |records: &R, cfg: &SpannedConfig, rows: &[usize], key: usize| {
+            let mut cells = aggregate.reduce_rows(records, cfg, &columns, rows);
+            if let Some(label) = aggregate.get_label() {
+                cells[key] = label.to_owned();
+            }
+
+            cells
+        };
+
+        group_rows(records, cfg, &self.keys, self.header, Some(subtotal));
+    }
+}
+
+// A row of a grouped table.
+enum Row {
+    Origin(usize),
+    Subtotal(Vec<String>),
+}
+
+fn group_rows<R, F>(
+    records: &mut R,
+    cfg: &mut ColoredConfig,
+    keys: &[usize],
+    count_header: usize,
+    subtotal: Option<F>,
+) where
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+    F: Fn(&R, &SpannedConfig, &[usize], usize) -> Vec<String>,
+{
+    let count_rows = records.count_rows();
+    let count_cols = records.count_columns();
+    if count_rows == 0 || count_cols == 0 {
+        return;
+    }
+
+    let keys = keys
+        .iter()
+        .copied()
+        .filter(|&col| col < count_cols)
+        .collect::<Vec<_>>();
+    if keys.is_empty() {
+        return;
+    }
+
+    let fixed = find_fixed_rows(cfg, count_rows, count_cols, count_header);
+    let data = (0..count_rows)
+        .filter(|&row| !fixed[row])
+        .collect::<Vec<_>>();
+    let first = match data.first() {
+        Some(&row) => row,
+        None => return,
+    };
+
+    let mut ctx = GroupCtx {
+        keys: &keys,
+        subtotal: subtotal.as_ref(),
+        layout: (0..first).map(Row::Origin).collect(),
+        spans: Vec::new(),
+    };
+
+    ctx.group(records, cfg, &data, 0);
+
+    let GroupCtx {
+        mut layout, spans, ..
+    } = ctx;
+
+    let rest = (first..count_rows).filter(|&row| fixed[row]);
+    layout.extend(rest.map(Row::Origin));
+
+    rebuild(records, cfg, layout, &spans, count_rows);
+}
+
+struct GroupCtx<'a, F> {
+    keys: &'a [usize],
+    subtotal: Option<&'a F>,
+    layout: Vec<Row>,
+    // a start row, a column and a length of a key span
+    spans: Vec<(usize, usize, usize)>,
+}
+
+impl<F> GroupCtx<'_, F> {
+    fn group<R>(&mut self, records: &R, cfg: &SpannedConfig, rows: &[usize], level: usize)
+    where
+        R: Records + PeekableRecords,
+        F: Fn(&R, &SpannedConfig, &[usize], usize) -> Vec<String>,
+    {
+        if level == self.keys.len() {
+            self.layout.extend(rows.iter().copied().map(Row::Origin));
+            return;
+        }
+
+        let col = self.keys[level];
+
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        for &row in rows {
+            let key = strip_ansi(records.get_text((row, col)));
+            match groups.iter_mut().find(|(k, _)| k.as_str() == key) {
+                Some((_, group)) => group.push(row),
+                None => groups.push((key.into_owned(), vec![row])),
+            }
+        }
+
+        for (_, group) in groups {
+            let start = self.layout.len();
+            self.group(records, cfg, &group, level + 1);
+            let length = self.layout.len() - start;
+            self.spans.push((start, col, length));
+
+            if let Some(subtotal) = self.subtotal {
+                let cells = (subtotal)(records, cfg, &group, col);
+                self.layout.push(Row::Subtotal(cells));
+            }
+        }
+    }
+}
+
+fn rebuild<R>(
+    records: &mut R,
+    cfg: &mut ColoredConfig,
+    layout: Vec<Row>,
+    spans: &[(usize, usize, usize)],
+    count_rows: usize,
+) where
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    let count_cols = records.count_columns();
+
+    // a new index of each original row
+    let mut index = vec![0; count_rows];
+    for (new_row, row) in layout.iter().enumerate() {
+        if let Row::Origin(row) = row {
+            index[*row] = new_row;
+        }
+    }
+
+    // a subtotal row has no configuration of its own,
+    // so it's taken from a row out of the table
+    let blank_row = std::cmp::max(count_rows, layout.len());
+    let origin = cfg.clone();
+    for (new_row, row) in layout.iter().enumerate() {
+        let row = match row {
+            Row::Origin(row) => *row,
+            Row::Subtotal(_) => blank_row,
+        };
+
+        if row != new_row {
+            move_cell_config(cfg, &origin, row, new_row, count_cols);
+        }
+    }
+
+    let texts = layout
+        .into_iter()
+        .map(|row| match row {
+            Row::Origin(row) => (0..count_cols)
+                .map(|col| records.get_text((row, col)).to_owned())
+                .collect(),
+            Row::Subtotal(cells) => cells,
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    let new_count_rows = texts.len();
+    for _ in count_rows..new_count_rows {
+        records.push_row();
+    }
+
+    for (row, cells) in texts.into_iter().enumerate() {
+        for (col, text) in cells.into_iter().enumerate() {
+            records.set((row, col), text);
+        }
+    }
+
+    move_spans(cfg, &index);
+    move_horizontal_lines(cfg, &index, new_count_rows);
+
+    for &(start, col, length) in spans {
+        if length > 1 {
+            cfg.set_row_span((start, col), length);
+        }
+    }
+}
+
+fn move_spans(cfg: &mut SpannedConfig, index: &[usize]) {
+    let spans = cfg.get_column_spans();
+    cfg.remove_column_spans();
+    for ((row, col), span) in spans {
+        cfg.set_column_span((index[row], col), span);
+    }
+
+    // rows of a span may be split apart by grouping,
+    // so only spans which rows are still together are kept
+    let spans = cfg.get_row_spans();
+    cfg.remove_row_spans();
+    for ((row, col), span) in spans {
+        let end = row + span - 1;
+        if end < index.len() && index[end] == index[row] + span - 1 {
+            cfg.set_row_span((index[row], col), span);
+        }
+    }
+}
+
+fn move_horizontal_lines(cfg: &mut SpannedConfig, index: &[usize], new_count_rows: usize) {
+    let count_rows = index.len();
+    let lines = cfg.get_horizontal_lines();
+    for line in lines.keys() {
+        cfg.remove_horizontal_line(*line, count_rows);
+    }
+
+    for (line, value) in lines {
+        let new_line = match index.get(line) {
+            Some(&new_line) => new_line,
+            None => line - count_rows + new_count_rows,
+        };
+
+        cfg.insert_horizontal_line(new_line, value);
+    }
+}
@@ -60,9 +60,13 @@ mod modify;
 #[cfg(feature = "std")]
 mod color;
 #[cfg(feature = "std")]
+mod column_style;
+#[cfg(feature = "std")]
 mod concat;
 #[cfg(feature = "std")]
 mod duplicate;
+#[cfg(feature = "std")]
+mod rows;
 
 pub mod style;
 
@@ -70,17 +74,26 @@ pub mod style;
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod object;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod aggregate;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod disable;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod filter;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod format;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod formatting;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod group;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod height;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -108,6 +121,9 @@ pub mod peaker;
 mod shadow;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod sort;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub mod span;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -132,17 +148,22 @@ pub use self::{
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
+    aggregate::Aggregate,
     color::Color,
+    column_style::ColumnStyle,
     concat::Concat,
     disable::Remove,
     duplicate::Dup,
+    filter::Filter,
     format::Format,
+    group::Group,
     height::Height,
     highlight::Highlight,
     merge::Merge,
     modify::{Modify, ModifyList},
     panel::Panel,
     shadow::Shadow,
+    sort::Sort,
     span::Span,
     themes::Theme,
     width::Width,
@@ -1,6 +1,7 @@
 use crate::{
     grid::config::{ColoredConfig, SpannedConfig},
     grid::records::{ExactRecords, Records, RecordsMut, Resizable},
+    grid::util::width::UnicodeWidth,
     settings::TableOption,
 };
 
@@ -104,7 +105,7 @@ fn split_string_by_width(str: &str, width: usize) -> String {
         return String::new();
     }
 
-    let (lhs, rhs) = crate::util::string::split_str(str, width);
+    let (lhs, rhs) = crate::util::string::split_str(str, width, &UnicodeWidth);
     if rhs.is_empty() {
         return lhs.into_owned();
     }
@@ -112,7 +113,7 @@ fn split_string_by_width(str: &str, width: usize) -> String {
     let mut buf = lhs.into_owned();
     let mut next = rhs.into_owned();
     while !next.is_empty() {
-        let (lhs, rhs) = crate::util::string::split_str(&next, width);
+        let (lhs, rhs) = crate::util::string::split_str(&next, width, &UnicodeWidth);
         buf.push('\n');
         buf.push_str(&lhs);
         next = rhs.into_owned();
@@ -135,12 +136,13 @@ mod tests {
         );
         assert_eq!(split_string_by_width("123456789", 0), "");
 
+        #[cfg(not(feature = "grapheme"))]
         assert_eq!(
             split_string_by_width("\u{1b}[31;100m😳😳🏳️\u{1b}[39m\u{1b}[49m😳🏳️", 3),
             {
                 #[cfg(feature = "ansi")]
                 {
-                    "\u{1b}[31m\u{1b}[100m😳\u{1b}[39m\u{1b}[49m�\n\u{1b}[31m\u{1b}[100m🏳\u{fe0f}\u{1b}[39m\u{1b}[49m😳\n🏳\u{fe0f}"
+                    "\u{1b}[31m\u{1b}[100m😳�\u{1b}[39m\u{1b}[49m\n\u{1b}[31m\u{1b}[100m🏳\u{fe0f}\u{1b}[39m\u{1b}[49m😳\n🏳\u{fe0f}"
                 }
                 #[cfg(not(feature = "ansi"))]
                 {
@@ -149,4 +151,15 @@ mod tests {
             }
         );
     }
+
+    #[cfg(all(feature = "grapheme", not(feature = "ansi")))]
+    #[test]
+    fn test_split_string_by_width_graphemes() {
+        assert_eq!(split_string_by_width("😳🏳️😳🏳️", 3), "😳�\n😳�");
+        assert_eq!(split_string_by_width("👨‍👩‍👧👨‍👩‍👧", 2), "👨‍👩‍👧\n👨‍👩‍👧");
+        assert_eq!(
+            split_string_by_width("e\u{301}e\u{301}e\u{301}", 2),
+            "e\u{301}e\u{301}\ne\u{301}"
+        );
+    }
 }
@@ -0,0 +1,94 @@
+//! This module contains helpers for settings which rearrange rows of a table.
+
+use crate::grid::{
+    colors::Colors,
+    config::{ColoredConfig, Entity, Position, SpannedConfig},
+};
+
+/// Returns which rows are a header or a panel.
+///
+/// Panel rows (a single cell spread across all columns) are not counted as a header.
+pub(crate) fn find_fixed_rows(
+    cfg: &SpannedConfig,
+    count_rows: usize,
+    count_cols: usize,
+    count_header: usize,
+) -> Vec<bool> {
+    let mut header = count_header;
+    let mut fixed = vec![false; count_rows];
+    for (row, is_fixed) in fixed.iter_mut().enumerate() {
+        let is_panel = cfg.get_column_span((row, 0)) == Some(count_cols);
+        if is_panel {
+            *is_fixed = true;
+            continue;
+        }
+
+        if header > 0 {
+            *is_fixed = true;
+            header -= 1;
+        }
+    }
+
+    fixed
+}
+
+/// Sets a per cell configuration of a `new_row` to the one the `row` had in `origin`.
+pub(crate) fn move_cell_config(
+    cfg: &mut ColoredConfig,
+    origin: &ColoredConfig,
+    row: usize,
+    new_row: usize,
+    count_cols: usize,
+) {
+    for col in 0..count_cols {
+        let src: Position = (row, col);
+        let dst: Position = (new_row, col);
+        let src_entity = Entity::from(src);
+        let dst_entity = Entity::from(dst);
+
+        // we set only what differs, not to override column settings for no reason
+
+        let value = origin.get_padding(src_entity);
+        if cfg.get_padding(dst_entity) != value {
+            cfg.set_padding(dst_entity, value);
+        }
+
+        let value = origin.get_padding_color(src_entity);
+        if cfg.get_padding_color(dst_entity) != value {
+            cfg.set_padding_color(dst_entity, value);
+        }
+
+        let value = *origin.get_formatting(src_entity);
+        if *cfg.get_formatting(dst_entity) != value {
+            cfg.set_formatting(dst_entity, value);
+        }
+
+        let value = *origin.get_alignment_horizontal(src_entity);
+        if *cfg.get_alignment_horizontal(dst_entity) != value {
+            cfg.set_alignment_horizontal(dst_entity, value);
+        }
+
+        let value = *origin.get_alignment_vertical(src_entity);
+        if *cfg.get_alignment_vertical(dst_entity) != value {
+            cfg.set_alignment_vertical(dst_entity, value);
+        }
+
+        let value = origin.get_justification(src_entity);
+        if cfg.get_justification(dst_entity) != value {
+            cfg.set_justification(dst_entity, value);
+        }
+
+        let value = origin.get_justification_color(src_entity);
+        if cfg.get_justification_color(dst_entity) != value {
+            cfg.set_justification_color(dst_entity, value.cloned());
+        }
+
+        let value = origin.get_colors().get_color(src);
+        if cfg.get_colors().get_color(dst) != value {
+            let _ = match value.cloned() {
+                Some(color) => cfg.set_color(dst_entity, color),
+                None => cfg.remove_color(dst_entity),
+            };
+        }
+    }
+}
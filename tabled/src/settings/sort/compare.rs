@@ -0,0 +1,147 @@
+use core::cmp::Ordering;
+
+use crate::util::string::parse_number;
+
+/// A trait which compares a text of 2 cells.
+///
+/// It's implemented for closures `Fn(&str, &str) -> Ordering`,
+/// so any custom logic can be used.
+pub trait Comparator {
+    /// Compares 2 cells by their text.
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering;
+}
+
+impl<F> Comparator for F
+where
+    F: Fn(&str, &str) -> Ordering,
+{
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        (self)(lhs, rhs)
+    }
+}
+
+/// A comparator which orders cells lexicographically (as strings).
+///
+/// ```
+/// use tabled::settings::sort::{Comparator, Lexicographic};
+///
+/// assert!(Lexicographic.compare("10", "9").is_lt());
+/// assert!(Lexicographic.compare("apple", "banana").is_lt());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lexicographic;
+
+impl Comparator for Lexicographic {
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        lhs.cmp(rhs)
+    }
+}
+
+/// A comparator which is aware of numbers in cells.
+///
+/// Cells which are numbers are compared by their value,
+/// and they go before cells which are not numbers.
+///
+/// The rest are compared in a "natural" order,
+/// meaning that digits inside a text are compared as numbers (`file9` < `file10`).
+///
+/// ```
+/// use tabled::settings::sort::{Comparator, Numeric};
+///
+/// assert!(Numeric.compare("9", "10").is_lt());
+/// assert!(Numeric.compare("-1.5", "0").is_lt());
+/// assert!(Numeric.compare("100", "N/A").is_lt());
+/// assert!(Numeric.compare("file9", "file10").is_lt());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Numeric;
+
+impl Comparator for Numeric {
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        match (parse_number(lhs), parse_number(rhs)) {
+            (Some(lhs), Some(rhs)) => lhs.total_cmp(&rhs),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => natural_cmp(lhs, rhs),
+        }
+    }
+}
+
+fn natural_cmp(lhs: &str, rhs: &str) -> Ordering {
+    let mut lhs = lhs;
+    let mut rhs = rhs;
+
+    loop {
+        match (lhs.is_empty(), rhs.is_empty()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => {}
+        }
+
+        let (lchunk, lrest) = split_chunk(lhs);
+        let (rchunk, rrest) = split_chunk(rhs);
+
+        let is_ldigit = lchunk.starts_with(|c: char| c.is_ascii_digit());
+        let is_rdigit = rchunk.starts_with(|c: char| c.is_ascii_digit());
+
+        let ord = if is_ldigit && is_rdigit {
+            cmp_digits(lchunk, rchunk)
+        } else {
+            lchunk.cmp(rchunk)
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
+        }
+
+        lhs = lrest;
+        rhs = rrest;
+    }
+}
+
+fn split_chunk(text: &str) -> (&str, &str) {
+    let is_digit = text.starts_with(|c: char| c.is_ascii_digit());
+    let end = text
+        .find(|c: char| c.is_ascii_digit() != is_digit)
+        .unwrap_or(text.len());
+
+    text.split_at(end)
+}
+
+fn cmp_digits(lhs: &str, rhs: &str) -> Ordering {
+    let lnum = lhs.trim_start_matches('0');
+    let rnum = rhs.trim_start_matches('0');
+
+    lnum.len()
+        .cmp(&rnum.len())
+        .then_with(|| lnum.cmp(rnum))
+        .then_with(|| lhs.len().cmp(&rhs.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric() {
+        assert_eq!(Numeric.compare("1", "1"), Ordering::Equal);
+        assert_eq!(Numeric.compare("2", "10"), Ordering::Less);
+        assert_eq!(Numeric.compare(" 2 ", "1.5"), Ordering::Greater);
+        assert_eq!(Numeric.compare("-3", "1e2"), Ordering::Less);
+        assert_eq!(Numeric.compare("1", "abc"), Ordering::Less);
+        assert_eq!(Numeric.compare("", "1"), Ordering::Greater);
+        assert_eq!(Numeric.compare("NaN", "1"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_natural() {
+        assert_eq!(natural_cmp("file2", "file10"), Ordering::Less);
+        assert_eq!(natural_cmp("file10", "file10"), Ordering::Equal);
+        assert_eq!(natural_cmp("file010", "file10"), Ordering::Greater);
+        assert_eq!(natural_cmp("a1b2", "a1b10"), Ordering::Less);
+        assert_eq!(natural_cmp("10 ms", "9 ms"), Ordering::Greater);
+        assert_eq!(natural_cmp("abc", "abd"), Ordering::Less);
+        assert_eq!(natural_cmp("ab", "abc"), Ordering::Less);
+    }
+}
@@ -0,0 +1,343 @@
+//! This module contains a [`Sort`] setting which reorders rows of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::{Sort, Style}};
+//! use testing_table::assert_table;
+//!
+//! let data = [
+//!     ("Rust", 2010, "Graydon Hoare"),
+//!     ("C", 1972, "Dennis Ritchie"),
+//!     ("Go", 2009, "Rob Pike"),
+//! ];
+//!
+//! let mut table = Table::new(data);
+//! table.with(Style::markdown());
+//! table.with(Sort::column(1).numeric().descending());
+//!
+//! assert_table!(
+//!     table,
+//!     "| &str | i32  | &str           |"
+//!     "|------|------|----------------|"
+//!     "| Rust | 2010 | Graydon Hoare  |"
+//!     "| Go   | 2009 | Rob Pike       |"
+//!     "| C    | 1972 | Dennis Ritchie |"
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+mod compare;
+
+pub use compare::{Comparator, Lexicographic, Numeric};
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, SpannedConfig},
+        records::{ExactRecords, PeekableRecords, Records, Resizable},
+    },
+    settings::{
+        rows::{find_fixed_rows, move_cell_config},
+        TableOption,
+    },
+    util::string::strip_ansi,
+};
+use core::cmp::Ordering;
+
+/// Sort reorders data rows of a [`Table`] by one or more columns.
+///
+/// By default the first row is considered to be a header, so it's kept in place.
+/// It can be changed by [`Sort::header`].
+///
+/// Rows which are a panel (a single cell spread across all columns, like [`Panel::header`] or [`Panel::footer`]),
+/// as well as rows which are a part of a vertical span are kept in place too.
+///
+/// Sorting is stable, so rows which are equal keep their relative order.
+///
+/// A per cell configuration (padding, alignment, formatting, colors) is moved together with the rows.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{Sort, Panel}};
+/// use testing_table::assert_table;
+///
+/// let data = [("b", 3), ("a", 2), ("c", 1), ("a", 1)];
+///
+/// let mut table = Table::new(data);
+/// table.with(Panel::footer("total 7"));
+/// table.with(Sort::column(0).then(Sort::column(1).numeric()));
+///
+/// assert_table!(
+///     table,
+///     "+------+-----+"
+///     "| &str | i32 |"
+///     "+------+-----+"
+///     "| a    | 1   |"
+///     "+------+-----+"
+///     "| a    | 2   |"
+///     "+------+-----+"
+///     "| b    | 3   |"
+///     "+------+-----+"
+///     "| c    | 1   |"
+///     "+------+-----+"
+///     "| total 7    |"
+///     "+------+-----+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Panel::header`]: crate::settings::Panel::header
+/// [`Panel::footer`]: crate::settings::Panel::footer
+#[derive(Debug, Clone)]
+pub struct Sort<O> {
+    order: O,
+    header: usize,
+}
+
+impl Sort<()> {
+    /// Sort rows by a given column in ascending lexicographic order.
+    ///
+    /// Use [`Sort::numeric`], [`Sort::by`] and [`Sort::descending`] to change the order.
+    pub fn column(column: usize) -> Sort<SortColumn<Lexicographic>> {
+        Sort::new(SortColumn::new(column, Lexicographic))
+    }
+}
+
+impl<O> Sort<O> {
+    /// Creates a [`Sort`] with a custom [`RowOrder`].
+    pub fn new(order: O) -> Self {
+        Self { order, header: 1 }
+    }
+
+    /// Set an amount of leading rows which are considered to be a header,
+    /// and therefore kept in place.
+    ///
+    /// Panel rows are not counted.
+    ///
+    /// By default it's `1`.
+    pub fn header(mut self, count_rows: usize) -> Self {
+        self.header = count_rows;
+        self
+    }
+
+    /// Adds a sorting key which is used to order rows which are equal by the current keys.
+    ///
+    /// Header settings of the given [`Sort`] are ignored.
+    pub fn then<T>(self, sort: Sort<T>) -> Sort<(O, T)> {
+        Sort {
+            order: (self.order, sort.order),
+            header: self.header,
+        }
+    }
+}
+
+impl<C> Sort<SortColumn<C>> {
+    /// Sort in ascending order (the default).
+    pub fn ascending(mut self) -> Self {
+        self.order.descending = false;
+        self
+    }
+
+    /// Sort in descending order.
+    pub fn descending(mut self) -> Self {
+        self.order.descending = true;
+        self
+    }
+
+    /// Use a [`Lexicographic`] comparator.
+    pub fn lexicographic(self) -> Sort<SortColumn<Lexicographic>> {
+        self.comparator(Lexicographic)
+    }
+
+    /// Use a [`Numeric`] comparator.
+    pub fn numeric(self) -> Sort<SortColumn<Numeric>> {
+        self.comparator(Numeric)
+    }
+
+    /// Use a custom comparator closure over cells text.
+    pub fn by<F>(self, f: F) -> Sort<SortColumn<F>>
+    where
+        F: Fn(&str, &str) -> Ordering,
+    {
+        self.comparator(f)
+    }
+
+    /// Use a custom [`Comparator`].
+    pub fn comparator<T>(self, comparator: T) -> Sort<SortColumn<T>> {
+        Sort {
+            order: SortColumn {
+                column: self.order.column,
+                descending: self.order.descending,
+                comparator,
+            },
+            header: self.header,
+        }
+    }
+}
+
+/// A trait which defines an order of rows for [`Sort`].
+pub trait RowOrder<R> {
+    /// Compares 2 rows by their indexes.
+    fn compare(&self, records: &R, lhs: usize, rhs: usize) -> Ordering;
+}
+
+impl<R, A, B> RowOrder<R> for (A, B)
+where
+    A: RowOrder<R>,
+    B: RowOrder<R>,
+{
+    fn compare(&self, records: &R, lhs: usize, rhs: usize) -> Ordering {
+        self.0
+            .compare(records, lhs, rhs)
+            .then_with(|| self.1.compare(records, lhs, rhs))
+    }
+}
+
+/// A [`RowOrder`] by a single column.
+///
+/// When `ansi` feature is on, the text is compared without ANSI sequences.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortColumn<C> {
+    column: usize,
+    descending: bool,
+    comparator: C,
+}
+
+impl<C> SortColumn<C> {
+    /// Creates an ascending order by a column, using a given comparator.
+    pub fn new(column: usize, comparator: C) -> Self {
+        Self {
+            column,
+            descending: false,
+            comparator,
+        }
+    }
+}
+
+impl<R, C> RowOrder<R> for SortColumn<C>
+where
+    R: Records + PeekableRecords,
+    C: Comparator,
+{
+    fn compare(&self, records: &R, lhs: usize, rhs: usize) -> Ordering {
+        if self.column >= records.count_columns() {
+            return Ordering::Equal;
+        }
+
+        let lhs = strip_ansi(records.get_text((lhs, self.column)));
+        let rhs = strip_ansi(records.get_text((rhs, self.column)));
+
+        let ord = self.comparator.compare(&lhs, &rhs);
+
+        if self.descending {
+            ord.reverse()
+        } else {
+            ord
+        }
+    }
+}
+
+impl<O, R, D> TableOption<R, ColoredConfig, D> for Sort<O>
+where
+    O: RowOrder<R>,
+    R: Records + ExactRecords + PeekableRecords + Resizable,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+        if count_rows == 0 || count_cols == 0 {
+            return;
+        }
+
+        let slots = find_sorted_rows(cfg, count_rows, count_cols, self.header);
+        if slots.len() < 2 {
+            return;
+        }
+
+        let mut order = slots.clone();
+        order.sort_by(|&lhs, &rhs| self.order.compare(records, lhs, rhs));
+
+        reorder_rows(records, &slots, &order, count_rows);
+
+        let origin = cfg.clone();
+        for (&slot, &row) in slots.iter().zip(&order) {
+            if slot != row {
+                move_cell_config(cfg, &origin, row, slot, count_cols);
+            }
+        }
+    }
+}
+
+// Rows of a vertical span are kept in place as well as a header and panels.
+fn find_sorted_rows(
+    cfg: &SpannedConfig,
+    count_rows: usize,
+    count_cols: usize,
+    count_header: usize,
+) -> Vec<usize> {
+    let mut fixed = find_fixed_rows(cfg, count_rows, count_cols, count_header);
+
+    for ((row, _), span) in cfg.get_row_spans() {
+        let end = core::cmp::min(row + span, count_rows);
+        for is_fixed in &mut fixed[row..end] {
+            *is_fixed = true;
+        }
+    }
+
+    (0..count_rows).filter(|&row| !fixed[row]).collect()
+}
+
+// Moves rows so that `order[i]` row ends up at `slots[i]` position.
+fn reorder_rows<R>(records: &mut R, slots: &[usize], order: &[usize], count_rows: usize)
+where
+    R: Resizable,
+{
+    // position of an original row, and an original row at a position
+    let mut position = (0..count_rows).collect::<Vec<_>>();
+    let mut origin = (0..count_rows).collect::<Vec<_>>();
+
+    for (&slot, &row) in slots.iter().zip(order) {
+        let current = position[row];
+        if current == slot {
+            continue;
+        }
+
+        records.swap_row(slot, current);
+
+        let displaced = origin[slot];
+        origin.swap(slot, current);
+        position[row] = slot;
+        position[displaced] = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reorder_rows;
+
+    #[test]
+    fn test_reorder_rows() {
+        assert_eq!(
+            reorder(vec![0, 1, 2, 3], &[0, 1, 2, 3], &[3, 2, 1, 0]),
+            [3, 2, 1, 0]
+        );
+        assert_eq!(
+            reorder(vec![0, 1, 2, 3], &[1, 2, 3], &[2, 3, 1]),
+            [0, 2, 3, 1]
+        );
+        assert_eq!(
+            reorder(vec![0, 1, 2, 3], &[0, 2, 3], &[3, 0, 2]),
+            [3, 1, 0, 2]
+        );
+        assert_eq!(reorder(vec![0, 1, 2], &[0, 1, 2], &[0, 1, 2]), [0, 1, 2]);
+    }
+
+    fn reorder(data: Vec<usize>, slots: &[usize], order: &[usize]) -> Vec<usize> {
+        let count_rows = data.len();
+        let mut data = data.into_iter().map(|i| vec![i]).collect::<Vec<_>>();
+        reorder_rows(&mut data, slots, order, count_rows);
+        data.into_iter().map(|row| row[0]).collect()
+    }
+}
@@ -0,0 +1,469 @@
+//! This module contains [`ColumnLayout`] structure, used to set a [`Table`] width
+//! by a set of per column constraints.
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, SpannedConfig},
+        dimension::CompleteDimensionVecRecords,
+        records::{EmptyRecords, ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
+        util::width::{UnicodeWidth, WidthMetric},
+    },
+    settings::{
+        measurement::Measurement,
+        width::{Truncate, TruncateMode, Wrap},
+        CellOption, TableOption, Width,
+    },
+};
+
+use super::util::{get_table_widths, get_table_widths_with_total};
+
+/// [`ColumnLayout`] sets a table width to a given value
+/// distributing it among columns according to their [`ColumnConstraint`]s.
+///
+/// Columns which have no constraint set use a default one,
+/// which can be changed via [`ColumnLayout::others`].
+///
+/// The layout is solved in 2 steps:
+///
+/// 1. Columns with a zero flex weight take their content width bound by their min and max width.
+///    If there's not enough space they are shrunk down to their min width.
+/// 2. The rest of a width is distributed among other columns proportionally to their flex weights,
+///    starting from their min width and never exceeding their max width.
+///
+/// Then each cell which doesn't fit its column is changed according to a [`ColumnOverflow`] policy of the column.
+///
+/// Constraints are set for a column content, so they don't consider padding.
+/// While the target width is a total table width including borders, padding and margin.
+///
+/// If constraints can't be satisfied (e.g. min widths exceed the target width)
+/// the table gets wider than the target width.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{
+///     settings::width::{ColumnConstraint, ColumnOverflow, TruncateMode, Width},
+///     Table,
+/// };
+///
+/// let data = [
+///     ("src/settings/width/column_layout.rs", "0c5d3c9f8e7d", "Per-column width constraints"),
+///     ("src/settings/width/wrap.rs", "9f8e7d6c5b4a", "Smarter word-breaking"),
+/// ];
+///
+/// let path = ColumnConstraint::new()
+///     .max(20)
+///     .flex(2)
+///     .overflow(ColumnOverflow::Elide(TruncateMode::Path));
+/// let hash = ColumnConstraint::fixed(7).overflow(ColumnOverflow::Truncate);
+/// let text = ColumnConstraint::new()
+///     .min(10)
+///     .overflow(ColumnOverflow::WrapWords);
+///
+/// let table = Table::new(data)
+///     .with(Width::layout(60).column(0, path).column(1, hash).others(text))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+----------------------+---------+-------------------------+\n\
+///      | &str                 | &str    | &str                    |\n\
+///      +----------------------+---------+-------------------------+\n\
+///      | …/column_layout.rs   | 0c5d3c9 | Per-column width        |\n\
+///      |                      |         | constraints             |\n\
+///      +----------------------+---------+-------------------------+\n\
+///      | …/width/wrap.rs      | 9f8e7d6 | Smarter word-breaking   |\n\
+///      +----------------------+---------+-------------------------+"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColumnLayout<W = usize, M = UnicodeWidth> {
+    width: W,
+    columns: Vec<(usize, ColumnConstraint)>,
+    others: ColumnConstraint,
+    metric: M,
+}
+
+/// A set of width constraints of a column used by [`ColumnLayout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ColumnConstraint {
+    min: usize,
+    max: Option<usize>,
+    flex: usize,
+    overflow: ColumnOverflow,
+}
+
+/// A policy of handling a content which doesn't fit a column width set by [`ColumnLayout`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColumnOverflow {
+    /// Wrap a content, see [`Wrap`].
+    #[default]
+    Wrap,
+    /// Wrap a content keeping words, see [`Wrap::keep_words`].
+    WrapWords,
+    /// Truncate a content, see [`Truncate`].
+    Truncate,
+    /// Truncate a content putting `…` in place of a cut part, see [`Truncate::mode`].
+    Elide(TruncateMode),
+}
+
+impl<W> ColumnLayout<W>
+where
+    W: Measurement<Width>,
+{
+    /// Creates a new [`ColumnLayout`] with a target table width.
+    pub fn new(width: W) -> Self {
+        Self {
+            width,
+            columns: Vec::new(),
+            others: ColumnConstraint::new(),
+            metric: UnicodeWidth,
+        }
+    }
+}
+
+impl<W, M> ColumnLayout<W, M> {
+    /// Sets a constraint of a column.
+    pub fn column(mut self, column: usize, constraint: ColumnConstraint) -> Self {
+        self.columns.retain(|(i, _)| *i != column);
+        self.columns.push((column, constraint));
+        self
+    }
+
+    /// Sets a constraint which is used for columns without a constraint set.
+    ///
+    /// By default it's [`ColumnConstraint::new`].
+    pub fn others(mut self, constraint: ColumnConstraint) -> Self {
+        self.others = constraint;
+        self
+    }
+
+    /// Sets a [`WidthMetric`] which is used to measure and change a content.
+    ///
+    /// By default [`UnicodeWidth`] is used.
+    pub fn metric<MM: WidthMetric>(self, metric: MM) -> ColumnLayout<W, MM> {
+        ColumnLayout {
+            width: self.width,
+            columns: self.columns,
+            others: self.others,
+            metric,
+        }
+    }
+
+    fn get_constraint(&self, column: usize) -> &ColumnConstraint {
+        self.columns
+            .iter()
+            .find(|(i, _)| *i == column)
+            .map(|(_, constraint)| constraint)
+            .unwrap_or(&self.others)
+    }
+}
+
+impl ColumnConstraint {
+    /// Creates a constraint with no min and max width and a flex weight equal to 1.
+    pub const fn new() -> Self {
+        Self {
+            min: 0,
+            max: None,
+            flex: 1,
+            overflow: ColumnOverflow::Wrap,
+        }
+    }
+
+    /// Creates a constraint of a column with a constant width.
+    pub const fn fixed(width: usize) -> Self {
+        Self {
+            min: width,
+            max: Some(width),
+            flex: 0,
+            overflow: ColumnOverflow::Wrap,
+        }
+    }
+
+    /// Sets a min width of a column.
+    pub const fn min(mut self, width: usize) -> Self {
+        self.min = width;
+        self
+    }
+
+    /// Sets a max width of a column.
+    pub const fn max(mut self, width: usize) -> Self {
+        self.max = Some(width);
+        self
+    }
+
+    /// Sets a flex weight of a column.
+    ///
+    /// A free space is distributed among columns proportionally to their weights.
+    /// A column with a zero weight takes its content width (bound by its min and max width).
+    pub const fn flex(mut self, weight: usize) -> Self {
+        self.flex = weight;
+        self
+    }
+
+    /// Sets a policy of handling a content which doesn't fit a column.
+    pub const fn overflow(mut self, overflow: ColumnOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    fn get_max(&self) -> usize {
+        std::cmp::max(self.min, self.max.unwrap_or(usize::MAX))
+    }
+}
+
+impl Default for ColumnConstraint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W, M, R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>> for ColumnLayout<W, M>
+where
+    W: Measurement<Width>,
+    M: WidthMetric,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+    for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
+{
+    fn change(
+        self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        let shape = (records.count_rows(), records.count_columns());
+        if shape.0 == 0 || shape.1 == 0 {
+            return;
+        }
+
+        let width = self.width.measure(&*records, cfg);
+        let widths = get_table_widths(&*records, cfg, &self.metric);
+        let (min_widths, min_total) =
+            get_table_widths_with_total(EmptyRecords::from(shape), cfg, &self.metric);
+
+        let content_widths = widths
+            .iter()
+            .zip(&min_widths)
+            .map(|(width, min)| width.saturating_sub(*min))
+            .collect::<Vec<_>>();
+
+        let constraints = (0..shape.1)
+            .map(|col| *self.get_constraint(col))
+            .collect::<Vec<_>>();
+
+        let available = width.saturating_sub(min_total);
+        let content_widths = solve_layout(&constraints, &content_widths, available);
+
+        let widths = content_widths
+            .iter()
+            .zip(&min_widths)
+            .map(|(width, min)| width + min)
+            .collect::<Vec<_>>();
+
+        let points = get_decrease_cell_list(cfg, &widths, &min_widths, shape);
+
+        for ((row, col), width) in points {
+            let text = records.get_text((row, col));
+            if self.metric.text_width(text) <= width {
+                continue;
+            }
+
+            let pos = (row, col).into();
+            match constraints[col].overflow {
+                ColumnOverflow::Wrap => {
+                    let wrap = Wrap::new(width).metric(&self.metric);
+                    CellOption::change(wrap, records, cfg, pos);
+                }
+                ColumnOverflow::WrapWords => {
+                    let wrap = Wrap::new(width).keep_words(true).metric(&self.metric);
+                    CellOption::change(wrap, records, cfg, pos);
+                }
+                ColumnOverflow::Truncate => {
+                    let truncate = Truncate::new(width).metric(&self.metric);
+                    CellOption::change(truncate, records, cfg, pos);
+                }
+                ColumnOverflow::Elide(mode) => {
+                    let truncate = Truncate::new(width)
+                        .suffix("…")
+                        .mode(mode)
+                        .metric(&self.metric);
+                    CellOption::change(truncate, records, cfg, pos);
+                }
+            }
+        }
+
+        dims.set_widths(widths);
+    }
+}
+
+/// Returns a content width of each column.
+fn solve_layout(
+    constraints: &[ColumnConstraint],
+    widths: &[usize],
+    available: usize,
+) -> Vec<usize> {
+    let mut list = constraints
+        .iter()
+        .zip(widths)
+        .map(|(c, &width)| {
+            if c.flex == 0 {
+                width.clamp(c.min, c.get_max())
+            } else {
+                c.min
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut total = list.iter().sum::<usize>();
+
+    // shrink content sized columns if there's not enough space
+    while total > available {
+        let col = constraints
+            .iter()
+            .zip(&list)
+            .enumerate()
+            .filter(|(_, (c, &width))| c.flex == 0 && width > c.min)
+            .max_by_key(|(_, (c, &width))| width - c.min)
+            .map(|(col, _)| col);
+
+        match col {
+            Some(col) => {
+                list[col] -= 1;
+                total -= 1;
+            }
+            None => return list,
+        }
+    }
+
+    // distribute the rest among flexible columns
+    let mut rest = available - total;
+    while rest > 0 {
+        let growable = (0..list.len())
+            .filter(|&col| constraints[col].flex > 0 && list[col] < constraints[col].get_max())
+            .collect::<Vec<_>>();
+
+        if growable.is_empty() {
+            break;
+        }
+
+        let total_flex = growable
+            .iter()
+            .map(|&col| constraints[col].flex)
+            .sum::<usize>();
+
+        let mut given = 0;
+        for &col in &growable {
+            let share = rest * constraints[col].flex / total_flex;
+            let share = std::cmp::min(share, constraints[col].get_max() - list[col]);
+            list[col] += share;
+            given += share;
+        }
+
+        if given == 0 {
+            // a rest is too small to be split by weights so we give it one by one
+            let mut growable = growable;
+            growable.sort_by_key(|&col| std::cmp::Reverse(constraints[col].flex));
+
+            for col in growable.into_iter().take(rest) {
+                list[col] += 1;
+                given += 1;
+            }
+        }
+
+        rest -= given;
+    }
+
+    list
+}
+
+fn get_decrease_cell_list(
+    cfg: &SpannedConfig,
+    widths: &[usize],
+    min_widths: &[usize],
+    shape: (usize, usize),
+) -> Vec<((usize, usize), usize)> {
+    let mut points = Vec::new();
+    (0..shape.1).for_each(|col| {
+        (0..shape.0)
+            .filter(|&row| cfg.is_cell_visible((row, col)))
+            .for_each(|row| {
+                let (width, width_min) = match cfg.get_column_span((row, col)) {
+                    Some(span) => {
+                        let width = (col..col + span).map(|i| widths[i]).sum::<usize>();
+                        let min_width = (col..col + span).map(|i| min_widths[i]).sum::<usize>();
+                        let count_borders = count_borders(cfg, col, col + span, shape.1);
+                        (width + count_borders, min_width + count_borders)
+                    }
+                    None => (widths[col], min_widths[col]),
+                };
+
+                if width >= width_min {
+                    let padding = cfg.get_padding((row, col).into());
+                    let width = width.saturating_sub(padding.left.size + padding.right.size);
+
+                    points.push(((row, col), width));
+                }
+            });
+    });
+
+    points
+}
+
+fn count_borders(cfg: &SpannedConfig, start: usize, end: usize, count_columns: usize) -> usize {
+    (start..end)
+        .skip(1)
+        .filter(|&i| cfg.has_vertical(i, count_columns))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_layout_test() {
+        let flex = ColumnConstraint::new;
+
+        assert_eq!(solve_layout(&[flex(), flex()], &[5, 5], 10), [5, 5]);
+        assert_eq!(solve_layout(&[flex(), flex()], &[5, 5], 11), [6, 5]);
+        assert_eq!(
+            solve_layout(&[flex().flex(2), flex()], &[0, 0], 30),
+            [20, 10]
+        );
+        assert_eq!(solve_layout(&[flex().max(5), flex()], &[0, 0], 30), [5, 25]);
+        assert_eq!(
+            solve_layout(&[flex().min(10), flex()], &[0, 0], 12),
+            [11, 1]
+        );
+        assert_eq!(
+            solve_layout(&[flex().min(10), flex().min(10)], &[0, 0], 12),
+            [10, 10]
+        );
+        assert_eq!(
+            solve_layout(&[flex().max(3), flex().max(3)], &[0, 0], 12),
+            [3, 3]
+        );
+
+        let fixed = ColumnConstraint::fixed;
+
+        assert_eq!(solve_layout(&[fixed(8), flex()], &[20, 20], 30), [8, 22]);
+        assert_eq!(solve_layout(&[fixed(8), flex()], &[2, 20], 30), [8, 22]);
+        assert_eq!(solve_layout(&[fixed(8), fixed(4)], &[20, 20], 30), [8, 4]);
+
+        let sized = || ColumnConstraint::new().flex(0);
+
+        assert_eq!(solve_layout(&[sized(), flex()], &[7, 20], 30), [7, 23]);
+        assert_eq!(solve_layout(&[sized(), sized()], &[7, 20], 20), [7, 13]);
+        assert_eq!(
+            solve_layout(&[sized().min(9), sized()], &[7, 20], 10),
+            [9, 1]
+        );
+        assert_eq!(
+            solve_layout(&[sized().max(5), flex()], &[7, 20], 30),
+            [5, 25]
+        );
+    }
+}
@@ -3,6 +3,7 @@
 use crate::{
     grid::config::ColoredConfig,
     grid::records::{ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
+    grid::util::width::{UnicodeWidth, WidthMetric},
     settings::{
         measurement::{Max, Measurement, Min},
         CellOption, TableOption, Width,
@@ -41,8 +42,9 @@ use crate::{
 ///
 /// [`Padding`]: crate::settings::Padding
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Justify<W> {
+pub struct Justify<W, M = UnicodeWidth> {
     width: W,
+    metric: M,
 }
 
 impl<W> Justify<W>
@@ -55,27 +57,49 @@ where
     ///
     /// [`Padding`]: crate::settings::Padding
     pub fn new(width: W) -> Self {
-        Self { width }
+        Self {
+            width,
+            metric: UnicodeWidth,
+        }
     }
 }
 
 impl Justify<Max> {
     /// Creates a new Justify instance with a Max width used as a value.
     pub fn max() -> Self {
-        Self { width: Max }
+        Self {
+            width: Max,
+            metric: UnicodeWidth,
+        }
     }
 }
 
 impl Justify<Min> {
     /// Creates a new Justify instance with a Min width used as a value.
     pub fn min() -> Self {
-        Self { width: Min }
+        Self {
+            width: Min,
+            metric: UnicodeWidth,
+        }
+    }
+}
+
+impl<W, M> Justify<W, M> {
+    /// Sets a [`WidthMetric`] which is used to measure a content.
+    ///
+    /// By default [`UnicodeWidth`] is used.
+    pub fn metric<MM: WidthMetric>(self, metric: MM) -> Justify<W, MM> {
+        Justify {
+            width: self.width,
+            metric,
+        }
     }
 }
 
-impl<R, D, W> TableOption<R, ColoredConfig, D> for Justify<W>
+impl<R, D, W, M> TableOption<R, ColoredConfig, D> for Justify<W, M>
 where
     W: Measurement<Width>,
+    M: WidthMetric,
     R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
@@ -89,8 +113,10 @@ where
         for row in 0..count_rows {
             for col in 0..count_columns {
                 let pos = (row, col).into();
-                CellOption::change(Width::increase(width), records, cfg, pos);
-                CellOption::change(Width::truncate(width), records, cfg, pos);
+                let increase = Width::increase(width).metric(&self.metric);
+                let truncate = Width::truncate(width).metric(&self.metric);
+                CellOption::change(increase, records, cfg, pos);
+                CellOption::change(truncate, records, cfg, pos);
             }
         }
     }
@@ -0,0 +1,77 @@
+//! This module contains [`Metric`] structure, used to set a [`WidthMetric`] of a [`Table`].
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::Entity,
+        records::vec_records::{Text, VecRecords},
+        util::width::WidthMetric,
+    },
+    settings::TableOption,
+};
+
+/// [`Metric`] re-measures all cells of a [`Table`] by a given [`WidthMetric`],
+/// so the table is rendered according to it.
+///
+/// Be aware that it must be applied after all options which change a content,
+/// because a changed cell is measured by a default metric again.
+/// Use a `metric` method of [`Wrap`], [`Truncate`], [`MinWidth`] and [`Justify`]
+/// to make them measure a content by the same metric.
+///
+/// ## Example
+///
+/// ```
+/// use tabled::{settings::Width, grid::util::width::AmbiguousWide, Table};
+///
+/// let table = Table::new(["±1", "○"]).with(Width::metric(AmbiguousWide)).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+------+\n\
+///      | &str |\n\
+///      +------+\n\
+///      | ±1  |\n\
+///      +------+\n\
+///      | ○   |\n\
+///      +------+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Wrap`]: crate::settings::width::Wrap
+/// [`Truncate`]: crate::settings::width::Truncate
+/// [`MinWidth`]: crate::settings::width::MinWidth
+/// [`Justify`]: crate::settings::width::Justify
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Metric<M> {
+    metric: M,
+}
+
+impl<M> Metric<M>
+where
+    M: WidthMetric,
+{
+    /// Creates a new [`Metric`] instance.
+    pub fn new(metric: M) -> Self {
+        Self { metric }
+    }
+}
+
+impl<M, C, D> TableOption<VecRecords<Text<String>>, C, D> for Metric<M>
+where
+    M: WidthMetric,
+{
+    fn change(self, records: &mut VecRecords<Text<String>>, _: &mut C, _: &mut D) {
+        for row in records.iter_mut() {
+            for cell in row.iter_mut() {
+                let text = std::mem::take(cell).into_inner();
+                *cell = Text::with_metric(text, &self.metric);
+            }
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
@@ -6,7 +6,10 @@ use crate::{
     grid::config::{ColoredConfig, Entity},
     grid::dimension::CompleteDimensionVecRecords,
     grid::records::{ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
-    grid::util::string::{get_lines, get_text_width},
+    grid::util::{
+        string::get_lines,
+        width::{UnicodeWidth, WidthMetric},
+    },
     settings::{
         measurement::Measurement,
         peaker::{Peaker, PriorityNone},
@@ -52,10 +55,11 @@ use super::util::get_table_widths_with_total;
 ///
 /// [`Padding`]: crate::settings::Padding
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct MinWidth<W = usize, P = PriorityNone> {
+pub struct MinWidth<W = usize, P = PriorityNone, M = UnicodeWidth> {
     width: W,
     fill: char,
     priority: P,
+    metric: M,
 }
 
 impl<W> MinWidth<W>
@@ -68,11 +72,12 @@ where
             width,
             fill: ' ',
             priority: PriorityNone::new(),
+            metric: UnicodeWidth,
         }
     }
 }
 
-impl<W, P> MinWidth<W, P> {
+impl<W, P, M> MinWidth<W, P, M> {
     /// Set's a fill character which will be used to fill the space
     /// when increasing the length of the string to the set boundary.
     ///
@@ -90,18 +95,32 @@ impl<W, P> MinWidth<W, P> {
     ///
     /// [`PriorityMax`]: crate::settings::peaker::PriorityMax
     /// [`PriorityMin`]: crate::settings::peaker::PriorityMin
-    pub fn priority<PP: Peaker>(self, peacker: PP) -> MinWidth<W, PP> {
+    pub fn priority<PP: Peaker>(self, peacker: PP) -> MinWidth<W, PP, M> {
         MinWidth {
             fill: self.fill,
             width: self.width,
             priority: peacker,
+            metric: self.metric,
+        }
+    }
+
+    /// Sets a [`WidthMetric`] which is used to measure a content.
+    ///
+    /// By default [`UnicodeWidth`] is used.
+    pub fn metric<MM: WidthMetric>(self, metric: MM) -> MinWidth<W, P, MM> {
+        MinWidth {
+            fill: self.fill,
+            width: self.width,
+            priority: self.priority,
+            metric,
         }
     }
 }
 
-impl<W, R> CellOption<R, ColoredConfig> for MinWidth<W>
+impl<W, M, R> CellOption<R, ColoredConfig> for MinWidth<W, PriorityNone, M>
 where
     W: Measurement<Width>,
+    M: WidthMetric,
     R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
@@ -119,12 +138,12 @@ where
             }
 
             let cell = records.get_text(pos);
-            let cell_width = get_text_width(cell);
+            let cell_width = self.metric.text_width(cell);
             if cell_width >= width {
                 continue;
             }
 
-            let content = increase_width(cell, width, self.fill);
+            let content = increase_width(cell, width, self.fill, &self.metric);
             records.set(pos, content);
         }
     }
@@ -134,10 +153,12 @@ where
     }
 }
 
-impl<W, P, R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>> for MinWidth<W, P>
+impl<W, P, M, R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>>
+    for MinWidth<W, P, M>
 where
     W: Measurement<Width>,
     P: Peaker,
+    M: WidthMetric,
     R: Records + ExactRecords + PeekableRecords,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
@@ -154,7 +175,7 @@ where
 
         let necessary_width = self.width.measure(&*records, cfg);
 
-        let (widths, total_width) = get_table_widths_with_total(&*records, cfg);
+        let (widths, total_width) = get_table_widths_with_total(&*records, cfg, &self.metric);
         if total_width >= necessary_width {
             return;
         }
@@ -190,13 +211,12 @@ where
     widths
 }
 
-fn increase_width(s: &str, width: usize, fill_with: char) -> String {
-    use crate::grid::util::string::get_line_width;
+fn increase_width<M: WidthMetric>(s: &str, width: usize, fill_with: char, metric: &M) -> String {
     use std::{borrow::Cow, iter::repeat};
 
     get_lines(s)
         .map(|line| {
-            let length = get_line_width(&line);
+            let length = metric.line_width(&line);
 
             if length < width {
                 let mut line = line.into_owned();
@@ -3,6 +3,8 @@
 //! - [`Truncate`] cuts a cell content to limit width.
 //! - [`Wrap`] split the content via new lines in order to fit max width.
 //! - [`Justify`] sets columns width to the same value.
+//! - [`ColumnLayout`] sets columns width by a set of min/max/flex constraints.
+//! - [`Metric`] sets a [`WidthMetric`] which is used to measure a content.
 //!
 //! To set a a table width, a combination of [`Width::truncate`] or [`Width::wrap`] and [`Width::increase`] can be used.
 //!
@@ -32,19 +34,23 @@
 //! );
 //! ```
 
+mod column_layout;
 mod justify;
+mod metric;
 mod min_width;
 mod truncate;
 mod util;
 mod width_list;
 mod wrap;
 
-use crate::settings::measurement::Measurement;
+use crate::{grid::util::width::WidthMetric, settings::measurement::Measurement};
 
 pub use self::{
+    column_layout::{ColumnConstraint, ColumnLayout, ColumnOverflow},
     justify::Justify,
+    metric::Metric,
     min_width::MinWidth,
-    truncate::{SuffixLimit, Truncate},
+    truncate::{SuffixLimit, Truncate, TruncateMode},
     width_list::WidthList,
     wrap::Wrap,
 };
@@ -160,4 +166,64 @@ impl Width {
     pub fn list<I: IntoIterator<Item = usize>>(rows: I) -> WidthList {
         WidthList::new(rows.into_iter().collect())
     }
+
+    /// Returns a [`ColumnLayout`] structure.
+    ///
+    /// It sets a table width distributing it among columns by their [`ColumnConstraint`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{settings::{width::ColumnConstraint, Width}, Table};
+    ///
+    /// let data = [("Hello World", "!")];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Width::layout(20).column(1, ColumnConstraint::fixed(3)))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------------+-----+\n\
+    ///      | &str       | &st |\n\
+    ///      |            | r   |\n\
+    ///      +------------+-----+\n\
+    ///      | Hello Worl | !   |\n\
+    ///      | d          |     |\n\
+    ///      +------------+-----+"
+    /// );
+    /// ```
+    pub fn layout<W: Measurement<Width>>(width: W) -> ColumnLayout<W> {
+        ColumnLayout::new(width)
+    }
+
+    /// Returns a [`Metric`] structure.
+    ///
+    /// It makes a table being measured by a given [`WidthMetric`],
+    /// e.g. to render East Asian Ambiguous characters as wide ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{settings::Width, grid::util::width::AmbiguousWide, Table};
+    ///
+    /// let table = Table::new(["±±±±±±"])
+    ///     .with(Width::wrap(8).metric(AmbiguousWide))
+    ///     .with(Width::metric(AmbiguousWide))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------+\n\
+    ///      | &str |\n\
+    ///      +------+\n\
+    ///      | ±± |\n\
+    ///      | ±± |\n\
+    ///      | ±± |\n\
+    ///      +------+"
+    /// );
+    /// ```
+    pub fn metric<M: WidthMetric>(metric: M) -> Metric<M> {
+        Metric::new(metric)
+    }
 }
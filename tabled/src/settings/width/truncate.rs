@@ -9,7 +9,7 @@ use crate::{
         config::{ColoredConfig, Entity, SpannedConfig},
         dimension::CompleteDimensionVecRecords,
         records::{EmptyRecords, ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
-        util::string::{get_line_width, get_text_width},
+        util::width::{UnicodeWidth, WidthMetric},
     },
     settings::{
         measurement::Measurement,
@@ -19,7 +19,7 @@ use crate::{
 };
 
 use super::util::{get_table_widths, get_table_widths_with_total};
-use crate::util::string::cut_str;
+use crate::util::string::{cut_str, cut_str_tail, strip_ansi};
 
 /// Truncate cut the string to a given width if its length exceeds it.
 /// Otherwise keeps the content of a cell untouched.
@@ -40,11 +40,13 @@ use crate::util::string::cut_str;
 ///
 /// [`Padding`]: crate::settings::Padding
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Truncate<'a, W = usize, P = PriorityNone> {
+pub struct Truncate<'a, W = usize, P = PriorityNone, M = UnicodeWidth> {
     width: W,
     suffix: Option<TruncateSuffix<'a>>,
     multiline: bool,
+    mode: TruncateMode,
     priority: P,
+    metric: M,
 }
 
 #[cfg(feature = "ansi")]
@@ -73,6 +75,25 @@ impl Default for TruncateSuffix<'_> {
     }
 }
 
+/// A side of a text which is cut by [`Truncate`].
+///
+/// A suffix set by [`Truncate::suffix`] is put in place of a cut part.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TruncateMode {
+    /// Cut the end of a text (`src/deep/pa…`).
+    #[default]
+    End,
+    /// Cut the start of a text (`…/deep/path/file.rs`).
+    Start,
+    /// Cut the middle of a text (`abcd…wxyz`).
+    Middle,
+    /// Cut whole leading components of a path (`…/path/file.rs`).
+    ///
+    /// Both `/` and `\\` are considered to be separators.
+    /// If even the last component doesn't fit it's cut as [`TruncateMode::Start`].
+    Path,
+}
+
 /// A suffix limit settings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SuffixLimit {
@@ -93,13 +114,15 @@ where
         Self {
             width,
             multiline: false,
+            mode: TruncateMode::End,
             suffix: None,
             priority: PriorityNone::new(),
+            metric: UnicodeWidth,
         }
     }
 }
 
-impl<'a, W, P> Truncate<'a, W, P> {
+impl<'a, W, P, M> Truncate<'a, W, P, M> {
     /// Sets a suffix which will be appended to a resultant string.
     ///
     /// The suffix is used in 3 circumstances:
@@ -108,57 +131,105 @@ impl<'a, W, P> Truncate<'a, W, P> {
     ///     2. If suffix is bigger than the original string.
     ///        We cut the suffix to fit in the width by default.
     ///        But you can peak the behaviour by using [`Truncate::suffix_limit`]
-    pub fn suffix<S: Into<Cow<'a, str>>>(self, suffix: S) -> Truncate<'a, W, P> {
+    pub fn suffix<S: Into<Cow<'a, str>>>(self, suffix: S) -> Truncate<'a, W, P, M> {
         let mut suff = self.suffix.unwrap_or_default();
         suff.text = suffix.into();
 
         Truncate {
             width: self.width,
             multiline: self.multiline,
+            mode: self.mode,
             priority: self.priority,
             suffix: Some(suff),
+            metric: self.metric,
         }
     }
 
     /// Sets a suffix limit, which is used when the suffix is too big to be used.
-    pub fn suffix_limit(self, limit: SuffixLimit) -> Truncate<'a, W, P> {
+    pub fn suffix_limit(self, limit: SuffixLimit) -> Truncate<'a, W, P, M> {
         let mut suff = self.suffix.unwrap_or_default();
         suff.limit = limit;
 
         Truncate {
             width: self.width,
             multiline: self.multiline,
+            mode: self.mode,
             priority: self.priority,
             suffix: Some(suff),
+            metric: self.metric,
         }
     }
 
     /// Use trancate logic per line, not as a string as a whole.
-    pub fn multiline(self, on: bool) -> Truncate<'a, W, P> {
+    pub fn multiline(self, on: bool) -> Truncate<'a, W, P, M> {
         Truncate {
             width: self.width,
             multiline: on,
+            mode: self.mode,
             suffix: self.suffix,
             priority: self.priority,
+            metric: self.metric,
+        }
+    }
+
+    /// Sets a side of a text which is cut.
+    ///
+    /// A suffix is put in place of a cut part.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{object::Rows, width::TruncateMode, Width},
+    ///     Table,
+    /// };
+    ///
+    /// let data = ["src/settings/width/truncate.rs", "9f8e7d6c5b4a39281706"];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.modify(Rows::single(1), Width::truncate(20).suffix("…").mode(TruncateMode::Path));
+    /// table.modify(Rows::single(2), Width::truncate(9).suffix("…").mode(TruncateMode::Middle));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---------------------+\n\
+    ///      | &str                |\n\
+    ///      +---------------------+\n\
+    ///      | …/width/truncate.rs |\n\
+    ///      +---------------------+\n\
+    ///      | 9f8e…1706           |\n\
+    ///      +---------------------+"
+    /// );
+    /// ```
+    pub fn mode(self, mode: TruncateMode) -> Truncate<'a, W, P, M> {
+        Truncate {
+            width: self.width,
+            multiline: self.multiline,
+            mode,
+            suffix: self.suffix,
+            priority: self.priority,
+            metric: self.metric,
         }
     }
 
     #[cfg(feature = "ansi")]
     /// Sets a optional logic to try to colorize a suffix.
-    pub fn suffix_try_color(self, color: bool) -> Truncate<'a, W, P> {
+    pub fn suffix_try_color(self, color: bool) -> Truncate<'a, W, P, M> {
         let mut suff = self.suffix.unwrap_or_default();
         suff.try_color = color;
 
         Truncate {
             width: self.width,
             multiline: self.multiline,
+            mode: self.mode,
             priority: self.priority,
             suffix: Some(suff),
+            metric: self.metric,
         }
     }
 }
 
-impl<'a, W, P> Truncate<'a, W, P> {
+impl<'a, W, P, M> Truncate<'a, W, P, M> {
     /// Priority defines the logic by which a truncate will be applied when is done for the whole table.
     ///
     /// - [`PriorityNone`] which cuts the columns one after another.
@@ -167,12 +238,52 @@ impl<'a, W, P> Truncate<'a, W, P> {
     ///
     /// [`PriorityMax`]: crate::settings::peaker::PriorityMax
     /// [`PriorityMin`]: crate::settings::peaker::PriorityMin
-    pub fn priority<PP: Peaker>(self, priority: PP) -> Truncate<'a, W, PP> {
+    pub fn priority<PP: Peaker>(self, priority: PP) -> Truncate<'a, W, PP, M> {
         Truncate {
             width: self.width,
             multiline: self.multiline,
+            mode: self.mode,
             suffix: self.suffix,
             priority,
+            metric: self.metric,
+        }
+    }
+
+    /// Sets a [`WidthMetric`] which is used to measure and cut a content.
+    ///
+    /// By default [`UnicodeWidth`] is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{
+    ///     grid::util::width::AmbiguousWide,
+    ///     settings::{object::Rows, Width},
+    ///     Table,
+    /// };
+    ///
+    /// let table = Table::new(["±±±±"])
+    ///     .modify(Rows::new(1..), Width::truncate(4).metric(AmbiguousWide))
+    ///     .with(Width::metric(AmbiguousWide))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------+\n\
+    ///      | &str |\n\
+    ///      +------+\n\
+    ///      | ±± |\n\
+    ///      +------+"
+    /// );
+    /// ```
+    pub fn metric<MM: WidthMetric>(self, metric: MM) -> Truncate<'a, W, P, MM> {
+        Truncate {
+            width: self.width,
+            multiline: self.multiline,
+            mode: self.mode,
+            suffix: self.suffix,
+            priority: self.priority,
+            metric,
         }
     }
 }
@@ -180,13 +291,14 @@ impl<'a, W, P> Truncate<'a, W, P> {
 impl Truncate<'_, (), ()> {
     /// Truncate a given string
     pub fn truncate(text: &str, width: usize) -> Cow<'_, str> {
-        truncate_text(text, width, "", false)
+        truncate_text(text, width, "", false, TruncateMode::End, &UnicodeWidth)
     }
 }
 
-impl<W, P, R> CellOption<R, ColoredConfig> for Truncate<'_, W, P>
+impl<W, P, M, R> CellOption<R, ColoredConfig> for Truncate<'_, W, P, M>
 where
     W: Measurement<Width>,
+    M: WidthMetric,
     R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
@@ -198,7 +310,7 @@ where
         let mut suffix = Cow::Borrowed("");
 
         if let Some(x) = self.suffix.as_ref() {
-            let (cut_suffix, rest_width) = make_suffix(x, width);
+            let (cut_suffix, rest_width) = make_suffix(x, width, &self.metric);
             suffix = cut_suffix;
             width = rest_width;
         };
@@ -216,26 +328,37 @@ where
 
             let text = records.get_text(pos);
 
-            let cell_width = get_text_width(text);
+            let cell_width = self.metric.text_width(text);
             if available >= cell_width {
                 continue;
             }
 
-            let text =
-                truncate_multiline(text, &suffix, width, available, colorize, self.multiline);
+            let text = truncate_multiline(
+                text,
+                &suffix,
+                width,
+                available,
+                colorize,
+                self.multiline,
+                self.mode,
+                &self.metric,
+            );
 
             records.set(pos, text.into_owned());
         }
     }
 }
 
-fn truncate_multiline<'a>(
+#[allow(clippy::too_many_arguments)]
+fn truncate_multiline<'a, M: WidthMetric>(
     text: &'a str,
     suffix: &'a str,
     width: usize,
     twidth: usize,
     suffix_color: bool,
     multiline: bool,
+    mode: TruncateMode,
+    metric: &M,
 ) -> Cow<'a, str> {
     if multiline {
         let mut buf = String::new();
@@ -244,22 +367,25 @@ fn truncate_multiline<'a>(
                 buf.push('\n');
             }
 
-            let line = make_text_truncated(&line, suffix, width, twidth, suffix_color);
+            let line =
+                make_text_truncated(&line, suffix, width, twidth, suffix_color, mode, metric);
             buf.push_str(&line);
         }
 
         Cow::Owned(buf)
     } else {
-        make_text_truncated(text, suffix, width, twidth, suffix_color)
+        make_text_truncated(text, suffix, width, twidth, suffix_color, mode, metric)
     }
 }
 
-fn make_text_truncated<'a>(
+fn make_text_truncated<'a, M: WidthMetric>(
     text: &'a str,
     suffix: &'a str,
     width: usize,
     twidth: usize,
     suffix_color: bool,
+    mode: TruncateMode,
+    metric: &M,
 ) -> Cow<'a, str> {
     if width == 0 {
         if twidth == 0 {
@@ -268,7 +394,7 @@ fn make_text_truncated<'a>(
             Cow::Borrowed(suffix)
         }
     } else {
-        truncate_text(text, width, suffix, suffix_color)
+        truncate_text(text, width, suffix, suffix_color, mode, metric)
     }
 }
 
@@ -283,8 +409,12 @@ fn need_suffix_color_preservation(_suffix: &Option<TruncateSuffix<'_>>) -> bool
     }
 }
 
-fn make_suffix<'a>(suffix: &'a TruncateSuffix<'_>, width: usize) -> (Cow<'a, str>, usize) {
-    let suffix_length = get_line_width(&suffix.text);
+fn make_suffix<'a, M: WidthMetric>(
+    suffix: &'a TruncateSuffix<'_>,
+    width: usize,
+    metric: &M,
+) -> (Cow<'a, str>, usize) {
+    let suffix_length = metric.line_width(&suffix.text);
     if width > suffix_length {
         return (Cow::Borrowed(suffix.text.as_ref()), width - suffix_length);
     }
@@ -292,7 +422,7 @@ fn make_suffix<'a>(suffix: &'a TruncateSuffix<'_>, width: usize) -> (Cow<'a, str
     match suffix.limit {
         SuffixLimit::Ignore => (Cow::Borrowed(""), width),
         SuffixLimit::Cut => {
-            let suffix = cut_str(&suffix.text, width);
+            let suffix = cut_str(&suffix.text, width, metric);
             (suffix, 0)
         }
         SuffixLimit::Replace(c) => {
@@ -302,10 +432,12 @@ fn make_suffix<'a>(suffix: &'a TruncateSuffix<'_>, width: usize) -> (Cow<'a, str
     }
 }
 
-impl<W, P, R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>> for Truncate<'_, W, P>
+impl<W, P, M, R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>>
+    for Truncate<'_, W, P, M>
 where
     W: Measurement<Width>,
     P: Peaker,
+    M: WidthMetric,
     R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
@@ -321,7 +453,7 @@ where
         }
 
         let width = self.width.measure(&*records, cfg);
-        let (widths, total) = get_table_widths_with_total(&*records, cfg);
+        let (widths, total) = get_table_widths_with_total(&*records, cfg, &self.metric);
         if total <= width {
             return;
         }
@@ -343,6 +475,8 @@ where
             self.priority,
             suffix,
             multiline,
+            self.mode,
+            &self.metric,
         );
 
         dims.set_widths(widths);
@@ -350,7 +484,7 @@ where
 }
 
 #[allow(clippy::too_many_arguments)]
-fn truncate_total_width<P, R>(
+fn truncate_total_width<P, M, R>(
     records: &mut R,
     cfg: &mut ColoredConfig,
     mut widths: Vec<usize>,
@@ -359,9 +493,12 @@ fn truncate_total_width<P, R>(
     priority: P,
     suffix: Option<TruncateSuffix<'_>>,
     multiline: bool,
+    mode: TruncateMode,
+    metric: &M,
 ) -> Vec<usize>
 where
     P: Peaker,
+    M: WidthMetric,
     R: Records + PeekableRecords + ExactRecords + RecordsMut<String>,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
@@ -369,69 +506,150 @@ where
     let count_rows = records.count_rows();
     let count_columns = records.count_columns();
 
-    let min_widths = get_table_widths(EmptyRecords::new(count_rows, count_columns), cfg);
+    let min_widths = get_table_widths(EmptyRecords::new(count_rows, count_columns), cfg, metric);
 
     decrease_widths(&mut widths, &min_widths, total, width, priority);
 
     let points = get_decrease_cell_list(cfg, &widths, &min_widths, (count_rows, count_columns));
 
     for ((row, col), width) in points {
-        let mut truncate = Truncate::new(width);
+        let mut truncate = Truncate::new(width).metric(metric);
         truncate.suffix.clone_from(&suffix);
         truncate.multiline = multiline;
+        truncate.mode = mode;
         CellOption::change(truncate, records, cfg, (row, col).into());
     }
 
     widths
 }
 
-fn truncate_text<'a>(
+fn truncate_text<'a, M: WidthMetric>(
     text: &'a str,
     width: usize,
     suffix: &str,
-    _suffix_color: bool,
+    suffix_color: bool,
+    mode: TruncateMode,
+    metric: &M,
 ) -> Cow<'a, str> {
-    let content = cut_str(text, width);
-    if suffix.is_empty() {
-        return content;
+    match mode {
+        TruncateMode::End => {
+            let content = cut_str(text, width, metric);
+            if suffix.is_empty() {
+                return content;
+            }
+
+            let suffix = make_suffix_colored(text, suffix, suffix_color, false);
+
+            let mut content = content.into_owned();
+            content.push_str(&suffix);
+            Cow::Owned(content)
+        }
+        _ => {
+            #[cfg(feature = "ansi")]
+            {
+                use super::wrap::build_link_prefix_suffix;
+                use crate::util::string::strip_osc;
+
+                let (text, url) = strip_osc(text);
+                let (prefix, link_suffix) = build_link_prefix_suffix(url);
+                let content = elide_text(&text, width, suffix, suffix_color, mode, metric);
+
+                Cow::Owned(format!("{prefix}{content}{link_suffix}"))
+            }
+
+            #[cfg(not(feature = "ansi"))]
+            {
+                Cow::Owned(elide_text(text, width, suffix, suffix_color, mode, metric))
+            }
+        }
+    }
+}
+
+fn elide_text<M: WidthMetric>(
+    text: &str,
+    width: usize,
+    suffix: &str,
+    suffix_color: bool,
+    mode: TruncateMode,
+    metric: &M,
+) -> String {
+    let (head, tail) = match mode {
+        TruncateMode::End => (cut_str(text, width, metric), Cow::Borrowed("")),
+        TruncateMode::Start => (Cow::Borrowed(""), cut_str_tail(text, width, metric)),
+        TruncateMode::Middle => {
+            let head = cut_str(text, width - width / 2, metric);
+            let tail = cut_str_tail(text, width / 2, metric);
+            (head, tail)
+        }
+        TruncateMode::Path => (Cow::Borrowed(""), cut_path(text, width, metric)),
+    };
+
+    let suffix = if head.is_empty() {
+        make_suffix_colored(&tail, suffix, suffix_color, true)
+    } else {
+        make_suffix_colored(&head, suffix, suffix_color, false)
+    };
+
+    let mut buf = String::with_capacity(head.len() + suffix.len() + tail.len());
+    buf.push_str(&head);
+    buf.push_str(&suffix);
+    buf.push_str(&tail);
+
+    buf
+}
+
+/// Cuts a path keeping as many trailing components as possible.
+fn cut_path<'a, M: WidthMetric>(text: &'a str, width: usize, metric: &M) -> Cow<'a, str> {
+    let stripped = strip_ansi(text);
+
+    let mut start = None;
+    for (i, c) in stripped.char_indices().rev() {
+        if c != '/' && c != '\\' {
+            continue;
+        }
+
+        if metric.line_width(&stripped[i..]) > width {
+            break;
+        }
+
+        start = Some(i);
     }
 
+    match start {
+        #[cfg(feature = "ansi")]
+        Some(start) => ansi_str::AnsiStr::ansi_cut(text, start..),
+        #[cfg(not(feature = "ansi"))]
+        Some(start) => Cow::Borrowed(&text[start..]),
+        None => cut_str_tail(text, width, metric),
+    }
+}
+
+/// Makes a suffix colored as the first or the last block of a text if `try_color` is set.
+fn make_suffix_colored<'a>(
+    _text: &str,
+    suffix: &'a str,
+    _try_color: bool,
+    _first: bool,
+) -> Cow<'a, str> {
     #[cfg(feature = "ansi")]
     {
-        if _suffix_color {
-            if let Some(block) = ansi_str::get_blocks(text).last() {
+        if _try_color {
+            let block = if _first {
+                ansi_str::get_blocks(_text).next()
+            } else {
+                ansi_str::get_blocks(_text).last()
+            };
+
+            if let Some(block) = block {
                 if block.has_ansi() {
                     let style = block.style();
-                    Cow::Owned(format!(
-                        "{}{}{}{}",
-                        content,
-                        style.start(),
-                        suffix,
-                        style.end()
-                    ))
-                } else {
-                    let mut content = content.into_owned();
-                    content.push_str(suffix);
-                    Cow::Owned(content)
+                    return Cow::Owned(format!("{}{}{}", style.start(), suffix, style.end()));
                 }
-            } else {
-                let mut content = content.into_owned();
-                content.push_str(suffix);
-                Cow::Owned(content)
             }
-        } else {
-            let mut content = content.into_owned();
-            content.push_str(suffix);
-            Cow::Owned(content)
         }
     }
 
-    #[cfg(not(feature = "ansi"))]
-    {
-        let mut content = content.into_owned();
-        content.push_str(suffix);
-        Cow::Owned(content)
-    }
+    Cow::Borrowed(suffix)
 }
 
 fn get_decrease_cell_list(
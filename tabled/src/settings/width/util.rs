@@ -1,23 +1,34 @@
 use crate::{
     grid::config::SpannedConfig,
-    grid::dimension::SpannedGridDimension,
+    grid::dimension::{Estimate, SpannedGridDimension},
     grid::records::{IntoRecords, Records},
+    grid::util::width::WidthMetric,
 };
 
-pub(crate) fn get_table_widths<R>(records: R, cfg: &SpannedConfig) -> Vec<usize>
+pub(crate) fn get_table_widths<R, M>(records: R, cfg: &SpannedConfig, metric: &M) -> Vec<usize>
 where
     R: Records,
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    M: WidthMetric,
 {
-    SpannedGridDimension::width(records, cfg)
+    let mut dims = SpannedGridDimension::with_metric(metric);
+    dims.estimate(records, cfg);
+
+    let (widths, _) = dims.get_values();
+    widths
 }
 
-pub(crate) fn get_table_widths_with_total<R>(records: R, cfg: &SpannedConfig) -> (Vec<usize>, usize)
+pub(crate) fn get_table_widths_with_total<R, M>(
+    records: R,
+    cfg: &SpannedConfig,
+    metric: &M,
+) -> (Vec<usize>, usize)
 where
     R: Records,
     <R::Iter as IntoRecords>::Cell: AsRef<str>,
+    M: WidthMetric,
 {
-    let widths = SpannedGridDimension::width(records, cfg);
+    let widths = get_table_widths(records, cfg, metric);
     let total_width = get_table_total_width(&widths, cfg);
     (widths, total_width)
 }
@@ -9,7 +9,10 @@ use crate::{
         config::{ColoredConfig, Entity},
         dimension::CompleteDimensionVecRecords,
         records::{EmptyRecords, ExactRecords, IntoRecords, PeekableRecords, Records, RecordsMut},
-        util::string::{get_char_width, get_text_width},
+        util::{
+            string::get_graphemes,
+            width::{UnicodeWidth, WidthMetric},
+        },
     },
     settings::{
         measurement::Measurement,
@@ -19,11 +22,8 @@ use crate::{
     },
 };
 
-#[cfg(not(feature = "ansi"))]
-use crate::grid::util::string::get_string_width;
-
 use super::util::{get_table_widths, get_table_widths_with_total};
-use crate::util::string::split_at_width;
+use crate::util::string::{replacement_char, split_at_width};
 
 /// Wrap wraps a string to a new line in case it exceeds the provided max boundary.
 /// Otherwise keeps the content of a cell untouched.
@@ -44,10 +44,27 @@ use crate::util::string::split_at_width;
 ///
 /// [`Padding`]: crate::settings::Padding
 #[derive(Debug, Clone)]
-pub struct Wrap<W = usize, P = PriorityNone> {
+pub struct Wrap<W = usize, P = PriorityNone, M = UnicodeWidth> {
     width: W,
     keep_words: bool,
+    rules: BreakRules,
     priority: P,
+    metric: M,
+}
+
+/// A set of rules which define where [`Wrap`] is allowed to break a line besides spaces.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+struct BreakRules {
+    separators: bool,
+    soft_hyphens: bool,
+    hyphenate: bool,
+    balance: bool,
+}
+
+impl BreakRules {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 impl<W> Wrap<W> {
@@ -59,12 +76,14 @@ impl<W> Wrap<W> {
         Wrap {
             width,
             keep_words: false,
+            rules: BreakRules::default(),
             priority: PriorityNone::new(),
+            metric: UnicodeWidth,
         }
     }
 }
 
-impl<W, P> Wrap<W, P> {
+impl<W, P, M> Wrap<W, P, M> {
     /// Priority defines the logic by which a truncate will be applied when is done for the whole table.
     ///
     /// - [`PriorityNone`] which cuts the columns one after another.
@@ -77,11 +96,13 @@ impl<W, P> Wrap<W, P> {
     /// [`Padding`]: crate::settings::Padding
     /// [`PriorityMax`]: crate::settings::peaker::PriorityMax
     /// [`PriorityMin`]: crate::settings::peaker::PriorityMin
-    pub fn priority<PP: Peaker>(self, priority: PP) -> Wrap<W, PP> {
+    pub fn priority<PP: Peaker>(self, priority: PP) -> Wrap<W, PP, M> {
         Wrap {
             width: self.width,
             keep_words: self.keep_words,
+            rules: self.rules,
             priority,
+            metric: self.metric,
         }
     }
 
@@ -93,19 +114,90 @@ impl<W, P> Wrap<W, P> {
         self.keep_words = on;
         self
     }
+
+    /// Allows to break a line after punctuation and path separators
+    /// (`/`, `\`, `::`, `-`, `_`, `.` and `,`) in addition to spaces.
+    ///
+    /// It implies [`Wrap::keep_words`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Rows, Width}};
+    ///
+    /// let mut table = Table::new(["src/settings/width/wrap.rs"]);
+    /// table.modify(Rows::new(1..), Width::wrap(12).break_at_separators(true));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------------+\n\
+    ///      | &str        |\n\
+    ///      +-------------+\n\
+    ///      | src/        |\n\
+    ///      | settings/   |\n\
+    ///      | width/wrap. |\n\
+    ///      | rs          |\n\
+    ///      +-------------+"
+    /// );
+    /// ```
+    pub fn break_at_separators(mut self, on: bool) -> Self {
+        self.rules.separators = on;
+        self
+    }
+
+    /// Makes soft hyphens (`U+00AD`) a possible break point.
+    ///
+    /// A soft hyphen is rendered as `-` when a line is broken at it and removed otherwise.
+    ///
+    /// It implies [`Wrap::keep_words`].
+    pub fn soft_hyphens(mut self, on: bool) -> Self {
+        self.rules.soft_hyphens = on;
+        self
+    }
+
+    /// Inserts a visible `-` when a word doesn't fit a line and has to be split.
+    ///
+    /// It implies [`Wrap::keep_words`].
+    pub fn hyphenate(mut self, on: bool) -> Self {
+        self.rules.hyphenate = on;
+        self
+    }
+
+    /// Chooses break points which make lines as even as possible (minimum raggedness),
+    /// instead of filling each line greedily.
+    ///
+    /// It implies [`Wrap::keep_words`].
+    pub fn balance(mut self, on: bool) -> Self {
+        self.rules.balance = on;
+        self
+    }
+
+    /// Sets a [`WidthMetric`] which is used to measure and split a content.
+    ///
+    /// By default [`UnicodeWidth`] is used.
+    pub fn metric<MM: WidthMetric>(self, metric: MM) -> Wrap<W, P, MM> {
+        Wrap {
+            width: self.width,
+            keep_words: self.keep_words,
+            rules: self.rules,
+            priority: self.priority,
+            metric,
+        }
+    }
 }
 
 impl Wrap<(), ()> {
     /// Wrap a given string
     pub fn wrap(text: &str, width: usize, keeping_words: bool) -> String {
-        wrap_text(text, width, keeping_words)
+        wrap_text(text, width, keeping_words, &UnicodeWidth)
     }
 }
 
-impl<W, P, R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>> for Wrap<W, P>
+impl<W, P, M, R> TableOption<R, ColoredConfig, CompleteDimensionVecRecords<'_>> for Wrap<W, P, M>
 where
     W: Measurement<Width>,
     P: Peaker,
+    M: WidthMetric,
     R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
@@ -121,22 +213,33 @@ where
         }
 
         let width = self.width.measure(&*records, cfg);
-        let (widths, total) = get_table_widths_with_total(&*records, cfg);
+        let (widths, total) = get_table_widths_with_total(&*records, cfg, &self.metric);
         if width >= total {
             return;
         }
 
         let priority = self.priority;
         let keep_words = self.keep_words;
-        let widths = wrap_total_width(records, cfg, widths, total, width, keep_words, priority);
+        let widths = wrap_total_width(
+            records,
+            cfg,
+            widths,
+            total,
+            width,
+            keep_words,
+            self.rules,
+            priority,
+            &self.metric,
+        );
 
         dims.set_widths(widths);
     }
 }
 
-impl<W, R> CellOption<R, ColoredConfig> for Wrap<W>
+impl<W, M, R> CellOption<R, ColoredConfig> for Wrap<W, PriorityNone, M>
 where
     W: Measurement<Width>,
+    M: WidthMetric,
     R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
@@ -154,63 +257,83 @@ where
             }
 
             let text = records.get_text(pos);
-            let cell_width = get_text_width(text);
+            let cell_width = self.metric.text_width(text);
             if cell_width <= width {
                 continue;
             }
 
-            let wrapped = wrap_text(text, width, self.keep_words);
+            let wrapped = if self.rules.is_empty() {
+                wrap_text(text, width, self.keep_words, &self.metric)
+            } else {
+                wrap_text_by_rules(text, width, self.rules, &self.metric)
+            };
+
             records.set(pos, wrapped);
         }
     }
 }
 
-fn wrap_total_width<R, P>(
+#[allow(clippy::too_many_arguments)]
+fn wrap_total_width<R, P, M>(
     records: &mut R,
     cfg: &mut ColoredConfig,
     mut widths: Vec<usize>,
     total_width: usize,
     width: usize,
     keep_words: bool,
+    rules: BreakRules,
     priority: P,
+    metric: &M,
 ) -> Vec<usize>
 where
     R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
     P: Peaker,
+    M: WidthMetric,
     for<'a> &'a R: Records,
     for<'a> <<&'a R as Records>::Iter as IntoRecords>::Cell: AsRef<str>,
 {
     let shape = (records.count_rows(), records.count_columns());
-    let min_widths = get_table_widths(EmptyRecords::from(shape), cfg);
+    let min_widths = get_table_widths(EmptyRecords::from(shape), cfg, metric);
 
     decrease_widths(&mut widths, &min_widths, total_width, width, priority);
 
     let points = get_decrease_cell_list(cfg, &widths, &min_widths, shape);
 
     for ((row, col), width) in points {
-        let mut wrap = Wrap::new(width);
-        wrap.keep_words = keep_words;
-        <Wrap as CellOption<_, _>>::change(wrap, records, cfg, (row, col).into());
+        let mut wrap = Wrap::new(width).keep_words(keep_words).metric(metric);
+        wrap.rules = rules;
+
+        CellOption::change(wrap, records, cfg, (row, col).into());
     }
 
     widths
 }
 
 #[cfg(not(feature = "ansi"))]
-pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
+pub(crate) fn wrap_text<M: WidthMetric>(
+    text: &str,
+    width: usize,
+    keep_words: bool,
+    metric: &M,
+) -> String {
     if width == 0 {
         return String::new();
     }
 
     if keep_words {
-        split_keeping_words(text, width, "\n")
+        split_keeping_words(text, width, "\n", metric)
     } else {
-        chunks(text, width).join("\n")
+        chunks(text, width, metric).join("\n")
     }
 }
 
 #[cfg(feature = "ansi")]
-pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
+pub(crate) fn wrap_text<M: WidthMetric>(
+    text: &str,
+    width: usize,
+    keep_words: bool,
+    metric: &M,
+) -> String {
     use crate::util::string::strip_osc;
 
     if width == 0 {
@@ -221,14 +344,73 @@ pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
     let (prefix, suffix) = build_link_prefix_suffix(url);
 
     if keep_words {
-        split_keeping_words(&text, width, &prefix, &suffix)
+        split_keeping_words(&text, width, &prefix, &suffix, metric)
     } else {
-        chunks(&text, width, &prefix, &suffix).join("\n")
+        chunks(&text, width, &prefix, &suffix, metric).join("\n")
+    }
+}
+
+fn wrap_text_by_rules<M: WidthMetric>(
+    text: &str,
+    width: usize,
+    rules: BreakRules,
+    metric: &M,
+) -> String {
+    if width == 0 {
+        return String::new();
+    }
+
+    #[cfg(not(feature = "ansi"))]
+    {
+        let lines = breaks::wrap(text, width, rules, metric);
+
+        let mut buf = String::with_capacity(text.len());
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                buf.push('\n');
+            }
+
+            breaks::render(&mut buf, line, |range| {
+                std::borrow::Cow::Borrowed(&text[range])
+            });
+        }
+
+        buf
+    }
+
+    #[cfg(feature = "ansi")]
+    {
+        use crate::util::string::strip_osc;
+
+        let (text, url): (String, Option<String>) = strip_osc(text);
+        let (prefix, suffix) = build_link_prefix_suffix(url);
+
+        let stripped = ansi_str::AnsiStr::ansi_strip(&text);
+        let lines = breaks::wrap(&stripped, width, rules, metric);
+
+        let mut buf = String::with_capacity(text.len());
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                buf.push('\n');
+            }
+
+            if line.is_empty() {
+                continue;
+            }
+
+            buf.push_str(&prefix);
+            breaks::render(&mut buf, line, |range| {
+                ansi_str::AnsiStr::ansi_cut(text.as_str(), range)
+            });
+            buf.push_str(&suffix);
+        }
+
+        buf
     }
 }
 
 #[cfg(feature = "ansi")]
-fn build_link_prefix_suffix(url: Option<String>) -> (String, String) {
+pub(super) fn build_link_prefix_suffix(url: Option<String>) -> (String, String) {
     match url {
         Some(url) => {
             // https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
@@ -242,8 +424,8 @@ fn build_link_prefix_suffix(url: Option<String>) -> (String, String) {
 }
 
 #[cfg(not(feature = "ansi"))]
-fn chunks(s: &str, width: usize) -> Vec<String> {
-    const REPLACEMENT: char = '\u{FFFD}';
+fn chunks<M: WidthMetric>(s: &str, width: usize, metric: &M) -> Vec<String> {
+    let replacement = replacement_char(metric);
 
     if width == 0 {
         return Vec::new();
@@ -253,8 +435,8 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
     let mut buf = String::with_capacity(width);
     let mut list = Vec::new();
     let mut i = 0;
-    for c in s.chars() {
-        if c == '\n' {
+    for grapheme in get_graphemes(s) {
+        if grapheme == "\n" || grapheme == "\r\n" {
             if buf.is_empty() {
                 list.push(String::new());
             } else {
@@ -267,14 +449,14 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
             continue;
         }
 
-        let c_width = get_char_width(c);
-        if i + c_width > width {
+        let g_width = metric.grapheme_width(grapheme);
+        if i + g_width > width {
             let count_unknowns = width - i;
-            buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+            buf.extend(std::iter::repeat(replacement).take(count_unknowns));
             i += count_unknowns;
         } else {
-            buf.push(c);
-            i += c_width;
+            buf.push_str(grapheme);
+            i += g_width;
         }
 
         if i == width {
@@ -293,7 +475,13 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
 }
 
 #[cfg(feature = "ansi")]
-fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
+fn chunks<M: WidthMetric>(
+    s: &str,
+    width: usize,
+    prefix: &str,
+    suffix: &str,
+    metric: &M,
+) -> Vec<String> {
     use std::fmt::Write;
 
     if width == 0 {
@@ -324,7 +512,7 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
         while !text_slice.is_empty() {
             let available_space = width - line_width;
 
-            let part_width = get_text_width(text_slice);
+            let part_width = metric.text_width(text_slice);
 
             if part_width <= available_space {
                 line.push_str(text_slice);
@@ -343,15 +531,16 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
                 break;
             }
 
-            let (lhs, rhs, (unknowns, split_char)) = split_string_at(text_slice, available_space);
+            let (lhs, rhs, (unknowns, split_char)) =
+                split_string_at(text_slice, available_space, metric);
 
             text_slice = &rhs[split_char..];
 
             line.push_str(lhs);
-            line_width += get_text_width(lhs);
+            line_width += metric.text_width(lhs);
 
-            const REPLACEMENT: char = '\u{FFFD}';
-            line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
+            let replacement = replacement_char(metric);
+            line.extend(std::iter::repeat(replacement).take(unknowns));
             line_width += unknowns;
 
             if line_width == width {
@@ -379,8 +568,8 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
 }
 
 #[cfg(not(feature = "ansi"))]
-fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
-    const REPLACEMENT: char = '\u{FFFD}';
+fn split_keeping_words<M: WidthMetric>(s: &str, width: usize, sep: &str, metric: &M) -> String {
+    let replacement = replacement_char(metric);
 
     let mut lines = Vec::new();
     let mut line = String::with_capacity(width);
@@ -402,7 +591,7 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             is_first_word = false;
         }
 
-        let word_width = get_string_width(word);
+        let word_width = metric.string_width(word);
 
         let line_has_space = line_width + word_width <= width;
         if line_has_space {
@@ -430,14 +619,14 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             while !word_part.is_empty() {
                 let available_space = width - line_width;
                 let (lhs, rhs, (unknowns, split_char)) =
-                    split_string_at(word_part, available_space);
+                    split_string_at(word_part, available_space, metric);
 
                 word_part = &rhs[split_char..];
-                line_width += get_string_width(lhs) + unknowns;
+                line_width += metric.string_width(lhs) + unknowns;
                 is_first_word = false;
 
                 line.push_str(lhs);
-                line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
+                line.extend(std::iter::repeat(replacement).take(unknowns));
 
                 if line_width == width {
                     lines.push(line);
@@ -458,7 +647,13 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
 }
 
 #[cfg(feature = "ansi")]
-fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) -> String {
+fn split_keeping_words<M: WidthMetric>(
+    text: &str,
+    width: usize,
+    prefix: &str,
+    suffix: &str,
+    metric: &M,
+) -> String {
     if text.is_empty() || width == 0 {
         return String::new();
     }
@@ -467,25 +662,26 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     let mut word_width = 0;
     let mut word_chars = 0;
     let mut blocks = parsing::Blocks::new(ansi_str::get_blocks(text));
-    let mut buf = parsing::MultilineBuffer::new(width);
+    let mut buf = parsing::MultilineBuffer::new(width, metric);
     buf.set_prefix(prefix);
     buf.set_suffix(suffix);
 
-    for c in stripped_text.chars() {
-        match c {
-            ' ' => {
-                parsing::handle_word(&mut buf, &mut blocks, word_chars, word_width, 1);
+    for grapheme in get_graphemes(&stripped_text) {
+        match grapheme {
+            " " => {
+                parsing::handle_word(&mut buf, &mut blocks, word_chars, word_width, 0);
+                parsing::handle_space(&mut buf, &mut blocks);
                 word_chars = 0;
                 word_width = 0;
             }
-            '\n' => {
+            "\n" => {
                 parsing::handle_word(&mut buf, &mut blocks, word_chars, word_width, 1);
                 word_chars = 0;
                 word_width = 0;
             }
             _ => {
-                word_width += get_char_width(c);
-                word_chars += 1;
+                word_width += metric.grapheme_width(grapheme);
+                word_chars += grapheme.chars().count();
             }
         }
     }
@@ -498,9 +694,422 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     buf.into_string()
 }
 
+mod breaks {
+    use std::{borrow::Cow, ops::Range};
+
+    use super::{get_graphemes, replacement_char, BreakRules, WidthMetric};
+
+    const SOFT_HYPHEN: &str = "\u{AD}";
+
+    /// A part of a wrapped line.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub(super) enum Piece {
+        /// A range of an original text.
+        Text(Range<usize>),
+        /// A char repeated a given number of times.
+        Fill(char, usize),
+    }
+
+    /// What stays between 2 fragments.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Glue {
+        /// Nothing is rendered whether a line is broken or not.
+        None,
+        /// A space (its position) which is dropped if a line is broken at it.
+        Space(usize),
+        /// Nothing is rendered unless a line is broken at it, in which case it's `-`.
+        Hyphen,
+    }
+
+    /// A piece of text which can't be broken (unless it's too long).
+    #[derive(Debug, Clone)]
+    struct Fragment {
+        piece: Piece,
+        width: usize,
+        glue: Glue,
+    }
+
+    /// Wraps a text, returning a list of lines.
+    pub(super) fn wrap<M: WidthMetric>(
+        text: &str,
+        width: usize,
+        rules: BreakRules,
+        metric: &M,
+    ) -> Vec<Vec<Piece>> {
+        let mut lines = Vec::new();
+
+        let mut base = 0;
+        for line in text.split('\n') {
+            let content = line.strip_suffix('\r').unwrap_or(line);
+
+            let fragments = split_fragments(content, base, width, rules, metric);
+            let ends = if rules.balance {
+                break_balanced(&fragments, width)
+            } else {
+                break_greedy(&fragments, width)
+            };
+
+            let mut start = 0;
+            for end in ends {
+                lines.push(build_line(&fragments, start, end, width));
+                start = end;
+            }
+
+            base += line.len() + 1;
+        }
+
+        lines
+    }
+
+    /// Renders a line into a buffer.
+    pub(super) fn render<'a, F>(buf: &mut String, line: &[Piece], get_text: F)
+    where
+        F: Fn(Range<usize>) -> Cow<'a, str>,
+    {
+        for piece in line {
+            match piece {
+                Piece::Text(range) => buf.push_str(&get_text(range.clone())),
+                Piece::Fill(c, n) => (0..*n).for_each(|_| buf.push(*c)),
+            }
+        }
+    }
+
+    fn split_fragments<M: WidthMetric>(
+        line: &str,
+        base: usize,
+        width: usize,
+        rules: BreakRules,
+        metric: &M,
+    ) -> Vec<Fragment> {
+        let mut list = Vec::new();
+
+        let mut start = 0;
+        let mut pos = 0;
+        let mut fragment_width = 0;
+        let mut has_word = false;
+        let mut is_separated = false;
+        let mut prev = "";
+
+        for grapheme in get_graphemes(line) {
+            let at = pos;
+            pos += grapheme.len();
+
+            let glue = if grapheme == " " {
+                Some(Glue::Space(base + at))
+            } else if rules.soft_hyphens && grapheme == SOFT_HYPHEN {
+                Some(Glue::Hyphen)
+            } else {
+                None
+            };
+
+            if let Some(glue) = glue {
+                let range = start..at;
+                push_fragment(
+                    &mut list,
+                    line,
+                    base,
+                    range,
+                    fragment_width,
+                    glue,
+                    width,
+                    rules,
+                    metric,
+                );
+
+                start = pos;
+                fragment_width = 0;
+                has_word = false;
+                is_separated = false;
+                prev = grapheme;
+                continue;
+            }
+
+            let is_separator = rules.separators && is_separator(grapheme, prev);
+            if is_separated && !is_separator {
+                let range = start..at;
+                push_fragment(
+                    &mut list,
+                    line,
+                    base,
+                    range,
+                    fragment_width,
+                    Glue::None,
+                    width,
+                    rules,
+                    metric,
+                );
+
+                start = at;
+                fragment_width = 0;
+                has_word = false;
+                is_separated = false;
+            }
+
+            if is_separator {
+                is_separated = has_word;
+            } else {
+                has_word = true;
+            }
+
+            fragment_width += metric.grapheme_width(grapheme);
+            prev = grapheme;
+        }
+
+        let range = start..pos;
+        push_fragment(
+            &mut list,
+            line,
+            base,
+            range,
+            fragment_width,
+            Glue::None,
+            width,
+            rules,
+            metric,
+        );
+
+        list
+    }
+
+    fn is_separator(grapheme: &str, prev: &str) -> bool {
+        match grapheme {
+            "/" | "\\" | "-" | "_" | "." | "," => true,
+            ":" => prev == ":",
+            _ => false,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn push_fragment<M: WidthMetric>(
+        list: &mut Vec<Fragment>,
+        line: &str,
+        base: usize,
+        range: Range<usize>,
+        fragment_width: usize,
+        glue: Glue,
+        width: usize,
+        rules: BreakRules,
+        metric: &M,
+    ) {
+        if fragment_width <= width {
+            let piece = Piece::Text(base + range.start..base + range.end);
+            list.push(Fragment::new(piece, fragment_width, glue));
+            return;
+        }
+
+        // the fragment is too long any way so we split it
+
+        let capacity = if rules.hyphenate && width > 1 {
+            width - 1
+        } else {
+            width
+        };
+
+        let split_glue = if capacity < width {
+            Glue::Hyphen
+        } else {
+            Glue::None
+        };
+
+        let mut start = range.start;
+        let mut pos = range.start;
+        let mut part_width = 0;
+        for grapheme in get_graphemes(&line[range]) {
+            let grapheme_width = metric.grapheme_width(grapheme);
+            if part_width + grapheme_width <= capacity {
+                pos += grapheme.len();
+                part_width += grapheme_width;
+                continue;
+            }
+
+            if part_width > 0 {
+                let piece = Piece::Text(base + start..base + pos);
+                list.push(Fragment::new(piece, part_width, split_glue));
+                start = pos;
+                part_width = 0;
+            }
+
+            pos += grapheme.len();
+
+            if grapheme_width <= capacity {
+                part_width = grapheme_width;
+            } else if grapheme_width <= width {
+                let piece = Piece::Text(base + start..base + pos);
+                list.push(Fragment::new(piece, grapheme_width, Glue::None));
+                start = pos;
+            } else {
+                let piece = Piece::Fill(replacement_char(metric), width);
+                list.push(Fragment::new(piece, width, Glue::None));
+                start = pos;
+            }
+        }
+
+        if part_width == 0 && start == pos {
+            if let Some(last) = list.last_mut() {
+                last.glue = glue;
+            }
+
+            return;
+        }
+
+        let piece = Piece::Text(base + start..base + pos);
+        list.push(Fragment::new(piece, part_width, glue));
+    }
+
+    /// Fills each line with as many fragments as possible.
+    fn break_greedy(fragments: &[Fragment], width: usize) -> Vec<usize> {
+        let mut ends = Vec::new();
+
+        let mut start = 0;
+        while start < fragments.len() {
+            let mut end = start + 1;
+            let mut line_width = 0;
+            for i in start..fragments.len() {
+                if i > start {
+                    line_width += fragments[i - 1].glue_width();
+                }
+
+                line_width += fragments[i].width;
+                if line_width > width {
+                    break;
+                }
+
+                if line_width + break_width(fragments, i) <= width {
+                    end = i + 1;
+                }
+            }
+
+            ends.push(end);
+            start = end;
+        }
+
+        ends
+    }
+
+    /// Chooses break points minimizing a sum of squares of a free space on each line,
+    /// except the last one.
+    fn break_balanced(fragments: &[Fragment], width: usize) -> Vec<usize> {
+        let count = fragments.len();
+
+        let mut costs = vec![usize::MAX; count + 1];
+        let mut starts = vec![0; count + 1];
+        costs[0] = 0;
+
+        for start in 0..count {
+            if costs[start] == usize::MAX {
+                continue;
+            }
+
+            let mut line_width = 0;
+            for i in start..count {
+                if i > start {
+                    line_width += fragments[i - 1].glue_width();
+                }
+
+                line_width += fragments[i].width;
+                if line_width > width && i > start {
+                    break;
+                }
+
+                let total_width = line_width + break_width(fragments, i);
+                if total_width > width && i > start {
+                    continue;
+                }
+
+                let cost = if i + 1 == count {
+                    0
+                } else {
+                    let space = width.saturating_sub(total_width);
+                    space * space
+                };
+
+                let cost = costs[start].saturating_add(cost);
+                if cost < costs[i + 1] {
+                    costs[i + 1] = cost;
+                    starts[i + 1] = start;
+                }
+            }
+        }
+
+        let mut ends = Vec::new();
+        let mut end = count;
+        while end > 0 {
+            ends.push(end);
+            end = starts[end];
+        }
+
+        ends.reverse();
+        ends
+    }
+
+    fn break_width(fragments: &[Fragment], i: usize) -> usize {
+        let is_last = i + 1 == fragments.len();
+        if !is_last && fragments[i].glue == Glue::Hyphen {
+            1
+        } else {
+            0
+        }
+    }
+
+    fn build_line(fragments: &[Fragment], start: usize, end: usize, width: usize) -> Vec<Piece> {
+        let mut line = Vec::new();
+        let mut line_width = 0;
+
+        for (i, fragment) in fragments.iter().enumerate().take(end).skip(start) {
+            push_piece(&mut line, fragment.piece.clone());
+            line_width += fragment.width;
+
+            if i + 1 < end {
+                if let Glue::Space(at) = fragment.glue {
+                    push_piece(&mut line, Piece::Text(at..at + 1));
+                    line_width += 1;
+                }
+            }
+        }
+
+        let has_hyphen = line_width + break_width(fragments, end - 1) <= width;
+        if break_width(fragments, end - 1) > 0 && has_hyphen {
+            line.push(Piece::Fill('-', 1));
+        }
+
+        line
+    }
+
+    fn push_piece(line: &mut Vec<Piece>, piece: Piece) {
+        if let Piece::Text(range) = &piece {
+            if range.is_empty() {
+                return;
+            }
+
+            if let Some(Piece::Text(last)) = line.last_mut() {
+                if last.end == range.start {
+                    last.end = range.end;
+                    return;
+                }
+            }
+        }
+
+        line.push(piece);
+    }
+
+    impl Fragment {
+        fn new(piece: Piece, width: usize, glue: Glue) -> Self {
+            Self { piece, width, glue }
+        }
+
+        fn glue_width(&self) -> usize {
+            match self.glue {
+                Glue::Space(_) => 1,
+                Glue::None | Glue::Hyphen => 0,
+            }
+        }
+    }
+}
+
 #[cfg(feature = "ansi")]
 mod parsing {
-    use super::get_char_width;
+    use super::{get_graphemes, replacement_char, WidthMetric};
+    use crate::grid::util::string::get_char_width;
     use ansi_str::{AnsiBlock, AnsiBlockIter, Style};
     use std::fmt::Write;
 
@@ -547,22 +1156,24 @@ mod parsing {
         }
     }
 
-    pub(super) struct MultilineBuffer<'a> {
+    pub(super) struct MultilineBuffer<'a, M> {
         buf: String,
         width_last: usize,
         width: usize,
         prefix: &'a str,
         suffix: &'a str,
+        metric: &'a M,
     }
 
-    impl<'a> MultilineBuffer<'a> {
-        pub(super) fn new(width: usize) -> Self {
+    impl<'a, M: WidthMetric> MultilineBuffer<'a, M> {
+        pub(super) fn new(width: usize, metric: &'a M) -> Self {
             Self {
                 buf: String::new(),
                 width_last: 0,
                 prefix: "",
                 suffix: "",
                 width,
+                metric,
             }
         }
 
@@ -635,15 +1246,15 @@ mod parsing {
         pub(super) fn read_chars(&mut self, block: &RelativeBlock<'_>, n: usize) -> (usize, usize) {
             let mut count_chars = 0;
             let mut count_bytes = 0;
-            for c in block.get_text().chars() {
-                if count_chars == n {
+            for grapheme in get_graphemes(block.get_text()) {
+                if count_chars >= n {
                     break;
                 }
 
-                count_chars += 1;
-                count_bytes += c.len_utf8();
+                count_chars += grapheme.chars().count();
+                count_bytes += grapheme.len();
 
-                let cwidth = get_char_width(c);
+                let cwidth = self.metric.grapheme_width(grapheme);
 
                 let available_space = self.width - self.width_last;
                 if available_space == 0 {
@@ -664,11 +1275,11 @@ mod parsing {
                     // thereatically a cwidth can be 2 but buf_width is 1
                     // but it handled here too;
 
-                    const REPLACEMENT: char = '\u{FFFD}';
-                    let _ = self.fill(REPLACEMENT);
+                    let replacement = replacement_char(self.metric);
+                    let _ = self.fill(replacement);
                     self.width_last = self.width;
                 } else {
-                    self.buf.push(c);
+                    self.buf.push_str(grapheme);
                     self.width_last += cwidth;
                 }
             }
@@ -683,18 +1294,18 @@ mod parsing {
         ) -> (usize, usize) {
             let mut count_chars = 0;
             let mut count_bytes = 0;
-            for c in block.get_text().chars() {
-                if count_chars == n {
+            for grapheme in get_graphemes(block.get_text()) {
+                if count_chars >= n {
                     break;
                 }
 
-                count_chars += 1;
-                count_bytes += c.len_utf8();
+                count_chars += grapheme.chars().count();
+                count_bytes += grapheme.len();
 
-                let cwidth = get_char_width(c);
+                let cwidth = self.metric.grapheme_width(grapheme);
                 self.width_last += cwidth;
 
-                self.buf.push(c);
+                self.buf.push_str(grapheme);
             }
 
             debug_assert!(self.width_last <= self.width);
@@ -703,7 +1314,11 @@ mod parsing {
         }
     }
 
-    pub(super) fn read_chars(buf: &mut MultilineBuffer<'_>, blocks: &mut Blocks<'_>, n: usize) {
+    pub(super) fn read_chars<M: WidthMetric>(
+        buf: &mut MultilineBuffer<'_, M>,
+        blocks: &mut Blocks<'_>,
+        n: usize,
+    ) {
         let mut n = n;
         while n > 0 {
             let is_new_block = blocks.current.is_none();
@@ -730,8 +1345,8 @@ mod parsing {
         }
     }
 
-    pub(super) fn read_chars_unchecked(
-        buf: &mut MultilineBuffer<'_>,
+    pub(super) fn read_chars_unchecked<M: WidthMetric>(
+        buf: &mut MultilineBuffer<'_, M>,
         blocks: &mut Blocks<'_>,
         n: usize,
     ) {
@@ -762,8 +1377,67 @@ mod parsing {
         }
     }
 
-    pub(super) fn handle_word(
-        buf: &mut MultilineBuffer<'_>,
+    /// Skips `n` chars of blocks, so they're not written into a buffer.
+    pub(super) fn skip_chars<M: WidthMetric>(
+        buf: &mut MultilineBuffer<'_, M>,
+        blocks: &mut Blocks<'_>,
+        n: usize,
+    ) {
+        let mut n = n;
+        while n > 0 {
+            let is_new_block = blocks.current.is_none();
+            let mut block = blocks.next_block().expect("Must never happen");
+
+            let mut skip_count = 0;
+            let mut skip_bytes = 0;
+            for grapheme in get_graphemes(block.get_text()) {
+                if skip_count >= n {
+                    break;
+                }
+
+                skip_count += grapheme.chars().count();
+                skip_bytes += grapheme.len();
+            }
+
+            if block.pos + skip_bytes == block.get_origin().len() {
+                if !is_new_block {
+                    let _ = buf
+                        .buf
+                        .write_fmt(format_args!("{}", block.get_style().end()));
+                }
+            } else {
+                if is_new_block {
+                    buf.buf.push_str(buf.prefix);
+                    let _ = buf
+                        .buf
+                        .write_fmt(format_args!("{}", block.get_style().start()));
+                }
+
+                block.pos += skip_bytes;
+                blocks.current = Some(block);
+            }
+
+            n -= skip_count;
+        }
+    }
+
+    /// Reads a space which separates words.
+    ///
+    /// A space which doesn't fit a line is dropped,
+    /// so it doesn't appear at the start of the next line.
+    pub(super) fn handle_space<M: WidthMetric>(
+        buf: &mut MultilineBuffer<'_, M>,
+        blocks: &mut Blocks<'_>,
+    ) {
+        if buf.available_width() > 0 {
+            read_chars_unchecked(buf, blocks, 1);
+        } else {
+            skip_chars(buf, blocks, 1);
+        }
+    }
+
+    pub(super) fn handle_word<M: WidthMetric>(
+        buf: &mut MultilineBuffer<'_, M>,
         blocks: &mut Blocks<'_>,
         word_chars: usize,
         word_width: usize,
@@ -801,8 +1475,12 @@ mod parsing {
     }
 }
 
-fn split_string_at(text: &str, at: usize) -> (&str, &str, (usize, usize)) {
-    let (length, width, split_char_size) = split_at_width(text, at);
+fn split_string_at<'a, M: WidthMetric>(
+    text: &'a str,
+    at: usize,
+    metric: &M,
+) -> (&'a str, &'a str, (usize, usize)) {
+    let (length, width, split_char_size) = split_at_width(text, at, metric);
     let count_unknowns = if split_char_size > 0 { at - width } else { 0 };
     let (lhs, rhs) = text.split_at(length);
 
@@ -893,6 +1571,26 @@ fn count_borders(cfg: &SpannedConfig, start: usize, end: usize, count_columns: u
 mod tests {
     use super::*;
 
+    #[cfg(not(feature = "ansi"))]
+    fn chunks(s: &str, width: usize) -> Vec<String> {
+        super::chunks(s, width, &UnicodeWidth)
+    }
+
+    #[cfg(feature = "ansi")]
+    fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
+        super::chunks(s, width, prefix, suffix, &UnicodeWidth)
+    }
+
+    #[cfg(not(feature = "ansi"))]
+    fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
+        super::split_keeping_words(s, width, sep, &UnicodeWidth)
+    }
+
+    #[cfg(feature = "ansi")]
+    fn split_keeping_words(s: &str, width: usize, prefix: &str, suffix: &str) -> String {
+        super::split_keeping_words(s, width, prefix, suffix, &UnicodeWidth)
+    }
+
     #[test]
     fn split_test() {
         #[cfg(not(feature = "ansi"))]
@@ -951,6 +1649,8 @@ mod tests {
         assert_eq!(split_keeping_words("😳😳😳😳😳", 1), "�\n�\n�\n�\n�");
 
         assert_eq!(split_keeping_words("111 234 1", 4), "111 \n234 \n1   ");
+        assert_eq!(split_keeping_words("1111 234", 4), "1111\n234 ");
+        assert_eq!(split_keeping_words("  11 2345", 4), "  11\n2345");
     }
 
     #[cfg(feature = "ansi")]
@@ -966,6 +1666,8 @@ mod tests {
         assert_eq!(split_keeping_words("😳😳😳😳😳", 1), "�\n�\n�\n�\n�");
 
         assert_eq!(split_keeping_words("111 234 1", 4), "111 \n234 \n1   ");
+        assert_eq!(split_keeping_words("1111 234", 4), "1111\n234 ");
+        assert_eq!(split_keeping_words("  11 2345", 4), "  11\n2345");
     }
 
     #[cfg(feature = "ansi")]
@@ -979,8 +1681,8 @@ mod tests {
 
         let text = "\u{1b}[36mJapanese “vacancy” button\u{1b}[0m";
 
-        assert_eq!(split_keeping_words(text, 2), "\u{1b}[36mJa\u{1b}[39m\n\u{1b}[36mpa\u{1b}[39m\n\u{1b}[36mne\u{1b}[39m\n\u{1b}[36mse\u{1b}[39m\n\u{1b}[36m “\u{1b}[39m\n\u{1b}[36mva\u{1b}[39m\n\u{1b}[36mca\u{1b}[39m\n\u{1b}[36mnc\u{1b}[39m\n\u{1b}[36my”\u{1b}[39m\n\u{1b}[36m b\u{1b}[39m\n\u{1b}[36mut\u{1b}[39m\n\u{1b}[36mto\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m ");
-        assert_eq!(split_keeping_words(text, 1), "\u{1b}[36mJ\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mp\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36ms\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36m \u{1b}[39m\n\u{1b}[36m“\u{1b}[39m\n\u{1b}[36mv\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36my\u{1b}[39m\n\u{1b}[36m”\u{1b}[39m\n\u{1b}[36m \u{1b}[39m\n\u{1b}[36mb\u{1b}[39m\n\u{1b}[36mu\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mo\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m");
+        assert_eq!(split_keeping_words(text, 2), "\u{1b}[36mJa\u{1b}[39m\n\u{1b}[36mpa\u{1b}[39m\n\u{1b}[36mne\u{1b}[39m\n\u{1b}[36mse\u{1b}[39m\n\u{1b}[36m“v\u{1b}[39m\n\u{1b}[36mac\u{1b}[39m\n\u{1b}[36man\u{1b}[39m\n\u{1b}[36mcy\u{1b}[39m\n\u{1b}[36m” \u{1b}[39m\n\u{1b}[36mbu\u{1b}[39m\n\u{1b}[36mtt\u{1b}[39m\n\u{1b}[36mon\u{1b}[39m");
+        assert_eq!(split_keeping_words(text, 1), "\u{1b}[36mJ\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mp\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36ms\u{1b}[39m\n\u{1b}[36me\u{1b}[39m\n\u{1b}[36m“\u{1b}[39m\n\u{1b}[36mv\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36ma\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m\n\u{1b}[36mc\u{1b}[39m\n\u{1b}[36my\u{1b}[39m\n\u{1b}[36m”\u{1b}[39m\n\u{1b}[36mb\u{1b}[39m\n\u{1b}[36mu\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mt\u{1b}[39m\n\u{1b}[36mo\u{1b}[39m\n\u{1b}[36mn\u{1b}[39m");
     }
 
     #[cfg(feature = "ansi")]
@@ -1008,23 +1710,16 @@ mod tests {
                 "\u{1b}[37mua\u{1b}[39m",
                 "\u{1b}[37mdo\u{1b}[39m",
                 "\u{1b}[37mr \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37mOM\u{1b}[39m",
                 "\u{1b}[37mYA\u{1b}[39m",
-                "\u{1b}[37m A\u{1b}[39m",
-                "\u{1b}[37mnd\u{1b}[39m",
-                "\u{1b}[37min\u{1b}[39m",
-                "\u{1b}[37ma \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
+                "\u{1b}[37mAn\u{1b}[39m",
+                "\u{1b}[37mdi\u{1b}[39m",
+                "\u{1b}[37mna\u{1b}[39m",
                 "\u{1b}[37m38\u{1b}[39m",
                 "\u{1b}[37m24\u{1b}[39m",
                 "\u{1b}[37m90\u{1b}[39m",
                 "\u{1b}[37m99\u{1b}[39m",
                 "\u{1b}[37m99\u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37mCa\u{1b}[39m",
                 "\u{1b}[37mlc\u{1b}[39m",
                 "\u{1b}[37miu\u{1b}[39m",
@@ -1034,9 +1729,6 @@ mod tests {
                 "\u{1b}[37mon\u{1b}[39m",
                 "\u{1b}[37mat\u{1b}[39m",
                 "\u{1b}[37me \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
-                "\u{1b}[37m  \u{1b}[39m",
                 "\u{1b}[37mCo\u{1b}[39m",
                 "\u{1b}[37mlo\u{1b}[39m",
                 "\u{1b}[37mmb\u{1b}[39m",
@@ -1054,7 +1746,6 @@ mod tests {
                 "\u{1b}[37mg\u{1b}[39m",
                 "\u{1b}[37mr\u{1b}[39m",
                 "\u{1b}[37me\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mE\u{1b}[39m",
                 "\u{1b}[37mc\u{1b}[39m",
                 "\u{1b}[37mu\u{1b}[39m",
@@ -1062,25 +1753,16 @@ mod tests {
                 "\u{1b}[37md\u{1b}[39m",
                 "\u{1b}[37mo\u{1b}[39m",
                 "\u{1b}[37mr\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mO\u{1b}[39m",
                 "\u{1b}[37mM\u{1b}[39m",
                 "\u{1b}[37mY\u{1b}[39m",
                 "\u{1b}[37mA\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mA\u{1b}[39m",
                 "\u{1b}[37mn\u{1b}[39m",
                 "\u{1b}[37md\u{1b}[39m",
                 "\u{1b}[37mi\u{1b}[39m",
                 "\u{1b}[37mn\u{1b}[39m",
                 "\u{1b}[37ma\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37m3\u{1b}[39m",
                 "\u{1b}[37m8\u{1b}[39m",
                 "\u{1b}[37m2\u{1b}[39m",
@@ -1091,12 +1773,6 @@ mod tests {
                 "\u{1b}[37m9\u{1b}[39m",
                 "\u{1b}[37m9\u{1b}[39m",
                 "\u{1b}[37m9\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mC\u{1b}[39m",
                 "\u{1b}[37ma\u{1b}[39m",
                 "\u{1b}[37ml\u{1b}[39m",
@@ -1104,7 +1780,6 @@ mod tests {
                 "\u{1b}[37mi\u{1b}[39m",
                 "\u{1b}[37mu\u{1b}[39m",
                 "\u{1b}[37mm\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mc\u{1b}[39m",
                 "\u{1b}[37ma\u{1b}[39m",
                 "\u{1b}[37mr\u{1b}[39m",
@@ -1114,13 +1789,6 @@ mod tests {
                 "\u{1b}[37ma\u{1b}[39m",
                 "\u{1b}[37mt\u{1b}[39m",
                 "\u{1b}[37me\u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
-                "\u{1b}[37m \u{1b}[39m",
                 "\u{1b}[37mC\u{1b}[39m",
                 "\u{1b}[37mo\u{1b}[39m",
                 "\u{1b}[37ml\u{1b}[39m",
@@ -1137,6 +1805,15 @@ mod tests {
     #[test]
     fn split_by_line_keeping_words_color_3_test() {
         let split = |text, width| split_keeping_words(text, width, "", "");
+        #[cfg(feature = "grapheme")]
+        assert_eq!(
+            split(
+                "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
+                3,
+            ),
+            "\u{1b}[37m🚵🏻�\u{1b}[39m\n\u{1b}[37m🚵🏻�\u{1b}[39m\n\u{1b}[37m🚵🏻�\u{1b}[39m\n\u{1b}[37m🚵🏻�\u{1b}[39m\n\u{1b}[37m🚵🏻�\u{1b}[39m",
+        );
+        #[cfg(not(feature = "grapheme"))]
         assert_eq!(
             split(
                 "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
@@ -1146,7 +1823,7 @@ mod tests {
         );
         assert_eq!(
             split("\u{1b}[37mthis is a long sentence\u{1b}[0m", 7),
-            "\u{1b}[37mthis is\u{1b}[39m\n\u{1b}[37m a long\u{1b}[39m\n\u{1b}[37m senten\u{1b}[39m\n\u{1b}[37mce\u{1b}[39m     "
+            "\u{1b}[37mthis is\u{1b}[39m\n\u{1b}[37ma long \u{1b}[39m\n\u{1b}[37msentenc\u{1b}[39m\n\u{1b}[37me\u{1b}[39m      "
         );
         assert_eq!(
             split("\u{1b}[37mHello World\u{1b}[0m", 7),
@@ -1250,23 +1927,16 @@ mod tests {
                 "^\u{1b}[37mua\u{1b}[39m$",
                 "^\u{1b}[37mdo\u{1b}[39m$",
                 "^\u{1b}[37mr \u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
                 "^\u{1b}[37mOM\u{1b}[39m$",
                 "^\u{1b}[37mYA\u{1b}[39m$",
-                "^\u{1b}[37m A\u{1b}[39m$",
-                "^\u{1b}[37mnd\u{1b}[39m$",
-                "^\u{1b}[37min\u{1b}[39m$",
-                "^\u{1b}[37ma \u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
+                "^\u{1b}[37mAn\u{1b}[39m$",
+                "^\u{1b}[37mdi\u{1b}[39m$",
+                "^\u{1b}[37mna\u{1b}[39m$",
                 "^\u{1b}[37m38\u{1b}[39m$",
                 "^\u{1b}[37m24\u{1b}[39m$",
                 "^\u{1b}[37m90\u{1b}[39m$",
                 "^\u{1b}[37m99\u{1b}[39m$",
                 "^\u{1b}[37m99\u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
                 "^\u{1b}[37mCa\u{1b}[39m$",
                 "^\u{1b}[37mlc\u{1b}[39m$",
                 "^\u{1b}[37miu\u{1b}[39m$",
@@ -1276,9 +1946,6 @@ mod tests {
                 "^\u{1b}[37mon\u{1b}[39m$",
                 "^\u{1b}[37mat\u{1b}[39m$",
                 "^\u{1b}[37me \u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
-                "^\u{1b}[37m  \u{1b}[39m$",
                 "^\u{1b}[37mCo\u{1b}[39m$",
                 "^\u{1b}[37mlo\u{1b}[39m$",
                 "^\u{1b}[37mmb\u{1b}[39m$",
@@ -1296,7 +1963,6 @@ mod tests {
                 "^\u{1b}[37mg\u{1b}[39m$",
                 "^\u{1b}[37mr\u{1b}[39m$",
                 "^\u{1b}[37me\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mE\u{1b}[39m$",
                 "^\u{1b}[37mc\u{1b}[39m$",
                 "^\u{1b}[37mu\u{1b}[39m$",
@@ -1304,25 +1970,16 @@ mod tests {
                 "^\u{1b}[37md\u{1b}[39m$",
                 "^\u{1b}[37mo\u{1b}[39m$",
                 "^\u{1b}[37mr\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mO\u{1b}[39m$",
                 "^\u{1b}[37mM\u{1b}[39m$",
                 "^\u{1b}[37mY\u{1b}[39m$",
                 "^\u{1b}[37mA\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mA\u{1b}[39m$",
                 "^\u{1b}[37mn\u{1b}[39m$",
                 "^\u{1b}[37md\u{1b}[39m$",
                 "^\u{1b}[37mi\u{1b}[39m$",
                 "^\u{1b}[37mn\u{1b}[39m$",
                 "^\u{1b}[37ma\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37m3\u{1b}[39m$",
                 "^\u{1b}[37m8\u{1b}[39m$",
                 "^\u{1b}[37m2\u{1b}[39m$",
@@ -1333,12 +1990,6 @@ mod tests {
                 "^\u{1b}[37m9\u{1b}[39m$",
                 "^\u{1b}[37m9\u{1b}[39m$",
                 "^\u{1b}[37m9\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mC\u{1b}[39m$",
                 "^\u{1b}[37ma\u{1b}[39m$",
                 "^\u{1b}[37ml\u{1b}[39m$",
@@ -1346,7 +1997,6 @@ mod tests {
                 "^\u{1b}[37mi\u{1b}[39m$",
                 "^\u{1b}[37mu\u{1b}[39m$",
                 "^\u{1b}[37mm\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mc\u{1b}[39m$",
                 "^\u{1b}[37ma\u{1b}[39m$",
                 "^\u{1b}[37mr\u{1b}[39m$",
@@ -1356,13 +2006,6 @@ mod tests {
                 "^\u{1b}[37ma\u{1b}[39m$",
                 "^\u{1b}[37mt\u{1b}[39m$",
                 "^\u{1b}[37me\u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
-                "^\u{1b}[37m \u{1b}[39m$",
                 "^\u{1b}[37mC\u{1b}[39m$",
                 "^\u{1b}[37mo\u{1b}[39m$",
                 "^\u{1b}[37ml\u{1b}[39m$",
@@ -1455,4 +2098,64 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn wrap_by_rules_test() {
+        let rules = |separators, soft_hyphens, hyphenate, balance| BreakRules {
+            separators,
+            soft_hyphens,
+            hyphenate,
+            balance,
+        };
+
+        let wrap = |text, width, rules| wrap_text_by_rules(text, width, rules, &UnicodeWidth);
+
+        let separators = rules(true, false, false, false);
+        assert_eq!(wrap("a/b/c", 0, separators), "");
+        assert_eq!(wrap("/usr/local/bin", 7, separators), "/usr/\nlocal/\nbin");
+        assert_eq!(
+            wrap("std::fmt::Display", 9, separators),
+            "std::\nfmt::\nDisplay"
+        );
+        assert_eq!(
+            wrap("std::fmt::Display", 10, separators),
+            "std::fmt::\nDisplay"
+        );
+        assert_eq!(
+            wrap("snake_case_name", 8, separators),
+            "snake_\ncase_\nname"
+        );
+        assert_eq!(wrap("a, b, c", 3, separators), "a,\nb,\nc");
+        assert_eq!(wrap("--flag-name", 7, separators), "--flag-\nname");
+
+        let soft_hyphens = rules(false, true, false, false);
+        assert_eq!(
+            wrap("hy\u{AD}phen\u{AD}ation", 8, soft_hyphens),
+            "hyphen-\nation"
+        );
+        assert_eq!(
+            wrap("hy\u{AD}phen\u{AD}ation", 20, soft_hyphens),
+            "hyphenation"
+        );
+        assert_eq!(wrap("hy\u{AD}phen", 2, soft_hyphens), "hy\nph\nen");
+
+        let hyphenate = rules(false, false, true, false);
+        assert_eq!(wrap("abcdefgh", 4, hyphenate), "abc-\ndef-\ngh");
+        assert_eq!(wrap("ab abcdefgh", 5, hyphenate), "ab\nabcd-\nefgh");
+        assert_eq!(wrap("abc", 1, hyphenate), "a\nb\nc");
+        assert_eq!(wrap("😳😳😳", 3, hyphenate), "😳-\n😳-\n😳");
+        assert_eq!(wrap("😳😳", 1, hyphenate), "�\n�");
+
+        let balance = rules(false, false, false, true);
+        assert_eq!(wrap("aaa bb cc dd", 6, balance), "aaa bb\ncc dd");
+        assert_eq!(wrap("aaa bb cc ddddd", 6, balance), "aaa\nbb cc\nddddd");
+        assert_eq!(
+            wrap("aaa bb cc ddddd", 6, rules(false, false, true, false)),
+            "aaa bb\ncc\nddddd"
+        );
+
+        let all = rules(true, true, true, true);
+        assert_eq!(wrap("line 1\nline 2", 4, all), "line\n1\nline\n2");
+        assert_eq!(wrap("\n\n", 4, all), "\n\n");
+    }
 }
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::settings::ColumnStyle;
+
 /// Tabled a trait responsible for providing a header fields and a row fields.
 ///
 /// It's urgent that `header` len is equal to `fields` len.
@@ -18,6 +20,39 @@ pub trait Tabled {
     fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<Cow<'static, str>>;
+
+    /// Fields of columns which are shown for the given groups.
+    ///
+    /// By default all fields are returned.
+    ///
+    /// The derive macro keeps only fields which either have no `#[tabled(group = "...")]`
+    /// or belong to any of the given groups.
+    fn fields_for(&self, groups: &[&str]) -> Vec<Cow<'_, str>> {
+        let _ = groups;
+        self.fields()
+    }
+
+    /// Headers of columns which are shown for the given groups.
+    ///
+    /// It must be consistent with [`Tabled::fields_for`].
+    ///
+    /// By default all headers are returned.
+    fn headers_for(groups: &[&str]) -> Vec<Cow<'static, str>> {
+        let _ = groups;
+        Self::headers()
+    }
+
+    /// Column styles returns an alignment, a color and a width limit of each column,
+    /// which are applied by [`Table::new`].
+    ///
+    /// It must be consistent with [`Tabled::headers`].
+    ///
+    /// By default all styles are empty.
+    ///
+    /// [`Table::new`]: crate::Table::new
+    fn column_styles() -> Vec<ColumnStyle> {
+        vec![ColumnStyle::new(); Self::LENGTH]
+    }
 }
 
 impl<T> Tabled for &T
@@ -32,6 +67,15 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn fields_for(&self, groups: &[&str]) -> Vec<Cow<'_, str>> {
+        T::fields_for(self, groups)
+    }
+    fn headers_for(groups: &[&str]) -> Vec<Cow<'static, str>> {
+        T::headers_for(groups)
+    }
+    fn column_styles() -> Vec<ColumnStyle> {
+        T::column_styles()
+    }
 }
 
 impl<T> Tabled for Box<T>
@@ -46,6 +90,15 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn fields_for(&self, groups: &[&str]) -> Vec<Cow<'_, str>> {
+        T::fields_for(self, groups)
+    }
+    fn headers_for(groups: &[&str]) -> Vec<Cow<'static, str>> {
+        T::headers_for(groups)
+    }
+    fn column_styles() -> Vec<ColumnStyle> {
+        T::column_styles()
+    }
 }
 
 macro_rules! tuple_table {
@@ -66,6 +119,26 @@ macro_rules! tuple_table {
                 $(fields.append(&mut $name::headers());)+
                 fields
             }
+
+            fn fields_for(&self, groups: &[&str]) -> Vec<Cow<'_, str>> {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = self;
+                let mut fields = Vec::with_capacity(Self::LENGTH);
+                $(fields.append(&mut $name.fields_for(groups));)+
+                fields
+            }
+
+            fn headers_for(groups: &[&str]) -> Vec<Cow<'static, str>> {
+                let mut fields = Vec::with_capacity(Self::LENGTH);
+                $(fields.append(&mut $name::headers_for(groups));)+
+                fields
+            }
+
+            fn column_styles() -> Vec<ColumnStyle> {
+                let mut styles = Vec::with_capacity(Self::LENGTH);
+                $(styles.append(&mut $name::column_styles());)+
+                styles
+            }
         }
     };
 }
@@ -2,6 +2,7 @@
 //! a structure has a lot of fields.
 
 use crate::grid::util::string::get_line_width;
+use crate::grid::util::width::UnicodeWidth;
 use crate::Tabled;
 use std::cell::RefCell;
 use std::fmt::{self, Debug, Display};
@@ -284,7 +285,7 @@ fn truncate(text: &mut String, max: usize, suffix: &str) {
     if max == 0 || text.is_empty() {
         *text = String::new();
     } else {
-        *text = crate::util::string::cut_str2(text, max).into_owned();
+        *text = crate::util::string::cut_str2(text, max, &UnicodeWidth).into_owned();
     }
 
     let cut_was_done = text.len() < original_len;
@@ -0,0 +1,313 @@
+//! This module contains a [`LiveTable`] table.
+//!
+//! [`LiveTable`] keeps a printed [`Table`] up to date in a terminal,
+//! by redrawing only lines which were changed.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{tables::LiveTable, Table};
+//!
+//! let table = Table::new([("build", "running"), ("test", "waiting")]);
+//!
+//! let mut live = LiveTable::new(Vec::new(), table);
+//! live.render().unwrap();
+//!
+//! live.set((2, 1), "running");
+//! live.render().unwrap();
+//!
+//! let output = String::from_utf8(live.into_inner()).unwrap();
+//!
+//! assert_eq!(
+//!     output,
+//!     concat!(
+//!         "+-------+---------+\n",
+//!         "| &str  | &str    |\n",
+//!         "+-------+---------+\n",
+//!         "| build | running |\n",
+//!         "+-------+---------+\n",
+//!         "| test  | waiting |\n",
+//!         "+-------+---------+\n",
+//!         // move 2 lines up, redraw a line, and go back down
+//!         "\u{1b}[2F\u{1b}[2K| test  | running |\n",
+//!         "\u{1b}[1E",
+//!     ),
+//! );
+//! ```
+
+use std::io;
+
+use crate::{
+    grid::{
+        config::Position,
+        records::{
+            vec_records::{Text, VecRecords},
+            ExactRecords, Records,
+        },
+    },
+    settings::TableOption,
+    Table,
+};
+
+/// A table which is printed into a terminal and then redrawn in place.
+///
+/// The first [`LiveTable::render`] prints a table as it is.
+/// Next calls compare the table with what was printed last time,
+/// and rewrite only changed lines using ANSI escape sequences to move the cursor.
+/// A cursor is expected to stay right after the table between the calls.
+///
+/// A [`LiveTable::window`] can be set to show only last rows,
+/// in which case the first row (a header) is always shown.
+/// It relies on rows not being spanned into one another.
+///
+/// As it writes into any [`io::Write`] it can be tested without a real terminal.
+///
+/// [`LiveTable::set`] and [`LiveTable::push_row`] reset widths and heights
+/// which were set by settings like [`Width::increase`], so such settings need to be applied again.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{tables::LiveTable, Table};
+///
+/// let table = Table::new(["a", "b"]);
+///
+/// let mut live = LiveTable::new(Vec::new(), table).window(1);
+/// live.render().unwrap();
+///
+/// live.push_row(["c"]);
+/// live.render().unwrap();
+///
+/// let output = String::from_utf8(live.into_inner()).unwrap();
+///
+/// assert_eq!(
+///     output,
+///     concat!(
+///         "+------+\n",
+///         "| &str |\n",
+///         "+------+\n",
+///         "| b    |\n",
+///         "+------+\n",
+///         "\u{1b}[2F\u{1b}[2K| c    |\n",
+///         "\u{1b}[1E",
+///     ),
+/// );
+/// ```
+///
+/// [`Width::increase`]: crate::settings::Width::increase
+#[derive(Debug)]
+pub struct LiveTable<W> {
+    writer: W,
+    table: Table,
+    window: Option<usize>,
+    lines: Vec<String>,
+}
+
+impl<W> LiveTable<W> {
+    /// Creates a new [`LiveTable`].
+    ///
+    /// Nothing is printed until [`LiveTable::render`] is called.
+    pub fn new(writer: W, table: Table) -> Self {
+        Self {
+            writer,
+            table,
+            window: None,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Sets a maximum number of rows shown after a header.
+    ///
+    /// Only the last rows are shown, so a table scrolls as rows are added.
+    pub fn window(mut self, rows: usize) -> Self {
+        self.window = Some(rows);
+        self
+    }
+
+    /// Returns a table.
+    pub fn get_table(&self) -> &Table {
+        &self.table
+    }
+
+    /// Returns a table.
+    ///
+    /// Changes are shown on a next [`LiveTable::render`].
+    pub fn get_table_mut(&mut self) -> &mut Table {
+        &mut self.table
+    }
+
+    /// Returns a reference to the writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Changes a text of a cell.
+    ///
+    /// The change is shown on a next [`LiveTable::render`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of the table.
+    pub fn set<S>(&mut self, pos: Position, text: S)
+    where
+        S: Into<String>,
+    {
+        let _ = self.table.modify(pos, text.into());
+    }
+
+    /// Appends a row.
+    ///
+    /// The row is cut or filled with empty cells to match a number of columns.
+    /// The change is shown on a next [`LiveTable::render`].
+    pub fn push_row<I, T>(&mut self, row: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let _ = self.table.with(PushRow(row));
+    }
+
+    /// Prints a table or redraws the lines changed since a previous call.
+    pub fn render(&mut self) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let lines = self.build_lines();
+
+        let mut buf = String::new();
+        if self.lines.is_empty() {
+            for line in &lines {
+                buf.push_str(line);
+                buf.push('\n');
+            }
+        } else {
+            redraw(&mut buf, &self.lines, &lines);
+        }
+
+        self.lines = lines;
+
+        self.writer.write_all(buf.as_bytes())?;
+        self.writer.flush()
+    }
+
+    fn build_lines(&self) -> Vec<String> {
+        let (text, layout) = self.table.to_string_with_layout();
+        let lines = text.lines().map(String::from).collect::<Vec<_>>();
+
+        let count_rows = self.table.count_rows();
+        let window = match self.window {
+            Some(window) if count_rows > window + 1 => window,
+            _ => return lines,
+        };
+
+        let row_lines = |row: usize| {
+            let cells = layout.cells().iter().filter(|cell| cell.position.0 == row);
+            let start = cells.clone().map(|cell| cell.area.line).min();
+            let end = cells.map(|cell| cell.area.lines().end).max();
+            start.zip(end)
+        };
+
+        let first_row = count_rows - window;
+
+        let header_end = row_lines(1).map(|(start, _)| start);
+        let rest_start = if first_row < count_rows {
+            row_lines(first_row).map(|(start, _)| start)
+        } else {
+            row_lines(count_rows - 1).map(|(_, end)| end)
+        };
+
+        match (header_end, rest_start) {
+            (Some(header_end), Some(rest_start)) => {
+                let mut view = lines[..header_end].to_vec();
+                view.extend_from_slice(&lines[rest_start..]);
+                view
+            }
+            _ => lines,
+        }
+    }
+}
+
+// A row is appended as a table option, so widths and heights cached by a table are reset.
+struct PushRow<I>(I);
+
+impl<I, T, C, D> TableOption<VecRecords<Text<String>>, C, D> for PushRow<I>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<String>,
+{
+    fn change(self, records: &mut VecRecords<Text<String>>, _: &mut C, _: &mut D) {
+        let count_columns = records.count_columns();
+
+        let mut row = self
+            .0
+            .into_iter()
+            .map(|text| Text::new(text.into()))
+            .collect::<Vec<_>>();
+        if records.count_rows() > 0 {
+            row.resize(count_columns, Text::default());
+        }
+
+        let mut data: Vec<Vec<_>> = std::mem::take(records).into();
+        data.push(row);
+        *records = VecRecords::new(data);
+    }
+}
+
+/// Writes ANSI sequences which turn `old` lines into `new` ones.
+///
+/// The cursor is expected to be on the line after the `old` lines,
+/// and it's left on the line after the `new` ones.
+fn redraw(buf: &mut String, old: &[String], new: &[String]) {
+    let first_change = (0..old.len().max(new.len())).find(|&i| old.get(i) != new.get(i));
+    let first_change = match first_change {
+        Some(line) => line,
+        None => return,
+    };
+
+    if first_change < old.len() {
+        move_up(buf, old.len() - first_change);
+    }
+
+    let mut skipped = 0;
+    for (i, line) in new.iter().enumerate().skip(first_change) {
+        if old.get(i) == Some(line) {
+            skipped += 1;
+            continue;
+        }
+
+        if skipped > 0 {
+            move_down(buf, skipped);
+            skipped = 0;
+        }
+
+        if i < old.len() {
+            // clear the line before it's rewritten
+            buf.push_str("\u{1b}[2K");
+        }
+
+        buf.push_str(line);
+        buf.push('\n');
+    }
+
+    if skipped > 0 {
+        move_down(buf, skipped);
+    }
+
+    if new.len() < old.len() {
+        // clear lines left from a previous table
+        buf.push_str("\u{1b}[J");
+    }
+}
+
+fn move_up(buf: &mut String, lines: usize) {
+    buf.push_str(&format!("\u{1b}[{}F", lines));
+}
+
+fn move_down(buf: &mut String, lines: usize) {
+    buf.push_str(&format!("\u{1b}[{}E", lines));
+}
@@ -22,6 +22,20 @@
 //! Peek it when you want to have a feature full table.
 //! But you have a memory conserns.
 //!
+//! ## [`StreamTable`]
+//!
+//! A table which writes rows into an [`std::io::Write`] as they are pushed.
+//! Column widths are set upfront or learned from first rows.
+//!
+//! Peek it when rows come over time, like a progress of a long running job.
+//!
+//! ## [`LiveTable`]
+//!
+//! A [`Table`] printed into a terminal which is redrawn in place,
+//! only changed lines are rewritten.
+//!
+//! Peek it for dashboards which are updated over time.
+//!
 //! ## [`PoolTable`]
 //!
 //! A table with a greater control of a layout.
@@ -55,6 +69,10 @@ mod extended;
 #[cfg(feature = "std")]
 mod iter;
 #[cfg(feature = "std")]
+mod live;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
 mod table;
 #[cfg(feature = "std")]
 mod table_pool;
@@ -67,6 +85,14 @@ pub use table::Table;
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use iter::IterTable;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use stream::StreamTable;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use live::LiveTable;
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use extended::ExtendedTable;
@@ -0,0 +1,441 @@
+//! This module contains a [`StreamTable`] table.
+//!
+//! In contrast to [`Table`] [`StreamTable`] prints rows right away as they're pushed,
+//! it never keeps the whole data.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{settings::Style, tables::StreamTable};
+//!
+//! let mut buf = Vec::new();
+//!
+//! let mut table = StreamTable::new(&mut buf).widths([4, 8]).with(Style::modern());
+//! table.push(["id", "status"]).unwrap();
+//! table.push(["1", "running"]).unwrap();
+//! table.push(["2", "finished successfully"]).unwrap();
+//! table.finish().unwrap();
+//!
+//! assert_eq!(
+//!     String::from_utf8(buf).unwrap(),
+//!     "┌──────┬──────────┐\n\
+//!      │ id   │ status   │\n\
+//!      ├──────┼──────────┤\n\
+//!      │ 1    │ running  │\n\
+//!      ├──────┼──────────┤\n\
+//!      │ 2    │ finished │\n\
+//!      └──────┴──────────┘\n",
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::io;
+
+use crate::{
+    grid::{
+        colors::NoColors,
+        config::{
+            AlignmentHorizontal, ColoredConfig, Entity, Formatting, Indent, Sides, SpannedConfig,
+        },
+        dimension::{CompleteDimensionVecRecords, Dimension},
+        records::vec_records::{Text, VecRecords},
+        util::string::{count_lines, get_lines, get_text_width},
+        PeekableGrid,
+    },
+    settings::{
+        width::{Truncate, Wrap},
+        Style, TableOption,
+    },
+};
+
+/// A table which writes rows into an [`io::Write`] as soon as they are pushed.
+///
+/// Column widths must be known before a first row is printed.
+/// They're either set explicitly via [`StreamTable::widths`],
+/// or learned from first rows (see [`StreamTable::sniff`]), which are buffered until then.
+/// By default widths are learned from the first row, which is usually a header.
+///
+/// A content which doesn't fit a column is truncated,
+/// or wrapped for columns set by [`StreamTable::wrap`].
+///
+/// Each line is ended with a `\n` and a writer is flushed after each row.
+///
+/// # Example
+///
+/// ```
+/// use tabled::tables::StreamTable;
+///
+/// let mut buf = Vec::new();
+///
+/// let mut table = StreamTable::new(&mut buf).sniff(2).wrap(1);
+/// table.push(["job", "log"]).unwrap();
+/// table.push(["build", "ok"]).unwrap();
+/// table.push(["test", "3 failed"]).unwrap();
+/// table.finish().unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(buf).unwrap(),
+///     "+-------+-----+\n\
+///      | job   | log |\n\
+///      +-------+-----+\n\
+///      | build | ok  |\n\
+///      +-------+-----+\n\
+///      | test  | 3 f |\n\
+///      |       | ail |\n\
+///      |       | ed  |\n\
+///      +-------+-----+\n",
+/// );
+/// ```
+#[derive(Debug)]
+pub struct StreamTable<W> {
+    writer: W,
+    cfg: ColoredConfig,
+    sniff: usize,
+    widths: Option<Vec<usize>>,
+    wrap: Vec<usize>,
+    buffer: Vec<Vec<String>>,
+    count_rows: usize,
+}
+
+impl<W> StreamTable<W> {
+    /// Creates a new [`StreamTable`] structure.
+    pub fn new(writer: W) -> Self
+    where
+        W: io::Write,
+    {
+        Self {
+            writer,
+            cfg: ColoredConfig::new(create_config()),
+            sniff: 1,
+            widths: None,
+            wrap: Vec::new(),
+            buffer: Vec::new(),
+            count_rows: 0,
+        }
+    }
+
+    /// With is a generic function which applies options to the [`StreamTable`].
+    ///
+    /// Only options which change a configuration like [`Style`], [`Padding`] and [`Alignment`]
+    /// make sense here, because there's no data yet.
+    ///
+    /// [`Padding`]: crate::settings::Padding
+    /// [`Alignment`]: crate::settings::Alignment
+    pub fn with<O>(mut self, option: O) -> Self
+    where
+        for<'a> O:
+            TableOption<VecRecords<Text<String>>, ColoredConfig, CompleteDimensionVecRecords<'a>>,
+    {
+        let mut records = VecRecords::new(Vec::new());
+        let mut dims = CompleteDimensionVecRecords::default();
+        option.change(&mut records, &mut self.cfg, &mut dims);
+
+        self
+    }
+
+    /// Sets widths of columns, which also sets a number of columns.
+    ///
+    /// A width doesn't include a padding.
+    pub fn widths<I>(mut self, widths: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.widths = Some(widths.into_iter().collect());
+        self
+    }
+
+    /// Sets a number of first rows which are used to learn widths of columns.
+    ///
+    /// The rows are printed only once all of them are pushed (or on [`StreamTable::finish`]).
+    /// It's not used if widths are set by [`StreamTable::widths`].
+    pub fn sniff(mut self, count: usize) -> Self {
+        self.sniff = count;
+        self
+    }
+
+    /// Sets a column to be wrapped instead of being truncated.
+    pub fn wrap(mut self, column: usize) -> Self {
+        if !self.wrap.contains(&column) {
+            self.wrap.push(column);
+        }
+
+        self
+    }
+
+    /// Returns a reference to the writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns a number of printed rows.
+    pub fn count_rows(&self) -> usize {
+        self.count_rows
+    }
+
+    /// Pushes a row.
+    ///
+    /// The row is printed right away unless widths are yet to be learned.
+    pub fn push<I, T>(&mut self, row: I) -> io::Result<()>
+    where
+        W: io::Write,
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let row = row.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        if self.widths.is_none() {
+            self.buffer.push(row);
+
+            if self.buffer.len() < self.sniff {
+                return Ok(());
+            }
+
+            return self.flush_buffer();
+        }
+
+        self.print_row(row)?;
+        self.writer.flush()
+    }
+
+    /// Prints a bottom border and returns the writer.
+    ///
+    /// Buffered rows are printed before it.
+    pub fn finish(mut self) -> io::Result<W>
+    where
+        W: io::Write,
+    {
+        if self.widths.is_none() {
+            self.flush_buffer()?;
+        }
+
+        if self.count_rows > 0 {
+            let count_rows = self.count_rows;
+            self.print_split_line(count_rows, count_rows)?;
+        }
+
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+    fn flush_buffer(&mut self) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let widths = learn_widths(&self.buffer);
+        self.widths = Some(widths);
+
+        let buffer = std::mem::take(&mut self.buffer);
+        for row in buffer {
+            self.print_row(row)?;
+        }
+
+        self.writer.flush()
+    }
+
+    fn print_row(&mut self, row: Vec<String>) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let widths = self.widths.clone().unwrap_or_default();
+        if widths.is_empty() {
+            return Ok(());
+        }
+
+        let row = widths
+            .iter()
+            .enumerate()
+            .map(|(col, &width)| {
+                let text = row.get(col).map(String::as_str).unwrap_or("");
+                let text = fit_text(text, width, self.wrap.contains(&col));
+                Text::new(text)
+            })
+            .collect::<Vec<_>>();
+
+        let row_index = self.count_rows;
+        // there's no last row yet, so any line is an inner one
+        self.print_split_line(row_index, usize::MAX)?;
+
+        let height = row
+            .iter()
+            .map(|text| count_lines(text.as_ref()))
+            .max()
+            .unwrap_or(1);
+
+        let mut cfg = create_row_config(&self.cfg);
+        for (col, _) in widths.iter().enumerate() {
+            copy_cell_settings(&self.cfg, &mut cfg, (row_index, col));
+        }
+
+        let (widths, height) = add_padding(&self.cfg, &widths, height);
+        let dims = StreamDimension { widths, height };
+
+        let records = VecRecords::new(vec![row]);
+        let text = PeekableGrid::new(&records, &cfg, &dims, NoColors).to_string();
+
+        self.count_rows += 1;
+
+        writeln!(self.writer, "{}", text)
+    }
+
+    fn print_split_line(&mut self, line: usize, count_rows: usize) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let widths = self.widths.clone().unwrap_or_default();
+        let count_columns = widths.len();
+        let (widths, _) = add_padding(&self.cfg, &widths, 0);
+
+        let cfg: &SpannedConfig = &self.cfg;
+        if !cfg.has_horizontal(line, count_rows) {
+            return Ok(());
+        }
+
+        let shape = (count_rows, count_columns);
+
+        let mut buf = String::new();
+        for (col, width) in widths.iter().enumerate() {
+            if cfg.has_vertical(col, count_columns) {
+                if let Some(c) = cfg.get_intersection((line, col), shape) {
+                    buf.push(c);
+                }
+            }
+
+            let c = cfg.get_horizontal((line, col), count_rows).unwrap_or(' ');
+            for _ in 0..*width {
+                buf.push(c);
+            }
+        }
+
+        if cfg.has_vertical(count_columns, count_columns) {
+            if let Some(c) = cfg.get_intersection((line, count_columns), shape) {
+                buf.push(c);
+            }
+        }
+
+        buf.push('\n');
+
+        self.writer.write_all(buf.as_bytes())
+    }
+}
+
+/// A dimension of a single row.
+#[derive(Debug)]
+struct StreamDimension {
+    widths: Vec<usize>,
+    height: usize,
+}
+
+impl Dimension for StreamDimension {
+    fn get_width(&self, column: usize) -> usize {
+        self.widths[column]
+    }
+
+    fn get_height(&self, _: usize) -> usize {
+        self.height
+    }
+}
+
+fn create_config() -> SpannedConfig {
+    let mut cfg = SpannedConfig::default();
+    cfg.set_padding(
+        Entity::Global,
+        Sides::new(
+            Indent::spaced(1),
+            Indent::spaced(1),
+            Indent::default(),
+            Indent::default(),
+        ),
+    );
+    cfg.set_alignment_horizontal(Entity::Global, AlignmentHorizontal::Left);
+    cfg.set_formatting(Entity::Global, Formatting::new(false, false, false));
+    cfg.set_borders(Style::ascii().get_borders());
+
+    cfg
+}
+
+/// Creates a config of a single row, which has no horizontal lines,
+/// as they are printed separately.
+fn create_row_config(cfg: &SpannedConfig) -> SpannedConfig {
+    let mut borders = *cfg.get_borders();
+    borders.top = None;
+    borders.top_left = None;
+    borders.top_right = None;
+    borders.top_intersection = None;
+    borders.bottom = None;
+    borders.bottom_left = None;
+    borders.bottom_right = None;
+    borders.bottom_intersection = None;
+    borders.horizontal = None;
+    borders.left_intersection = None;
+    borders.right_intersection = None;
+    borders.intersection = None;
+
+    let mut row = SpannedConfig::default();
+    row.set_borders(borders);
+    row.set_borders_missing(cfg.get_borders_missing());
+
+    for (line, vertical) in cfg.get_vertical_lines() {
+        row.insert_vertical_line(line, vertical);
+    }
+
+    row
+}
+
+/// Copies settings of a cell of a table into a cell of a single row.
+fn copy_cell_settings(cfg: &SpannedConfig, row: &mut SpannedConfig, pos: (usize, usize)) {
+    let entity = Entity::Cell(pos.0, pos.1);
+    let target = Entity::Cell(0, pos.1);
+
+    // a padding is taken from a column so all rows are aligned
+    row.set_padding(target, cfg.get_padding(Entity::Column(pos.1)));
+    row.set_alignment_horizontal(target, *cfg.get_alignment_horizontal(entity));
+    row.set_alignment_vertical(target, *cfg.get_alignment_vertical(entity));
+    row.set_formatting(target, *cfg.get_formatting(entity));
+    row.set_justification(target, cfg.get_justification(entity));
+}
+
+fn add_padding(cfg: &SpannedConfig, widths: &[usize], height: usize) -> (Vec<usize>, usize) {
+    let mut max_height = 0;
+    let widths = widths
+        .iter()
+        .enumerate()
+        .map(|(col, width)| {
+            let pad = cfg.get_padding(Entity::Column(col));
+            max_height = max_height.max(height + pad.top.size + pad.bottom.size);
+            width + pad.left.size + pad.right.size
+        })
+        .collect();
+
+    (widths, max_height)
+}
+
+fn learn_widths(rows: &[Vec<String>]) -> Vec<usize> {
+    let count_columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..count_columns)
+        .map(|col| {
+            rows.iter()
+                .filter_map(|row| row.get(col))
+                .map(|text| get_text_width(text))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect()
+}
+
+fn fit_text(text: &str, width: usize, wrap: bool) -> String {
+    if get_text_width(text) <= width {
+        return text.to_string();
+    }
+
+    if wrap {
+        return Wrap::wrap(text, width, false);
+    }
+
+    get_lines(text)
+        .map(|line| Truncate::truncate(&line, width).into_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -5,6 +5,7 @@ use std::{borrow::Cow, fmt, iter::FromIterator};
 
 use crate::{
     builder::Builder,
+    export::{build_asciidoc, build_markdown, build_rst},
     grid::{
         colors::NoColors,
         config::{
@@ -12,13 +13,17 @@ use crate::{
             Indent, Sides, SpannedConfig,
         },
         dimension::{CompleteDimensionVecRecords, Dimension, Estimate, PeekableDimension},
+        layout::GridLayout,
         records::{
             vec_records::{Text, VecRecords},
             ExactRecords, Records,
         },
         PeekableGrid,
     },
-    settings::{object::Object, CellOption, Style, TableOption},
+    settings::{
+        object::{Columns, Object, Rows},
+        CellOption, Style, TableOption,
+    },
     Tabled,
 };
 
@@ -231,11 +236,19 @@ impl Table {
 
         let records = VecRecords::new(records);
 
-        Self {
+        let mut table = Self {
             records,
             config: ColoredConfig::new(configure_grid()),
             dimension: CompleteDimensionVecRecords::default(),
+        };
+
+        for (col, style) in T::column_styles().into_iter().enumerate() {
+            if !style.is_empty() {
+                let _ = table.modify(Columns::single(col).not(Rows::first()), style);
+            }
         }
+
+        table
     }
 
     /// Creates a builder from a data set given.
@@ -302,6 +315,52 @@ impl Table {
         builder
     }
 
+    /// Creates a [`Table`] out of any type which implements [`Serialize`].
+    ///
+    /// It's a shortcut for [`SerdeBuilder`], look there for the details.
+    ///
+    /// ```
+    /// use serde::Serialize;
+    /// use tabled::Table;
+    /// use testing_table::assert_table;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Server {
+    ///     host: &'static str,
+    ///     #[serde(rename = "up")]
+    ///     is_up: bool,
+    /// }
+    ///
+    /// let servers = [
+    ///     Server { host: "eu-1", is_up: true },
+    ///     Server { host: "us-1", is_up: false },
+    /// ];
+    ///
+    /// let table = Table::from_serialize(&servers).unwrap();
+    ///
+    /// assert_table!(
+    ///     table,
+    ///     "+------+-------+"
+    ///     "| host | up    |"
+    ///     "+------+-------+"
+    ///     "| eu-1 | true  |"
+    ///     "+------+-------+"
+    ///     "| us-1 | false |"
+    ///     "+------+-------+"
+    /// );
+    /// ```
+    ///
+    /// [`Serialize`]: serde::Serialize
+    /// [`SerdeBuilder`]: crate::builder::SerdeBuilder
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn from_serialize<T>(value: &T) -> Result<Self, crate::builder::SerdeError>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        crate::builder::SerdeBuilder::new().build(value)
+    }
+
     /// It's a generic function which applies options to the [`Table`].
     ///
     /// It applies settings immediately.
@@ -396,6 +455,168 @@ impl Table {
         total + countv + margin.left.size + margin.right.size
     }
 
+    /// Builds a string representation of a table along with its [`GridLayout`].
+    ///
+    /// The layout contains a place of each cell and each border segment in the output,
+    /// so it can be used to find a cell by a line and a column of the text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{grid::layout::Rect, Table};
+    ///
+    /// let table = Table::new([("tabled", 1)]);
+    /// let (text, layout) = table.to_string_with_layout();
+    ///
+    /// assert_eq!(
+    ///     text,
+    ///     "+--------+-----+\n\
+    ///      | &str   | i32 |\n\
+    ///      +--------+-----+\n\
+    ///      | tabled | 1   |\n\
+    ///      +--------+-----+"
+    /// );
+    ///
+    /// let cell = layout.find_cell(3, 4).unwrap();
+    /// assert_eq!(cell.position, (1, 0));
+    /// assert_eq!(cell.area, Rect::new(3, 1, 1, 8));
+    /// assert_eq!(cell.text, Rect::new(3, 2, 1, 6));
+    /// ```
+    pub fn to_string_with_layout(&self) -> (String, GridLayout) {
+        if self.is_empty() {
+            return (String::new(), GridLayout::default());
+        }
+
+        let config = self.config.as_ref();
+        let colors = self.config.get_colors();
+
+        let mut buf = String::new();
+        let result = if !self.dimension.is_empty() {
+            let mut dims = self.dimension.clone();
+            dims.estimate(&self.records, config);
+
+            let layout = GridLayout::new(config, &dims, self.shape());
+            print_grid(&mut buf, &self.records, config, &dims, colors).map(|_| layout)
+        } else {
+            let mut dims = PeekableDimension::default();
+            dims.estimate(&self.records, config);
+
+            let layout = GridLayout::new(config, &dims, self.shape());
+            print_grid(&mut buf, &self.records, config, &dims, colors).map(|_| layout)
+        };
+
+        let layout = result.expect("It's guaranteed to never happen otherwise it's considered an stdlib error or impl error");
+
+        (buf, layout)
+    }
+
+    /// Builds a GitHub Flavored Markdown table.
+    ///
+    /// In contrast to [`Style::markdown`] it produces a valid markdown,
+    /// no matter which style is set:
+    ///
+    /// - A first row is used as a header.
+    /// - An alignment of columns is put into a delimiter row (`---`, `:---:`, `---:`).
+    /// - `|` is escaped and multi-line cells are joined with `<br>`.
+    /// - A spanned cell is put into its first position, the covered cells are left empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{object::Columns, Alignment},
+    ///     Table,
+    /// };
+    ///
+    /// let mut table = Table::new([("a|b", 1), ("multi\nline", 20)]);
+    /// table.modify(Columns::single(1), Alignment::right());
+    ///
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     "| &str          | i32 |\n\
+    ///      | ------------- | --: |\n\
+    ///      | a\\|b          |   1 |\n\
+    ///      | multi<br>line |  20 |"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        build_markdown(self)
+    }
+
+    /// Builds a reStructuredText grid table.
+    ///
+    /// In contrast to [`Style::re_structured_text`] it can represent spans:
+    ///
+    /// - A first row is used as a header, separated by `=`.
+    /// - Column and row spans are drawn by removing borders inside a spanned cell.
+    /// - Text is aligned to the left as grid tables don't carry an alignment.
+    /// - ANSI sequences are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{object::Cell, Span},
+    ///     Table,
+    /// };
+    ///
+    /// let mut table = Table::new([("a", 1), ("b", 2), ("c", 3)]);
+    /// table.modify(Cell::new(1, 0), Span::row(2));
+    /// table.modify(Cell::new(3, 0), Span::column(2));
+    ///
+    /// assert_eq!(
+    ///     table.to_rst(),
+    ///     "+------+-----+\n\
+    ///      | &str | i32 |\n\
+    ///      +======+=====+\n\
+    ///      | a    | 1   |\n\
+    ///      |      +-----+\n\
+    ///      |      | 2   |\n\
+    ///      +------+-----+\n\
+    ///      | c          |\n\
+    ///      +------------+"
+    /// );
+    /// ```
+    pub fn to_rst(&self) -> String {
+        build_rst(self)
+    }
+
+    /// Builds an AsciiDoc table.
+    ///
+    /// - A first row is used as a header.
+    /// - An alignment of columns is put into a `cols` attribute,
+    ///   a cell which is aligned differently gets its own specifier.
+    /// - Spans are put as `2+|` for columns, `.2+|` for rows and `2.3+|` for both.
+    /// - `|` is escaped and multi-line cells are joined with ` +` hard line breaks.
+    /// - ANSI sequences are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{
+    ///     settings::{object::{Cell, Columns}, Alignment, Span},
+    ///     Table,
+    /// };
+    ///
+    /// let mut table = Table::new([("a|b", 1), ("c", 2)]);
+    /// table.modify(Columns::single(1), Alignment::right());
+    /// table.modify(Cell::new(2, 0), Span::column(2));
+    ///
+    /// assert_eq!(
+    ///     table.to_asciidoc(),
+    ///     "[cols=\"<,>\",options=\"header\"]\n\
+    ///      |===\n\
+    ///      |&str |i32\n\
+    ///      \n\
+    ///      |a\\|b |1\n\
+    ///      2+|c\n\
+    ///      |==="
+    /// );
+    /// ```
+    pub fn to_asciidoc(&self) -> String {
+        build_asciidoc(self)
+    }
+
     /// Returns a table config.
     pub fn get_config(&self) -> &ColoredConfig {
         &self.config
@@ -1,22 +1,26 @@
 use std::borrow::Cow;
 
-use crate::grid::util::string::get_char_width;
+use crate::grid::util::{string::get_graphemes, width::WidthMetric};
 
 /// The function cuts the string to a specific width.
 /// Preserving colors with `ansi` feature on.
-pub(crate) fn split_str(s: &str, width: usize) -> (Cow<'_, str>, Cow<'_, str>) {
+pub(crate) fn split_str<'a, M: WidthMetric>(
+    s: &'a str,
+    width: usize,
+    metric: &M,
+) -> (Cow<'a, str>, Cow<'a, str>) {
     #[cfg(feature = "ansi")]
     {
-        const REPLACEMENT: char = '\u{FFFD}';
+        let replacement = replacement_char(metric);
 
         let stripped = ansi_str::AnsiStr::ansi_strip(s);
-        let (length, cutwidth, csize) = split_at_width(&stripped, width);
+        let (length, cutwidth, csize) = split_at_width(&stripped, width, metric);
         let (mut lhs, mut rhs) = ansi_str::AnsiStr::ansi_split_at(s, length);
 
         if csize > 0 {
             let mut buf = lhs.into_owned();
             let count_unknowns = width - cutwidth;
-            buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+            push_before_closing_sequences(&mut buf, replacement, count_unknowns);
             lhs = Cow::Owned(buf);
             rhs = Cow::Owned(ansi_str::AnsiStr::ansi_cut(rhs.as_ref(), csize..).into_owned());
         }
@@ -26,9 +30,9 @@ pub(crate) fn split_str(s: &str, width: usize) -> (Cow<'_, str>, Cow<'_, str>) {
 
     #[cfg(not(feature = "ansi"))]
     {
-        const REPLACEMENT: char = '\u{FFFD}';
+        let replacement = replacement_char(metric);
 
-        let (length, cutwidth, csize) = split_at_width(s, width);
+        let (length, cutwidth, csize) = split_at_width(s, width, metric);
         let (lhs, rhs) = s.split_at(length);
 
         if csize == 0 {
@@ -37,7 +41,7 @@ pub(crate) fn split_str(s: &str, width: usize) -> (Cow<'_, str>, Cow<'_, str>) {
 
         let count_unknowns = width - cutwidth;
         let mut buf = lhs.to_owned();
-        buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+        buf.extend(std::iter::repeat(replacement).take(count_unknowns));
 
         (Cow::Owned(buf), Cow::Borrowed(&rhs[csize..]))
     }
@@ -45,18 +49,18 @@ pub(crate) fn split_str(s: &str, width: usize) -> (Cow<'_, str>, Cow<'_, str>) {
 
 /// The function cuts the string to a specific width.
 /// Preserving colors with `ansi` feature on.
-pub(crate) fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
+pub(crate) fn cut_str<'a, M: WidthMetric>(s: &'a str, width: usize, metric: &M) -> Cow<'a, str> {
     #[cfg(feature = "ansi")]
     {
-        const REPLACEMENT: char = '\u{FFFD}';
+        let replacement = replacement_char(metric);
 
         let stripped = ansi_str::AnsiStr::ansi_strip(s);
-        let (length, cutwidth, csize) = split_at_width(&stripped, width);
+        let (length, cutwidth, csize) = split_at_width(&stripped, width, metric);
         let mut buf = ansi_str::AnsiStr::ansi_cut(s, ..length);
         if csize != 0 {
             let mut b = buf.into_owned();
             let count_unknowns = width - cutwidth;
-            b.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+            push_before_closing_sequences(&mut b, replacement, count_unknowns);
             buf = Cow::Owned(b);
         }
 
@@ -65,16 +69,20 @@ pub(crate) fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
 
     #[cfg(not(feature = "ansi"))]
     {
-        cut_str2(s, width)
+        cut_str2(s, width, metric)
     }
 }
 
 /// The function cuts the string to a specific width.
 /// While not preserving ansi sequences.
-pub(crate) fn cut_str2(text: &str, width: usize) -> Cow<'_, str> {
-    const REPLACEMENT: char = '\u{FFFD}';
-
-    let (length, cutwidth, csize) = split_at_width(text, width);
+pub(crate) fn cut_str2<'a, M: WidthMetric>(
+    text: &'a str,
+    width: usize,
+    metric: &M,
+) -> Cow<'a, str> {
+    let replacement = replacement_char(metric);
+
+    let (length, cutwidth, csize) = split_at_width(text, width, metric);
     if csize == 0 {
         let buf = &text[..length];
         return Cow::Borrowed(buf);
@@ -83,44 +91,201 @@ pub(crate) fn cut_str2(text: &str, width: usize) -> Cow<'_, str> {
     let buf = &text[..length];
     let mut buf = buf.to_owned();
     let count_unknowns = width - cutwidth;
-    buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
+    buf.extend(std::iter::repeat(replacement).take(count_unknowns));
 
     Cow::Owned(buf)
 }
 
+/// The function cuts the string to a specific width keeping its end.
+/// Preserving colors with `ansi` feature on.
+pub(crate) fn cut_str_tail<'a, M: WidthMetric>(
+    s: &'a str,
+    width: usize,
+    metric: &M,
+) -> Cow<'a, str> {
+    #[cfg(feature = "ansi")]
+    {
+        let stripped = ansi_str::AnsiStr::ansi_strip(s);
+        let (start, cutwidth, csize) = split_at_width_tail(&stripped, width, metric);
+        let buf = ansi_str::AnsiStr::ansi_cut(s, start..);
+        if csize == 0 {
+            return buf;
+        }
+
+        let replacement = replacement_char(metric);
+        let mut b = String::with_capacity(buf.len() + width - cutwidth);
+        for _ in cutwidth..width {
+            b.push(replacement);
+        }
+
+        b.push_str(&buf);
+
+        Cow::Owned(b)
+    }
+
+    #[cfg(not(feature = "ansi"))]
+    {
+        let (start, cutwidth, csize) = split_at_width_tail(s, width, metric);
+        if csize == 0 {
+            return Cow::Borrowed(&s[start..]);
+        }
+
+        let replacement = replacement_char(metric);
+        let mut buf = String::with_capacity(s.len() - start + width - cutwidth);
+        for _ in cutwidth..width {
+            buf.push(replacement);
+        }
+
+        buf.push_str(&s[start..]);
+
+        Cow::Owned(buf)
+    }
+}
+
+/// The function splits a string in the position counted from the end and
+/// returns a number of bytes before the kept tail, its width and in case of a split in an unicode grapheme
+/// a size in bytes of a grapheme which was tried to be split in.
+pub(crate) fn split_at_width_tail<M: WidthMetric>(
+    s: &str,
+    at_width: usize,
+    metric: &M,
+) -> (usize, usize, usize) {
+    let graphemes = get_graphemes(s).collect::<Vec<_>>();
+
+    let mut start = s.len();
+    let mut width = 0;
+    for grapheme in graphemes.into_iter().rev() {
+        if width == at_width {
+            break;
+        };
+
+        if grapheme == "\n" || grapheme == "\r\n" {
+            width = 0;
+            start -= grapheme.len();
+            continue;
+        }
+
+        let g_width = metric.grapheme_width(grapheme);
+        if width + g_width > at_width {
+            return (start, width, grapheme.len());
+        }
+
+        width += g_width;
+        start -= grapheme.len();
+    }
+
+    (start, width, 0)
+}
+
 /// The function splits a string in the position and
 /// returns a exact number of bytes before the position and in case of a split in an unicode grapheme
-/// a width of a character which was tried to be split in.
-pub(crate) fn split_at_width(s: &str, at_width: usize) -> (usize, usize, usize) {
+/// a size in bytes of a grapheme which was tried to be split in.
+pub(crate) fn split_at_width<M: WidthMetric>(
+    s: &str,
+    at_width: usize,
+    metric: &M,
+) -> (usize, usize, usize) {
     let mut length = 0;
     let mut width = 0;
-    for c in s.chars() {
+    for grapheme in get_graphemes(s) {
         if width == at_width {
             break;
         };
 
-        if c == '\n' {
+        if grapheme == "\n" || grapheme == "\r\n" {
             width = 0;
-            length += 1;
+            length += grapheme.len();
             continue;
         }
 
-        let c_width = get_char_width(c);
-        let c_length = c.len_utf8();
+        let g_width = metric.grapheme_width(grapheme);
+        let g_length = grapheme.len();
 
-        // We cut the chars which takes more then 1 symbol to display,
+        // We cut the graphemes which takes more then 1 symbol to display,
         // in order to archive the necessary width.
-        if width + c_width > at_width {
-            return (length, width, c_length);
+        if width + g_width > at_width {
+            return (length, width, g_length);
         }
 
-        width += c_width;
-        length += c_length;
+        width += g_width;
+        length += g_length;
     }
 
     (length, width, 0)
 }
 
+/// Returns a character which is used to fill a space of a grapheme which was split.
+///
+/// It's a replacement character unless a metric considers it to be wide.
+pub(crate) fn replacement_char<M: WidthMetric>(metric: &M) -> char {
+    const REPLACEMENT: char = '\u{FFFD}';
+
+    if metric.grapheme_width("\u{FFFD}") == 1 {
+        REPLACEMENT
+    } else {
+        ' '
+    }
+}
+
+/// Inserts `count` characters right before the trailing SGR reset sequences of a string,
+/// so they are colored the same way as a text before them.
+#[cfg(feature = "ansi")]
+fn push_before_closing_sequences(buf: &mut String, c: char, count: usize) {
+    const RESETS: [&str; 13] = [
+        "", "0", "22", "23", "24", "25", "27", "28", "29", "39", "49", "55", "59",
+    ];
+
+    let mut end = buf.len();
+    while let Some(head) = buf[..end].strip_suffix('m') {
+        let start = match head.rfind("\u{1b}[") {
+            Some(start) => start,
+            None => break,
+        };
+
+        let is_reset = head[start + 2..]
+            .split(';')
+            .all(|code| RESETS.contains(&code));
+        if !is_reset {
+            break;
+        }
+
+        end = start;
+    }
+
+    let fill = (0..count).map(|_| c).collect::<String>();
+    buf.insert_str(end, &fill);
+}
+
+/// The function removes ANSI sequences from a string when `ansi` feature is on.
+pub(crate) fn strip_ansi(text: &str) -> Cow<'_, str> {
+    #[cfg(feature = "ansi")]
+    {
+        ansi_str::AnsiStr::ansi_strip(text)
+    }
+
+    #[cfg(not(feature = "ansi"))]
+    {
+        Cow::Borrowed(text)
+    }
+}
+
+/// The function parses a number from a cell text, ignoring surrounding whitespace.
+///
+/// `NaN` is not considered to be a number.
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    let number = text.parse::<f64>().ok()?;
+    if number.is_nan() {
+        return None;
+    }
+
+    Some(number)
+}
+
 /// Strip OSC codes from `s`. If `s` is a single OSC8 hyperlink, with no other text, then return
 /// (s_with_all_hyperlinks_removed, Some(url)). If `s` does not meet this description, then return
 /// (s_with_all_hyperlinks_removed, None). Any ANSI color sequences in `s` will be retained. See
@@ -188,11 +353,41 @@ pub(crate) fn strip_osc(text: &str) -> (String, Option<String>) {
 mod tests {
     use super::*;
 
-    use crate::grid::util::string::get_line_width;
+    use crate::grid::util::{
+        string::get_line_width,
+        width::{AmbiguousWide, CharCount, UnicodeWidth},
+    };
 
     #[cfg(feature = "ansi")]
     use owo_colors::{colors::Yellow, OwoColorize};
 
+    fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
+        super::cut_str(s, width, &UnicodeWidth)
+    }
+
+    #[test]
+    fn cut_str_tail_test() {
+        let cut = |s, width| cut_str_tail(s, width, &UnicodeWidth);
+
+        assert_eq!(cut("123456", 0), "");
+        assert_eq!(cut("123456", 3), "456");
+        assert_eq!(cut("123456", 6), "123456");
+        assert_eq!(cut("123456", 10), "123456");
+        assert_eq!(cut("😳😳😳", 4), "😳😳");
+        assert_eq!(cut("😳😳😳", 3), "�😳");
+        assert_eq!(cut("😳😳😳", 1), "�");
+        assert_eq!(cut_str_tail("±±±", 3, &AmbiguousWide), " ±");
+    }
+
+    #[test]
+    fn strip_by_metric_test() {
+        assert_eq!(super::cut_str("±±±", 3, &UnicodeWidth), "±±±");
+        assert_eq!(super::cut_str("±±±", 3, &AmbiguousWide), "± ");
+        assert_eq!(super::cut_str("±±±", 4, &AmbiguousWide), "±±");
+        assert_eq!(super::cut_str("日本語", 2, &CharCount), "日本");
+        assert_eq!(super::cut_str("😳😳😳", 1, &CharCount), "😳");
+    }
+
     #[test]
     fn strip_test() {
         assert_eq!(cut_str("123456", 0), "");
@@ -205,7 +400,11 @@ mod tests {
         assert_eq!(cut_str("😳😳😳😳😳", 3), "😳�");
         assert_eq!(cut_str("😳😳😳😳😳", 4), "😳😳");
         assert_eq!(cut_str("😳😳😳😳😳", 20), "😳😳😳😳😳");
+    }
 
+    #[cfg(not(feature = "grapheme"))]
+    #[test]
+    fn strip_emoji_by_chars_test() {
         assert_eq!(cut_str("🏳️🏳️", 0), "");
         assert_eq!(cut_str("🏳️🏳️", 1), "🏳");
         assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳");
@@ -231,6 +430,28 @@ mod tests {
         assert_eq!(cut_str("🇻🇬", 4), "🇻🇬");
     }
 
+    #[cfg(feature = "grapheme")]
+    #[test]
+    fn strip_emoji_by_graphemes_test() {
+        assert_eq!(cut_str("🏳️🏳️", 0), "");
+        assert_eq!(cut_str("🏳️🏳️", 1), "�");
+        assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}");
+        assert_eq!(cut_str("🏳️🏳️", 3), "🏳\u{fe0f}�");
+
+        assert_eq!(cut_str("👍🏿", 1), "�");
+        assert_eq!(cut_str("👍🏿", 2), "👍🏿");
+        assert_eq!(cut_str("👍🏿", 3), "👍🏿");
+
+        assert_eq!(cut_str("🇻🇬🇻🇬", 1), "�");
+        assert_eq!(cut_str("🇻🇬🇻🇬", 3), "🇻🇬�");
+
+        assert_eq!(cut_str("👨‍👩‍👧👨‍👩‍👧", 3), "👨‍👩‍👧�");
+        assert_eq!(cut_str("a\u{301}b\u{301}", 1), "a\u{301}");
+        assert_eq!(cut_str("नमस्ते", 3), "नम�");
+        assert_eq!(cut_str("नमस्ते", 4), "नमस्ते");
+        assert_eq!(get_line_width("👨‍👩‍👧"), 2);
+    }
+
     #[cfg(feature = "ansi")]
     #[test]
     fn strip_color_test() {
@@ -248,14 +469,18 @@ mod tests {
         assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
         assert_eq!(
             cut_str(&emojies, 3),
-            "\u{1b}[31;100m😳\u{1b}[39m\u{1b}[49m�"
+            "\u{1b}[31;100m😳�\u{1b}[39m\u{1b}[49m"
         );
         assert_eq!(
             cut_str(&emojies, 4),
             "\u{1b}[31;100m😳😳\u{1b}[39m\u{1b}[49m"
         );
         assert_eq!(cut_str(&emojies, 20), "\u{1b}[31;100m😳😳😳😳😳\u{1b}[0m");
+    }
 
+    #[cfg(all(feature = "ansi", not(feature = "grapheme")))]
+    #[test]
+    fn strip_color_emoji_by_chars_test() {
         let emojies = "🏳️🏳️".red().on_bright_black().to_string();
 
         assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
@@ -270,6 +495,23 @@ mod tests {
         );
     }
 
+    #[cfg(all(feature = "ansi", feature = "grapheme"))]
+    #[test]
+    fn strip_color_emoji_by_graphemes_test() {
+        let emojies = "🏳️🏳️".red().on_bright_black().to_string();
+
+        assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
+        assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m�\u{1b}[39m\u{1b}[49m");
+        assert_eq!(
+            cut_str(&emojies, 2),
+            "\u{1b}[31;100m🏳\u{fe0f}\u{1b}[39m\u{1b}[49m"
+        );
+        assert_eq!(
+            cut_str(&emojies, 3),
+            "\u{1b}[31;100m🏳\u{fe0f}�\u{1b}[39m\u{1b}[49m"
+        );
+    }
+
     #[test]
     #[cfg(feature = "ansi")]
     fn test_color_strip() {
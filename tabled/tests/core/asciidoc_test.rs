@@ -0,0 +1,144 @@
+#![cfg(feature = "std")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Cell, Columns, Rows},
+        Alignment, Span, Style,
+    },
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    asciidoc,
+    Matrix::table(2, 2).to_asciidoc(),
+    "[cols=\"^,^,^\",options=\"header\"]"
+    "|==="
+    "|N |column 0 |column 1"
+    ""
+    "|0 |0-0 |0-1"
+    "|1 |1-0 |1-1"
+    "|==="
+);
+
+test_table!(
+    asciidoc_ignores_style,
+    Matrix::new(2, 2).with(Style::modern()).to_asciidoc(),
+    "[cols=\"^,^,^\",options=\"header\"]"
+    "|==="
+    "|N |column 0 |column 1"
+    ""
+    "|0 |0-0 |0-1"
+    "|1 |1-0 |1-1"
+    "|==="
+);
+
+test_table!(
+    asciidoc_alignment,
+    Matrix::table(2, 2)
+        .modify(Columns::first(), Alignment::left())
+        .modify(Columns::single(2), Alignment::right())
+        .to_asciidoc(),
+    "[cols=\"<,^,>\",options=\"header\"]"
+    "|==="
+    "|N |column 0 |column 1"
+    ""
+    "|0 |0-0 |0-1"
+    "|1 |1-0 |1-1"
+    "|==="
+);
+
+test_table!(
+    asciidoc_alignment_from_data_rows,
+    Matrix::table(2, 2)
+        .modify(Rows::first(), Alignment::center())
+        .modify(Rows::new(1..), Alignment::right())
+        .to_asciidoc(),
+    "[cols=\">,>,>\",options=\"header\"]"
+    "|==="
+    "^|N ^|column 0 ^|column 1"
+    ""
+    "|0 |0-0 |0-1"
+    "|1 |1-0 |1-1"
+    "|==="
+);
+
+test_table!(
+    asciidoc_cell_alignment,
+    Matrix::table(2, 2).modify(Cell::new(2, 1), Alignment::left()).to_asciidoc(),
+    "[cols=\"^,^,^\",options=\"header\"]"
+    "|==="
+    "|N |column 0 |column 1"
+    ""
+    "|0 |0-0 |0-1"
+    "|1 <|1-0 |1-1"
+    "|==="
+);
+
+test_table!(
+    asciidoc_column_span,
+    Matrix::table(2, 2).modify(Cell::new(1, 1), Span::column(2)).to_asciidoc(),
+    "[cols=\"^,^,^\",options=\"header\"]"
+    "|==="
+    "|N |column 0 |column 1"
+    ""
+    "|0 2+|0-0"
+    "|1 |1-0 |1-1"
+    "|==="
+);
+
+test_table!(
+    asciidoc_row_span,
+    Matrix::table(3, 2).modify(Cell::new(1, 2), Span::row(2)).to_asciidoc(),
+    "[cols=\"^,^,^\",options=\"header\"]"
+    "|==="
+    "|N |column 0 |column 1"
+    ""
+    "|0 |0-0 .2+|0-1"
+    "|1 |1-0"
+    "|2 |2-0 |2-1"
+    "|==="
+);
+
+test_table!(
+    asciidoc_column_and_row_span,
+    Matrix::table(3, 3)
+        .modify(Cell::new(1, 1), Span::column(2))
+        .modify(Cell::new(1, 1), Span::row(2))
+        .to_asciidoc(),
+    "[cols=\"^,^,^,^\",options=\"header\"]"
+    "|==="
+    "|N |column 0 |column 1 |column 2"
+    ""
+    "|0 2.2+|0-0 |0-2"
+    "|1 |1-2"
+    "|2 |2-0 |2-1 |2-2"
+    "|==="
+);
+
+test_table!(
+    asciidoc_escape,
+    Builder::from_iter([["a|b", "c"], ["multi\nline", "|"]]).build().to_asciidoc(),
+    "[cols=\"<,<\",options=\"header\"]"
+    "|==="
+    "|a\\|b |c"
+    ""
+    "|multi +"
+    "line |\\|"
+    "|==="
+);
+
+test_table!(
+    asciidoc_single_row,
+    Builder::from_iter([["a", "b"]]).build().to_asciidoc(),
+    "[cols=\"<,<\"]"
+    "|==="
+    "|a |b"
+    "|==="
+);
+
+test_table!(asciidoc_empty, Builder::default().build().to_asciidoc(), "");
@@ -0,0 +1,196 @@
+#![cfg(feature = "std")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    export::{CsvWriter, QuoteStyle, SpanFill},
+    grid::records::IterRecords,
+    settings::{Span, Style},
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    csv,
+    CsvWriter::new().format(&Matrix::new(2, 2).with(Style::modern()))
+    .trim_end(),
+    "N,column 0,column 1"
+    "0,0-0,0-1"
+    "1,1-0,1-1"
+);
+
+test_table!(
+    csv_without_header,
+    CsvWriter::new().header(false).format(&Matrix::table(2, 2))
+    .trim_end(),
+    "0,0-0,0-1"
+    "1,1-0,1-1"
+);
+
+test_table!(
+    tsv,
+    CsvWriter::tsv().format(&Matrix::table(1, 2))
+    .trim_end(),
+    "N\tcolumn 0\tcolumn 1"
+    "0\t0-0\t0-1"
+);
+
+test_table!(
+    csv_custom_delimiter,
+    CsvWriter::new().delimiter(';').format(&Table::new([("a;b", "c,d")]))
+    .trim_end(),
+    "&str;&str"
+    "\"a;b\";c,d"
+);
+
+test_table!(
+    csv_quoting,
+    CsvWriter::new().format(&Table::new([("a \"quote\"", "multi\nline")]))
+    .trim_end(),
+    "&str,&str"
+    "\"a \"\"quote\"\"\",\"multi"
+    "line\""
+);
+
+test_table!(
+    csv_quote_always,
+    CsvWriter::new()
+        .quote_style(QuoteStyle::Always)
+        .format(&Table::new([("a", "")]))
+    .trim_end(),
+    "\"&str\",\"&str\""
+    "\"a\",\"\""
+);
+
+test_table!(
+    csv_quote_never,
+    CsvWriter::new()
+        .quote_style(QuoteStyle::Never)
+        .format(&Table::new([("a,b", "\"c\"")]))
+    .trim_end(),
+    "&str,&str"
+    "a,b,\"c\""
+);
+
+test_table!(
+    csv_custom_quote,
+    CsvWriter::new().quote('\'').format(&Table::new([("it's", "\"c\"")]))
+    .trim_end(),
+    "&str,&str"
+    "'it''s',\"c\""
+);
+
+test_table!(
+    csv_column_span,
+    {
+        let mut table = Matrix::table(2, 2);
+        table.modify((1, 1), Span::column(2));
+        CsvWriter::new().format(&table)
+    }
+    .trim_end(),
+    "N,column 0,column 1"
+    "0,0-0,"
+    "1,1-0,1-1"
+);
+
+test_table!(
+    csv_column_span_duplicate,
+    {
+        let mut table = Matrix::table(2, 2);
+        table.modify((1, 1), Span::column(2));
+        CsvWriter::new().span_fill(SpanFill::Duplicate).format(&table)
+    }
+    .trim_end(),
+    "N,column 0,column 1"
+    "0,0-0,0-0"
+    "1,1-0,1-1"
+);
+
+test_table!(
+    csv_row_span_duplicate,
+    {
+        let mut table = Matrix::table(2, 2);
+        table.modify((1, 0), Span::row(2));
+        CsvWriter::new().span_fill(SpanFill::Duplicate).format(&table)
+    }
+    .trim_end(),
+    "N,column 0,column 1"
+    "0,0-0,0-1"
+    "0,1-0,1-1"
+);
+
+test_table!(
+    csv_area_span,
+    {
+        let mut table = Matrix::table(2, 2);
+        table.modify((1, 1), Span::row(2)).modify((1, 1), Span::column(2));
+        CsvWriter::new().format(&table)
+    }
+    .trim_end(),
+    "N,column 0,column 1"
+    "0,0-0,"
+    "1,,"
+);
+
+test_table!(
+    csv_area_span_duplicate,
+    {
+        let mut table = Matrix::table(2, 2);
+        table.modify((1, 1), Span::row(2)).modify((1, 1), Span::column(2));
+        CsvWriter::new().span_fill(SpanFill::Duplicate).format(&table)
+    }
+    .trim_end(),
+    "N,column 0,column 1"
+    "0,0-0,0-0"
+    "1,0-0,0-0"
+);
+
+test_table!(
+    csv_records,
+    {
+        let data: Vec<Vec<String>> = Builder::from_iter([["a", "b,c"], ["1", "2"]]).into();
+        CsvWriter::new().format_records(IterRecords::new(&data, 2, None))
+    }
+    .trim_end(),
+    "a,\"b,c\""
+    "1,2"
+);
+
+test_table!(
+    csv_table_records,
+    CsvWriter::tsv()
+        .header(false)
+        .format_records(Matrix::table(1, 1).get_records())
+        .trim_end(),
+    "0\t0-0"
+);
+
+test_table!(
+    csv_empty,
+    CsvWriter::new().format(&Builder::default().build()),
+    ""
+);
+
+#[test]
+fn csv_write() {
+    let mut buf = Vec::new();
+    CsvWriter::new()
+        .write(&mut buf, &Table::new(["a"]))
+        .unwrap();
+
+    assert_eq!(buf, b"&str\na\n");
+}
+
+#[cfg(feature = "ansi")]
+test_table!(
+    csv_strip_ansi,
+    CsvWriter::new()
+        .strip_ansi(true)
+        .format(&Table::new(["\u{1b}[31mred\u{1b}[39m"]))
+    .trim_end(),
+    "&str"
+    "red"
+);
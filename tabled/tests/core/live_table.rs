@@ -0,0 +1,278 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{Style, Width},
+    tables::LiveTable,
+    Table,
+};
+
+fn output(live: &LiveTable<Vec<u8>>) -> String {
+    String::from_utf8(live.get_ref().clone()).unwrap()
+}
+
+fn live_table() -> LiveTable<Vec<u8>> {
+    let mut table = Table::new([("build", "ok"), ("test", "ok")]);
+    table.with(Style::psql());
+
+    LiveTable::new(Vec::new(), table)
+}
+
+#[test]
+fn live_table_first_render() {
+    let mut live = live_table();
+    live.render().unwrap();
+
+    assert_eq!(
+        output(&live),
+        concat!(
+            " &str  | &str \n",
+            "-------+------\n",
+            " build | ok   \n",
+            " test  | ok   \n",
+        )
+    );
+}
+
+#[test]
+fn live_table_no_changes() {
+    let mut live = live_table();
+    live.render().unwrap();
+    let before = output(&live);
+
+    live.render().unwrap();
+    live.set((1, 1), "ok");
+    live.render().unwrap();
+
+    assert_eq!(output(&live), before);
+}
+
+#[test]
+fn live_table_update_cells() {
+    let mut live = live_table();
+    live.render().unwrap();
+    let before = output(&live).len();
+
+    live.set((1, 0), "check");
+    live.set((2, 1), "no");
+    live.render().unwrap();
+
+    assert_eq!(
+        &output(&live)[before..],
+        concat!(
+            "\u{1b}[2F",
+            "\u{1b}[2K check | ok   \n",
+            "\u{1b}[2K test  | no   \n",
+        )
+    );
+}
+
+#[test]
+fn live_table_update_skips_unchanged_lines() {
+    let mut live = live_table();
+    live.push_row(["deploy", "ok"]);
+    live.render().unwrap();
+    let before = output(&live).len();
+
+    live.set((1, 1), "no");
+    live.set((3, 1), "no");
+    live.render().unwrap();
+
+    assert_eq!(
+        &output(&live)[before..],
+        concat!(
+            "\u{1b}[3F",
+            "\u{1b}[2K build  | no   \n",
+            "\u{1b}[1E",
+            "\u{1b}[2K deploy | no   \n",
+        )
+    );
+}
+
+#[test]
+fn live_table_width_change_redraws_all() {
+    let mut live = live_table();
+    live.render().unwrap();
+    let before = output(&live).len();
+
+    live.set((1, 1), "failed");
+    live.render().unwrap();
+
+    assert_eq!(
+        &output(&live)[before..],
+        concat!(
+            "\u{1b}[4F",
+            "\u{1b}[2K &str  | &str   \n",
+            "\u{1b}[2K-------+--------\n",
+            "\u{1b}[2K build | failed \n",
+            "\u{1b}[2K test  | ok     \n",
+        )
+    );
+}
+
+#[test]
+fn live_table_push_rows() {
+    let mut live = live_table();
+    live.render().unwrap();
+    let before = output(&live).len();
+
+    live.push_row(["lint", "ok", "ignored"]);
+    live.push_row(["docs"]);
+    live.render().unwrap();
+
+    assert_eq!(
+        &output(&live)[before..],
+        concat!(" lint  | ok   \n", " docs  |      \n")
+    );
+}
+
+#[test]
+fn live_table_push_row_resets_widths() {
+    let mut live = live_table();
+    live.get_table_mut().with(Width::increase(20));
+    live.render().unwrap();
+    let before = output(&live).len();
+
+    live.push_row(["a much longer first cell here", "x"]);
+    live.render().unwrap();
+
+    assert_eq!(
+        &output(&live)[before..],
+        concat!(
+            "\u{1b}[4F",
+            "\u{1b}[2K &str                          | &str \n",
+            "\u{1b}[2K-------------------------------+------\n",
+            "\u{1b}[2K build                         | ok   \n",
+            "\u{1b}[2K test                          | ok   \n",
+            " a much longer first cell here | x    \n",
+        )
+    );
+}
+
+#[test]
+fn live_table_set_resets_widths() {
+    let mut live = live_table();
+    live.get_table_mut().with(Width::increase(20));
+    live.render().unwrap();
+    let before = output(&live).len();
+
+    live.set((1, 0), "a much longer first cell here");
+    live.render().unwrap();
+
+    assert_eq!(
+        &output(&live)[before..],
+        concat!(
+            "\u{1b}[4F",
+            "\u{1b}[2K &str                          | &str \n",
+            "\u{1b}[2K-------------------------------+------\n",
+            "\u{1b}[2K a much longer first cell here | ok   \n",
+            "\u{1b}[2K test                          | ok   \n",
+        )
+    );
+}
+
+#[test]
+fn live_table_shrink() {
+    let mut live = live_table();
+    live.render().unwrap();
+    let before = output(&live).len();
+
+    live.get_table_mut()
+        .with(Style::psql().remove_horizontals());
+    live.render().unwrap();
+
+    assert_eq!(
+        &output(&live)[before..],
+        concat!(
+            "\u{1b}[3F",
+            "\u{1b}[2K build | ok   \n",
+            "\u{1b}[2K test  | ok   \n",
+            "\u{1b}[J",
+        )
+    );
+}
+
+#[test]
+fn live_table_window() {
+    let mut table = Table::new(["a", "b", "c"]);
+    table.with(Style::modern());
+
+    let mut live = LiveTable::new(Vec::new(), table).window(2);
+    live.render().unwrap();
+
+    assert_eq!(
+        output(&live),
+        concat!(
+            "┌──────┐\n",
+            "│ &str │\n",
+            "├──────┤\n",
+            "│ b    │\n",
+            "├──────┤\n",
+            "│ c    │\n",
+            "└──────┘\n",
+        )
+    );
+
+    let before = output(&live).len();
+
+    live.push_row(["d"]);
+    live.render().unwrap();
+
+    assert_eq!(
+        &output(&live)[before..],
+        concat!(
+            "\u{1b}[4F",
+            "\u{1b}[2K│ c    │\n",
+            "\u{1b}[1E",
+            "\u{1b}[2K│ d    │\n",
+            "\u{1b}[1E",
+        )
+    );
+}
+
+#[test]
+fn live_table_window_keeps_header_line() {
+    let mut table = Table::new(["a", "b", "c"]);
+    table.with(Style::psql());
+
+    let mut live = LiveTable::new(Vec::new(), table).window(1);
+    live.render().unwrap();
+
+    assert_eq!(output(&live), concat!(" &str \n", "------\n", " c    \n"));
+}
+
+#[test]
+fn live_table_window_zero() {
+    let mut table = Table::new(["a", "b"]);
+    table.with(Style::ascii());
+
+    let mut live = LiveTable::new(Vec::new(), table).window(0);
+    live.render().unwrap();
+
+    assert_eq!(
+        output(&live),
+        concat!("+------+\n", "| &str |\n", "+------+\n", "+------+\n")
+    );
+}
+
+#[test]
+fn live_table_window_with_multiline_rows() {
+    let mut table = Table::new(["a", "b\nc", "d"]);
+    table.with(Width::increase(6));
+
+    let mut live = LiveTable::new(Vec::new(), table).window(2);
+    live.render().unwrap();
+
+    assert_eq!(
+        output(&live),
+        concat!(
+            "+------+\n",
+            "| &str |\n",
+            "+------+\n",
+            "| b    |\n",
+            "| c    |\n",
+            "+------+\n",
+            "| d    |\n",
+            "+------+\n",
+        )
+    );
+}
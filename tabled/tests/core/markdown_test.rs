@@ -0,0 +1,113 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Columns, Rows},
+        Alignment, Span, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    markdown,
+    Matrix::table(2, 2).to_markdown(),
+    "|  N  | column 0 | column 1 |"
+    "| :-: | :------: | :------: |"
+    "|  0  |   0-0    |   0-1    |"
+    "|  1  |   1-0    |   1-1    |"
+);
+
+test_table!(
+    markdown_ignores_style,
+    Matrix::new(2, 2).with(Style::modern()).to_markdown(),
+    "|  N  | column 0 | column 1 |"
+    "| :-: | :------: | :------: |"
+    "|  0  |   0-0    |   0-1    |"
+    "|  1  |   1-0    |   1-1    |"
+);
+
+test_table!(
+    markdown_alignment,
+    Matrix::table(2, 2)
+        .modify(Columns::first(), Alignment::left())
+        .modify(Columns::single(2), Alignment::right())
+        .to_markdown(),
+    "| N   | column 0 | column 1 |"
+    "| --- | :------: | -------: |"
+    "| 0   |   0-0    |      0-1 |"
+    "| 1   |   1-0    |      1-1 |"
+);
+
+test_table!(
+    markdown_alignment_from_data_rows,
+    Matrix::table(2, 2)
+        .modify(Rows::first(), Alignment::center())
+        .modify(Rows::new(1..), Alignment::right())
+        .to_markdown(),
+    "|   N | column 0 | column 1 |"
+    "| --: | -------: | -------: |"
+    "|   0 |      0-0 |      0-1 |"
+    "|   1 |      1-0 |      1-1 |"
+);
+
+test_table!(
+    markdown_escape,
+    Builder::from(vec![
+        vec![String::from("a|b"), String::from("c")],
+        vec![String::from("1\n2\n3"), String::from("||")],
+    ])
+    .build()
+    .to_markdown(),
+    "| a\\|b        | c    |"
+    "| ----------- | ---- |"
+    "| 1<br>2<br>3 | \\|\\| |"
+);
+
+test_table!(
+    markdown_column_span,
+    Matrix::table(2, 2).modify((1, 1), Span::column(2)).to_markdown(),
+    "|  N  | column 0 | column 1 |"
+    "| :-: | :------: | :------: |"
+    "|  0  |   0-0    |          |"
+    "|  1  |   1-0    |   1-1    |"
+);
+
+test_table!(
+    markdown_row_span,
+    Matrix::table(2, 2).modify((1, 0), Span::row(2)).to_markdown(),
+    "|  N  | column 0 | column 1 |"
+    "| :-: | :------: | :------: |"
+    "|  0  |   0-0    |   0-1    |"
+    "|     |   1-0    |   1-1    |"
+);
+
+test_table!(
+    markdown_header_only,
+    Builder::from(vec![vec![String::from("name"), String::from("a")]]).build().to_markdown(),
+    "| name | a   |"
+    "| ---- | --- |"
+);
+
+test_table!(
+    markdown_wide_chars,
+    Table::new(["😀", "a"]).to_markdown(),
+    "| &str |"
+    "| ---- |"
+    "| 😀   |"
+    "| a    |"
+);
+
+test_table!(markdown_empty, Builder::default().build().to_markdown(), "");
+
+#[cfg(feature = "ansi")]
+test_table!(
+    markdown_strips_ansi,
+    Table::new(["\u{1b}[31mred\u{1b}[39m"]).to_markdown(),
+    "| &str |"
+    "| ---- |"
+    "| red  |"
+);
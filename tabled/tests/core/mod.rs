@@ -1,7 +1,17 @@
+mod asciidoc_test;
 mod builder_test;
 mod compact_table;
+mod csv_test;
 mod extended_table_test;
 mod index_test;
 mod iter_table;
+mod live_table;
+mod markdown_test;
+mod pivot_builder_test;
 mod pool_table;
+mod rst_test;
+mod serde_builder_test;
+mod stream_table;
+mod svg_test;
+mod table_parser_test;
 mod table_test;
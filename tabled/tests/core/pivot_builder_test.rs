@@ -0,0 +1,145 @@
+#![cfg(feature = "std")]
+
+use std::iter::FromIterator;
+
+use tabled::builder::{Builder, PivotBuilder};
+use testing_table::test_table;
+
+test_table!(pivot_empty, PivotBuilder::new().build(), "");
+
+test_table!(
+    pivot_empty_with_corner,
+    {
+        let mut builder = PivotBuilder::new();
+        builder.set_corner("corner");
+        builder.build()
+    },
+    ""
+);
+
+test_table!(
+    pivot_single,
+    PivotBuilder::from_iter([("a", "b", "c")]).build(),
+    "+---+---+"
+    "|   | b |"
+    "+---+---+"
+    "| a | c |"
+    "+---+---+"
+);
+
+test_table!(
+    pivot_order_of_appearance,
+    PivotBuilder::from_iter([
+        ("tabled", "windows", "ok"),
+        ("papergrid", "linux", "failed"),
+        ("tabled", "linux", "ok"),
+        ("json_to_table", "macos", "ok"),
+    ])
+    .build(),
+    "+---------------+---------+--------+-------+"
+    "|               | windows | linux  | macos |"
+    "+---------------+---------+--------+-------+"
+    "| tabled        | ok      | ok     |       |"
+    "+---------------+---------+--------+-------+"
+    "| papergrid     |         | failed |       |"
+    "+---------------+---------+--------+-------+"
+    "| json_to_table |         |        | ok    |"
+    "+---------------+---------+--------+-------+"
+);
+
+test_table!(
+    pivot_corner_and_empty,
+    {
+        let mut builder = PivotBuilder::from_iter([("a", "x", "1"), ("b", "y", "2")]);
+        builder.set_corner("row \\ col");
+        builder.set_empty("-");
+        builder.build()
+    },
+    "+-----------+---+---+"
+    "| row \\ col | x | y |"
+    "+-----------+---+---+"
+    "| a         | 1 | - |"
+    "+-----------+---+---+"
+    "| b         | - | 2 |"
+    "+-----------+---+---+"
+);
+
+test_table!(
+    pivot_collision_last,
+    PivotBuilder::from_iter([("a", "x", "1"), ("a", "x", "2"), ("a", "x", "3")]).build(),
+    "+---+---+"
+    "|   | x |"
+    "+---+---+"
+    "| a | 3 |"
+    "+---+---+"
+);
+
+test_table!(
+    pivot_collision_aggregate,
+    PivotBuilder::from_iter([("a", "x", "1"), ("a", "x", "2"), ("b", "x", "3")])
+        .build_with(|values| values.join(",")),
+    "+---+-----+"
+    "|   | x   |"
+    "+---+-----+"
+    "| a | 1,2 |"
+    "+---+-----+"
+    "| b | 3   |"
+    "+---+-----+"
+);
+
+test_table!(
+    pivot_aggregate_is_not_called_for_empty,
+    {
+        let mut builder = PivotBuilder::from_iter([("a", "x", "1"), ("b", "y", "2")]);
+        builder.set_empty("none");
+        builder.build_with(|values| values.len().to_string())
+    },
+    "+---+------+------+"
+    "|   | x    | y    |"
+    "+---+------+------+"
+    "| a | 1    | none |"
+    "+---+------+------+"
+    "| b | none | 1    |"
+    "+---+------+------+"
+);
+
+test_table!(
+    pivot_extend,
+    {
+        let mut builder = PivotBuilder::new();
+        builder.push_record("a", "x", "1");
+        builder.extend([("b".to_string(), "x".to_string(), "2".to_string())]);
+        builder.extend([("a", "y", "3")]);
+        builder.build()
+    },
+    "+---+---+---+"
+    "|   | x | y |"
+    "+---+---+---+"
+    "| a | 1 | 3 |"
+    "+---+---+---+"
+    "| b | 2 |   |"
+    "+---+---+---+"
+);
+
+test_table!(
+    pivot_into_builder,
+    {
+        let mut builder = Builder::from(PivotBuilder::from_iter([("a", "x", "1")]));
+        builder.push_record(["total", "1"]);
+        builder.build()
+    },
+    "+-------+---+"
+    "|       | x |"
+    "+-------+---+"
+    "| a     | 1 |"
+    "+-------+---+"
+    "| total | 1 |"
+    "+-------+---+"
+);
+
+#[test]
+fn pivot_count() {
+    let builder = PivotBuilder::from_iter([("a", "x", "1"), ("b", "x", "2"), ("a", "x", "3")]);
+    assert_eq!(builder.count_rows(), 2);
+    assert_eq!(builder.count_columns(), 1);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "std")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{object::Cell, Span, Style},
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    rst,
+    Matrix::table(2, 2).to_rst(),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+===+==========+==========+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    rst_ignores_style,
+    Matrix::new(2, 2).with(Style::modern()).to_rst(),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+===+==========+==========+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    rst_column_span,
+    Matrix::table(2, 2).modify(Cell::new(1, 1), Span::column(2)).to_rst(),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+===+==========+==========+"
+    "| 0 | 0-0                 |"
+    "+---+----------+----------+"
+    "| 1 | 1-0      | 1-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    rst_row_span,
+    Matrix::table(3, 2).modify(Cell::new(1, 2), Span::row(2)).to_rst(),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+===+==========+==========+"
+    "| 0 | 0-0      | 0-1      |"
+    "+---+----------+          |"
+    "| 1 | 1-0      |          |"
+    "+---+----------+----------+"
+    "| 2 | 2-0      | 2-1      |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    rst_column_and_row_span,
+    Matrix::table(3, 3)
+        .modify(Cell::new(1, 1), Span::column(2))
+        .modify(Cell::new(1, 1), Span::row(2))
+        .to_rst(),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+===+==========+==========+==========+"
+    "| 0 | 0-0                 | 0-2      |"
+    "+---+                     +----------+"
+    "| 1 |                     | 1-2      |"
+    "+---+----------+----------+----------+"
+    "| 2 | 2-0      | 2-1      | 2-2      |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    rst_wide_spanned_cell,
+    Builder::from_iter([["a", "b"], ["a long spanned cell", ""]])
+        .build()
+        .modify(Cell::new(1, 0), Span::column(2))
+        .to_rst(),
+    "+---+-----------------+"
+    "| a | b               |"
+    "+===+=================+"
+    "| a long spanned cell |"
+    "+---------------------+"
+);
+
+test_table!(
+    rst_tall_spanned_cell,
+    Builder::from_iter([["a", "b"], ["1\n2\n3\n4", "x"], ["", "y"]])
+        .build()
+        .modify(Cell::new(1, 0), Span::row(2))
+        .to_rst(),
+    "+---+---+"
+    "| a | b |"
+    "+===+===+"
+    "| 1 | x |"
+    "| 2 +---+"
+    "| 3 | y |"
+    "| 4 |   |"
+    "+---+---+"
+);
+
+test_table!(
+    rst_header_span,
+    Matrix::table(2, 2).modify(Cell::new(0, 0), Span::column(3)).to_rst(),
+    "+---------------+"
+    "| N             |"
+    "+===+=====+=====+"
+    "| 0 | 0-0 | 0-1 |"
+    "+---+-----+-----+"
+    "| 1 | 1-0 | 1-1 |"
+    "+---+-----+-----+"
+);
+
+test_table!(
+    rst_multiline,
+    Builder::from_iter([["a", "b"], ["multi\nline", "c"]]).build().to_rst(),
+    "+-------+---+"
+    "| a     | b |"
+    "+=======+===+"
+    "| multi | c |"
+    "| line  |   |"
+    "+-------+---+"
+);
+
+test_table!(
+    rst_single_row,
+    Builder::from_iter([["a", "b"]]).build().to_rst(),
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+);
+
+test_table!(rst_empty, Builder::default().build().to_rst(), "");
@@ -0,0 +1,228 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use serde::{ser::Error, Serialize, Serializer};
+use tabled::{builder::SerdeBuilder, Table};
+use testing_table::test_table;
+
+#[derive(Serialize)]
+struct Package {
+    name: &'static str,
+    #[serde(rename = "ver")]
+    version: &'static str,
+    #[serde(skip)]
+    #[allow(dead_code)]
+    hidden: bool,
+    license: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct Host {
+    name: &'static str,
+    address: Address,
+    ports: Vec<u16>,
+}
+
+#[derive(Serialize)]
+struct Address {
+    ip: &'static str,
+    location: Location,
+}
+
+#[derive(Serialize)]
+struct Location {
+    city: &'static str,
+}
+
+#[derive(Serialize)]
+enum State {
+    Running,
+    Failed(i32),
+    Paused { seconds: u64 },
+}
+
+#[derive(Serialize)]
+struct Job {
+    id: usize,
+    state: State,
+}
+
+fn hosts() -> Vec<Host> {
+    vec![
+        Host {
+            name: "a",
+            address: Address {
+                ip: "10.0.0.1",
+                location: Location { city: "Berlin" },
+            },
+            ports: vec![22, 80],
+        },
+        Host {
+            name: "b",
+            address: Address {
+                ip: "10.0.0.2",
+                location: Location { city: "Paris" },
+            },
+            ports: vec![],
+        },
+    ]
+}
+
+test_table!(
+    serde_structs,
+    Table::from_serialize(&[
+        Package { name: "tabled", version: "0.17.0", hidden: true, license: Some("MIT") },
+        Package { name: "unknown", version: "0.0.1", hidden: false, license: None },
+    ])
+    .unwrap(),
+    "+---------+--------+---------+"
+    "| name    | ver    | license |"
+    "+---------+--------+---------+"
+    "| tabled  | 0.17.0 | MIT     |"
+    "+---------+--------+---------+"
+    "| unknown | 0.0.1  |         |"
+    "+---------+--------+---------+"
+);
+
+test_table!(
+    serde_single_struct,
+    Table::from_serialize(&Package { name: "tabled", version: "0.17.0", hidden: true, license: None }).unwrap(),
+    "+--------+--------+---------+"
+    "| name   | ver    | license |"
+    "+--------+--------+---------+"
+    "| tabled | 0.17.0 |         |"
+    "+--------+--------+---------+"
+);
+
+test_table!(
+    serde_nested,
+    Table::from_serialize(&hosts()).unwrap(),
+    "+------+------------+-----------------------+--------+"
+    "| name | address.ip | address.location.city | ports  |"
+    "+------+------------+-----------------------+--------+"
+    "| a    | 10.0.0.1   | Berlin                | 22, 80 |"
+    "+------+------------+-----------------------+--------+"
+    "| b    | 10.0.0.2   | Paris                 |        |"
+    "+------+------------+-----------------------+--------+"
+);
+
+test_table!(
+    serde_nested_separator,
+    SerdeBuilder::new().separator("::").build(&hosts()).unwrap(),
+    "+------+-------------+-------------------------+--------+"
+    "| name | address::ip | address::location::city | ports  |"
+    "+------+-------------+-------------------------+--------+"
+    "| a    | 10.0.0.1    | Berlin                  | 22, 80 |"
+    "+------+-------------+-------------------------+--------+"
+    "| b    | 10.0.0.2    | Paris                   |        |"
+    "+------+-------------+-------------------------+--------+"
+);
+
+test_table!(
+    serde_tuples,
+    Table::from_serialize(&vec![(1, "a", 'c'), (2, "b", 'd')]).unwrap(),
+    "+---+---+---+"
+    "| 0 | 1 | 2 |"
+    "+---+---+---+"
+    "| 1 | a | c |"
+    "+---+---+---+"
+    "| 2 | b | d |"
+    "+---+---+---+"
+);
+
+test_table!(
+    serde_primitives,
+    Table::from_serialize(&[1, 2, 3]).unwrap(),
+    "+---+"
+    "|   |"
+    "+---+"
+    "| 1 |"
+    "+---+"
+    "| 2 |"
+    "+---+"
+    "| 3 |"
+    "+---+"
+);
+
+test_table!(
+    serde_maps,
+    {
+        let mut a = BTreeMap::new();
+        a.insert("x", 1);
+        a.insert("y", 2);
+
+        let mut b = BTreeMap::new();
+        b.insert("y", 3);
+        b.insert("z", 4);
+
+        Table::from_serialize(&[a, b]).unwrap()
+    },
+    "+---+---+---+"
+    "| x | y | z |"
+    "+---+---+---+"
+    "| 1 | 2 |   |"
+    "+---+---+---+"
+    "|   | 3 | 4 |"
+    "+---+---+---+"
+);
+
+test_table!(
+    serde_enums,
+    Table::from_serialize(&[
+        Job { id: 0, state: State::Running },
+        Job { id: 1, state: State::Failed(2) },
+        Job { id: 2, state: State::Paused { seconds: 10 } },
+    ])
+    .unwrap(),
+    "+----+---------+--------------+----------------------+"
+    "| id | state   | state.Failed | state.Paused.seconds |"
+    "+----+---------+--------------+----------------------+"
+    "| 0  | Running |              |                      |"
+    "+----+---------+--------------+----------------------+"
+    "| 1  |         | 2            |                      |"
+    "+----+---------+--------------+----------------------+"
+    "| 2  |         |              | 10                   |"
+    "+----+---------+--------------+----------------------+"
+);
+
+test_table!(
+    serde_nested_lists,
+    {
+        let mut map = BTreeMap::new();
+        map.insert("name", "a");
+
+        #[derive(Serialize)]
+        struct Data {
+            matrix: Vec<Vec<u8>>,
+            maps: Vec<BTreeMap<&'static str, &'static str>>,
+        }
+
+        Table::from_serialize(&Data { matrix: vec![vec![1, 2], vec![3]], maps: vec![map] }).unwrap()
+    },
+    "+-------------+-----------+"
+    "| matrix      | maps      |"
+    "+-------------+-----------+"
+    "| [1, 2], [3] | {name: a} |"
+    "+-------------+-----------+"
+);
+
+test_table!(
+    serde_empty,
+    Table::from_serialize(&Vec::<Package>::new()).unwrap(),
+    ""
+);
+
+#[test]
+fn serde_error() {
+    struct Broken;
+
+    impl Serialize for Broken {
+        fn serialize<S: Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(S::Error::custom("broken value"))
+        }
+    }
+
+    let err = Table::from_serialize(&[Broken]).unwrap_err();
+    assert_eq!(err.to_string(), "broken value");
+}
@@ -0,0 +1,193 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{Alignment, Padding, Style},
+    tables::StreamTable,
+};
+
+use testing_table::test_table;
+
+fn stream(mut table: StreamTable<Vec<u8>>, rows: &[&[&str]]) -> String {
+    for row in rows {
+        table.push(row.iter().copied()).unwrap();
+    }
+
+    let buf = table.finish().unwrap();
+    let text = String::from_utf8(buf).unwrap();
+    text.trim_end_matches('\n').to_string()
+}
+
+const DATA: &[&[&str]] = &[
+    &["name", "status"],
+    &["build", "ok"],
+    &["integration tests", "failed"],
+];
+
+test_table!(
+    stream_table,
+    stream(StreamTable::new(Vec::new()), DATA),
+    "+------+--------+"
+    "| name | status |"
+    "+------+--------+"
+    "| buil | ok     |"
+    "+------+--------+"
+    "| inte | failed |"
+    "+------+--------+"
+);
+
+test_table!(
+    stream_table_sniff,
+    stream(StreamTable::new(Vec::new()).sniff(10), DATA),
+    "+-------------------+--------+"
+    "| name              | status |"
+    "+-------------------+--------+"
+    "| build             | ok     |"
+    "+-------------------+--------+"
+    "| integration tests | failed |"
+    "+-------------------+--------+"
+);
+
+test_table!(
+    stream_table_widths,
+    stream(StreamTable::new(Vec::new()).widths([6, 3]), DATA),
+    "+--------+-----+"
+    "| name   | sta |"
+    "+--------+-----+"
+    "| build  | ok  |"
+    "+--------+-----+"
+    "| integr | fai |"
+    "+--------+-----+"
+);
+
+test_table!(
+    stream_table_wrap,
+    stream(StreamTable::new(Vec::new()).widths([6, 6]).wrap(0), DATA),
+    "+--------+--------+"
+    "| name   | status |"
+    "+--------+--------+"
+    "| build  | ok     |"
+    "+--------+--------+"
+    "| integr | failed |"
+    "| ation  |        |"
+    "| tests  |        |"
+    "+--------+--------+"
+);
+
+test_table!(
+    stream_table_psql,
+    stream(StreamTable::new(Vec::new()).sniff(3).with(Style::psql()), DATA),
+    " name              | status "
+    "-------------------+--------"
+    " build             | ok     "
+    " integration tests | failed "
+);
+
+test_table!(
+    stream_table_markdown,
+    stream(StreamTable::new(Vec::new()).sniff(3).with(Style::markdown()), DATA),
+    "| name              | status |"
+    "|-------------------|--------|"
+    "| build             | ok     |"
+    "| integration tests | failed |"
+);
+
+test_table!(
+    stream_table_rounded,
+    stream(StreamTable::new(Vec::new()).sniff(3).with(Style::rounded()), DATA),
+    "╭───────────────────┬────────╮"
+    "│ name              │ status │"
+    "├───────────────────┼────────┤"
+    "│ build             │ ok     │"
+    "│ integration tests │ failed │"
+    "╰───────────────────┴────────╯"
+);
+
+test_table!(
+    stream_table_blank,
+    stream(StreamTable::new(Vec::new()).sniff(3).with(Style::blank()), DATA),
+    " name                status "
+    " build               ok     "
+    " integration tests   failed "
+);
+
+test_table!(
+    stream_table_padding_and_alignment,
+    stream(
+        StreamTable::new(Vec::new())
+            .widths([5, 6])
+            .with(Padding::new(2, 0, 0, 1))
+            .with(Alignment::right()),
+        &DATA[..2],
+    ),
+    "+-------+--------+"
+    "|   name|  status|"
+    "|       |        |"
+    "+-------+--------+"
+    "|  build|      ok|"
+    "|       |        |"
+    "+-------+--------+"
+);
+
+test_table!(
+    stream_table_uneven_rows,
+    stream(StreamTable::new(Vec::new()).widths([3, 3]), &[&["a"], &["b", "c", "d"]]),
+    "+-----+-----+"
+    "| a   |     |"
+    "+-----+-----+"
+    "| b   | c   |"
+    "+-----+-----+"
+);
+
+test_table!(
+    stream_table_multiline,
+    stream(StreamTable::new(Vec::new()).widths([3]), &[&["a\nlong\nb"]]),
+    "+-----+"
+    "| a   |"
+    "| lon |"
+    "| b   |"
+    "+-----+"
+);
+
+test_table!(
+    stream_table_empty,
+    stream(StreamTable::new(Vec::new()), &[]),
+    ""
+);
+
+#[test]
+fn stream_table_writes_rows_right_away() {
+    let mut table = StreamTable::new(Vec::new()).widths([3, 3]);
+    assert!(table.get_ref().is_empty());
+
+    table.push(["a", "b"]).unwrap();
+    assert_eq!(
+        table.get_ref().as_slice(),
+        b"+-----+-----+\n| a   | b   |\n"
+    );
+    assert_eq!(table.count_rows(), 1);
+
+    table.push(["c", "d"]).unwrap();
+    assert_eq!(
+        table.get_ref().as_slice(),
+        b"+-----+-----+\n| a   | b   |\n+-----+-----+\n| c   | d   |\n"
+    );
+
+    let buf = table.finish().unwrap();
+    assert!(buf.ends_with(b"| c   | d   |\n+-----+-----+\n"));
+}
+
+#[test]
+fn stream_table_sniff_buffers_rows() {
+    let mut table = StreamTable::new(Vec::new()).sniff(2);
+
+    table.push(["a"]).unwrap();
+    assert!(table.get_ref().is_empty());
+    assert_eq!(table.count_rows(), 0);
+
+    table.push(["bbb"]).unwrap();
+    assert_eq!(
+        table.get_ref().as_slice(),
+        b"+-----+\n| a   |\n+-----+\n| bbb |\n"
+    );
+    assert_eq!(table.count_rows(), 2);
+}
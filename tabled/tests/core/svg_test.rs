@@ -0,0 +1,141 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    export::SvgWriter,
+    settings::{style::BorderColor, Color, Style},
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    svg,
+    SvgWriter::new().format(&Matrix::new(1, 1).with(Style::ascii())),
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"128\" height=\"80\" viewBox=\"0 0 128 80\">"
+    "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>"
+    "<g font-family=\"monospace\" font-size=\"14\" fill=\"#000000\" xml:space=\"preserve\">"
+    "<text x=\"0\" y=\"12\" textLength=\"128\" lengthAdjust=\"spacingAndGlyphs\">+---+----------+</text>"
+    "<text x=\"0\" y=\"28\" textLength=\"128\" lengthAdjust=\"spacingAndGlyphs\">| N | column 0 |</text>"
+    "<text x=\"0\" y=\"44\" textLength=\"128\" lengthAdjust=\"spacingAndGlyphs\">+---+----------+</text>"
+    "<text x=\"0\" y=\"60\" textLength=\"128\" lengthAdjust=\"spacingAndGlyphs\">| 0 |   0-0    |</text>"
+    "<text x=\"0\" y=\"76\" textLength=\"128\" lengthAdjust=\"spacingAndGlyphs\">+---+----------+</text>"
+    "</g>"
+    "</svg>"
+);
+
+test_table!(
+    svg_options,
+    SvgWriter::new()
+        .font_family("Fira Code")
+        .font_size(10)
+        .cell_size(6, 12)
+        .foreground("white")
+        .background("#1e1e1e")
+        .format(&Table::new(["a"]).with(Style::blank()).clone()),
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"36\" height=\"24\" viewBox=\"0 0 36 24\">"
+    "<rect width=\"100%\" height=\"100%\" fill=\"#1e1e1e\"/>"
+    "<g font-family=\"Fira Code\" font-size=\"10\" fill=\"white\" xml:space=\"preserve\">"
+    "<text x=\"0\" y=\"9\" textLength=\"36\" lengthAdjust=\"spacingAndGlyphs\"> &amp;str </text>"
+    "<text x=\"0\" y=\"21\" textLength=\"36\" lengthAdjust=\"spacingAndGlyphs\"> a    </text>"
+    "</g>"
+    "</svg>"
+);
+
+test_table!(
+    svg_transparent,
+    SvgWriter::new().transparent().format_text("a"),
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"8\" height=\"16\" viewBox=\"0 0 8 16\">"
+    "<g font-family=\"monospace\" font-size=\"14\" fill=\"#000000\" xml:space=\"preserve\">"
+    "<text x=\"0\" y=\"12\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\">a</text>"
+    "</g>"
+    "</svg>"
+);
+
+test_table!(
+    svg_border_color,
+    SvgWriter::new().format(
+        &Table::new(["a"])
+            .with(Style::ascii())
+            .with(BorderColor::filled(Color::FG_BLUE))
+            .clone()
+    ),
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"64\" height=\"80\" viewBox=\"0 0 64 80\">"
+    "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>"
+    "<g font-family=\"monospace\" font-size=\"14\" fill=\"#000000\" xml:space=\"preserve\">"
+    "<text x=\"0\" y=\"12\" textLength=\"64\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#0000ee\">+------+</text>"
+    "<text x=\"0\" y=\"28\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#0000ee\">|</text>"
+    "<text x=\"8\" y=\"28\" textLength=\"48\" lengthAdjust=\"spacingAndGlyphs\"> &amp;str </text>"
+    "<text x=\"56\" y=\"28\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#0000ee\">|</text>"
+    "<text x=\"0\" y=\"44\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#0000ee\">+</text>"
+    "<text x=\"8\" y=\"44\" textLength=\"48\" lengthAdjust=\"spacingAndGlyphs\">------</text>"
+    "<text x=\"56\" y=\"44\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#0000ee\">+</text>"
+    "<text x=\"0\" y=\"60\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#0000ee\">|</text>"
+    "<text x=\"8\" y=\"60\" textLength=\"48\" lengthAdjust=\"spacingAndGlyphs\"> a    </text>"
+    "<text x=\"56\" y=\"60\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#0000ee\">|</text>"
+    "<text x=\"0\" y=\"76\" textLength=\"64\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#0000ee\">+------+</text>"
+    "</g>"
+    "</svg>"
+);
+
+test_table!(
+    svg_ansi_styles,
+    SvgWriter::new().transparent().format_text(
+        "\u{1b}[1;31mab\u{1b}[0m\u{1b}[2;3mc\u{1b}[22;23m\u{1b}[4;9md\u{1b}[24;29m\u{1b}[38;5;208me\u{1b}[38;2;1;2;3mf\u{1b}[39m"
+    ),
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"48\" height=\"16\" viewBox=\"0 0 48 16\">"
+    "<g font-family=\"monospace\" font-size=\"14\" fill=\"#000000\" xml:space=\"preserve\">"
+    "<text x=\"0\" y=\"12\" textLength=\"16\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#cd0000\" font-weight=\"bold\">ab</text>"
+    "<text x=\"16\" y=\"12\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" font-style=\"italic\" opacity=\"0.5\">c</text>"
+    "<text x=\"24\" y=\"12\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" text-decoration=\"underline line-through\">d</text>"
+    "<text x=\"32\" y=\"12\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#ff8700\">e</text>"
+    "<text x=\"40\" y=\"12\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#010203\">f</text>"
+    "</g>"
+    "</svg>"
+);
+
+test_table!(
+    svg_background,
+    SvgWriter::new().transparent().format_text("\u{1b}[44m  \u{1b}[49m\u{1b}[7m\u{1b}[92mx\u{1b}[0m"),
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"24\" height=\"16\" viewBox=\"0 0 24 16\">"
+    "<g font-family=\"monospace\" font-size=\"14\" fill=\"#000000\" xml:space=\"preserve\">"
+    "<rect x=\"0\" y=\"0\" width=\"16\" height=\"16\" fill=\"#0000ee\"/>"
+    "<rect x=\"16\" y=\"0\" width=\"8\" height=\"16\" fill=\"#00ff00\"/>"
+    "<text x=\"16\" y=\"12\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#ffffff\">x</text>"
+    "</g>"
+    "</svg>"
+);
+
+test_table!(
+    svg_skips_unsupported_sequences,
+    SvgWriter::new()
+        .transparent()
+        .format_text("\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{7}\u{1b}[2Jx"),
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"40\" height=\"16\" viewBox=\"0 0 40 16\">"
+    "<g font-family=\"monospace\" font-size=\"14\" fill=\"#000000\" xml:space=\"preserve\">"
+    "<text x=\"0\" y=\"12\" textLength=\"40\" lengthAdjust=\"spacingAndGlyphs\">linkx</text>"
+    "</g>"
+    "</svg>"
+);
+
+test_table!(
+    svg_wide_chars,
+    SvgWriter::new().transparent().format_text("😀a\nb"),
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"24\" height=\"32\" viewBox=\"0 0 24 32\">"
+    "<g font-family=\"monospace\" font-size=\"14\" fill=\"#000000\" xml:space=\"preserve\">"
+    "<text x=\"0\" y=\"12\" textLength=\"24\" lengthAdjust=\"spacingAndGlyphs\">😀a</text>"
+    "<text x=\"0\" y=\"28\" textLength=\"8\" lengthAdjust=\"spacingAndGlyphs\">b</text>"
+    "</g>"
+    "</svg>"
+);
+
+test_table!(
+    svg_empty,
+    SvgWriter::new().format(&Table::new(Vec::<String>::new()).with(Style::empty()).clone()),
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"64\" height=\"16\" viewBox=\"0 0 64 16\">"
+    "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>"
+    "<g font-family=\"monospace\" font-size=\"14\" fill=\"#000000\" xml:space=\"preserve\">"
+    "<text x=\"0\" y=\"12\" textLength=\"64\" lengthAdjust=\"spacingAndGlyphs\"> String </text>"
+    "</g>"
+    "</svg>"
+);
@@ -0,0 +1,197 @@
+#![cfg(feature = "std")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::{Builder, ParseError, TableParser},
+    settings::{Span, Style},
+    Table,
+};
+use testing_table::test_table;
+
+fn data() -> Vec<Vec<String>> {
+    vec![
+        vec!["name".into(), "version".into(), "description".into()],
+        vec!["tabled".into(), "0.17.0".into(), "pretty tables".into()],
+        vec!["papergrid".into(), "".into(), "a grid\nengine".into()],
+    ]
+}
+
+fn records(table: &Table) -> Vec<Vec<String>> {
+    Builder::from(table.clone()).into()
+}
+
+macro_rules! test_round_trip {
+    ($name:ident, $style:expr, $data:expr) => {
+        #[test]
+        fn $name() {
+            let mut table = Builder::from($data).build();
+            table.with($style);
+            let text = table.to_string();
+
+            let parsed = TableParser::new($style).parse(&text).unwrap();
+
+            assert_eq!(records(&parsed), $data);
+            assert_eq!(parsed.to_string(), text);
+        }
+    };
+}
+
+test_round_trip!(parse_ascii, Style::ascii(), data());
+test_round_trip!(parse_modern, Style::modern(), data());
+test_round_trip!(parse_rounded, Style::rounded(), data()[..2].to_vec());
+test_round_trip!(parse_extended, Style::extended(), data());
+test_round_trip!(parse_psql, Style::psql(), data()[..2].to_vec());
+test_round_trip!(parse_markdown, Style::markdown(), data()[..2].to_vec());
+test_round_trip!(parse_rst, Style::re_structured_text(), data()[..2].to_vec());
+
+#[test]
+fn parse_rows_without_lines() {
+    let text = concat!(
+        " name      | version \n",
+        "-----------+---------\n",
+        " tabled    | 0.17.0  \n",
+        " papergrid |         \n",
+    );
+
+    let table = TableParser::new(Style::psql()).parse(text).unwrap();
+
+    assert_eq!(
+        records(&table),
+        [["name", "version"], ["tabled", "0.17.0"], ["papergrid", ""],]
+    );
+}
+
+#[test]
+fn parse_trimmed_lines() {
+    let text = concat!(
+        "| name   | version |\n",
+        "|--------|---------|\n",
+        "| tabled |         |\n",
+    );
+
+    let text = text
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let table = TableParser::new(Style::markdown()).parse(&text).unwrap();
+
+    assert_eq!(records(&table), [["name", "version"], ["tabled", ""]]);
+}
+
+#[test]
+fn parse_column_span() {
+    let mut table = Builder::from(data()).build();
+    table.with(Style::modern());
+    table.modify((1, 0), Span::column(2));
+    table.modify((2, 1), Span::column(2));
+    let text = table.to_string();
+
+    let parsed = TableParser::new(Style::modern()).parse(&text).unwrap();
+
+    assert_eq!(parsed.get_config().get_column_span((1, 0)), Some(2));
+    assert_eq!(parsed.get_config().get_column_span((2, 1)), Some(2));
+    assert_eq!(
+        records(&parsed),
+        [
+            ["name", "version", "description"],
+            ["tabled", "", "pretty tables"],
+            ["papergrid", "", ""],
+        ]
+    );
+    assert_eq!(parsed.to_string(), text);
+}
+
+#[test]
+fn parse_wide_chars() {
+    let data = vec![vec!["名前", "値"], vec!["😀", "ok"]];
+    let mut table = Builder::from_iter(data.clone()).build();
+    table.with(Style::ascii());
+    let text = table.to_string();
+
+    let parsed = TableParser::new(Style::ascii()).parse(&text).unwrap();
+
+    assert_eq!(records(&parsed), data);
+}
+
+#[cfg(feature = "ansi")]
+#[test]
+fn parse_colored() {
+    use tabled::settings::{object::Rows, Color};
+
+    let mut table = Builder::from(data()).build();
+    table.with(Style::ascii());
+    table.modify(Rows::first(), Color::FG_RED);
+
+    let parsed = TableParser::new(Style::ascii())
+        .parse(&table.to_string())
+        .unwrap();
+
+    assert_eq!(records(&parsed), data());
+}
+
+test_table!(
+    parse_and_restyle,
+    {
+        let text = concat!(
+            "+------+-------+\n",
+            "| name | value |\n",
+            "+------+-------+\n",
+            "| a    | 1     |\n",
+            "+------+-------+\n",
+        );
+
+        let mut table = TableParser::new(Style::ascii()).parse(text).unwrap();
+        table.with(Style::rounded());
+        table
+    },
+    "╭──────┬───────╮"
+    "│ name │ value │"
+    "├──────┼───────┤"
+    "│ a    │ 1     │"
+    "╰──────┴───────╯"
+);
+
+test_table!(
+    parse_empty,
+    TableParser::new(Style::ascii()).parse("").unwrap(),
+    ""
+);
+
+#[test]
+fn parse_error_line_too_wide() {
+    let text = concat!(
+        "+------+-------+\n",
+        "| name | value |\n",
+        "+------+-------+\n",
+        "| a    | 1        |\n",
+        "+------+-------+\n",
+    );
+
+    let err = TableParser::new(Style::ascii()).parse(text).unwrap_err();
+
+    assert_eq!(err.line(), 3);
+    assert_eq!(err.to_string(), "line 3 doesn't match the table layout");
+}
+
+#[test]
+fn parse_error_missing_border() {
+    let text = concat!(
+        "+------+-------+\n",
+        "| name | value |\n",
+        "+------+-------+\n",
+        "| a    | 1      \n",
+        "+------+-------+\n",
+    );
+
+    let err = TableParser::new(Style::ascii()).parse(text).unwrap_err();
+
+    assert_eq!(
+        err,
+        TableParser::new(Style::ascii()).parse(text).unwrap_err()
+    );
+    assert_eq!(err.line(), 3);
+
+    let _: &dyn std::error::Error = &err as &ParseError;
+}
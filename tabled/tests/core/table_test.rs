@@ -747,6 +747,132 @@ mod derived {
         "| Keep it simple                     | Unknown         | 🍳                             | 100    |"
         "+------------------------------------+-----------------+--------------------------------+--------+"
     );
+
+    test_table!(
+        table_column_styles,
+        Table::new({
+            #[derive(Tabled)]
+            struct Package {
+                #[tabled(align = "center")]
+                name: &'static str,
+                #[tabled(max_width = 6)]
+                description: &'static str,
+                #[tabled(align = "right")]
+                downloads: usize,
+            }
+
+            vec![
+                Package { name: "tabled", description: "A table library", downloads: 1000 },
+                Package { name: "gd", description: "Go", downloads: 1 },
+            ]
+        }),
+        "+--------+-------------+-----------+"
+        "| name   | description | downloads |"
+        "+--------+-------------+-----------+"
+        "| tabled | A tabl      |      1000 |"
+        "+--------+-------------+-----------+"
+        "|   gd   | Go          |         1 |"
+        "+--------+-------------+-----------+"
+    );
+
+    test_table!(
+        table_column_styles_wrap,
+        Table::new({
+            #[derive(Tabled)]
+            struct Package {
+                name: &'static str,
+                #[tabled(max_width = 6, wrap)]
+                description: &'static str,
+            }
+
+            vec![Package { name: "tabled", description: "A table library" }]
+        }),
+        "+--------+-------------+"
+        "| name   | description |"
+        "+--------+-------------+"
+        "| tabled | A tabl      |"
+        "|        | e libr      |"
+        "|        | ary         |"
+        "+--------+-------------+"
+    );
+
+    test_table!(
+        table_column_styles_follow_order,
+        Table::new({
+            #[derive(Tabled)]
+            struct Package {
+                #[tabled(align = "right", order = 1)]
+                downloads: usize,
+                name: &'static str,
+            }
+
+            vec![Package { downloads: 1000, name: "tabled" }, Package { downloads: 1, name: "gd" }]
+        }),
+        "+--------+-----------+"
+        "| name   | downloads |"
+        "+--------+-----------+"
+        "| tabled |      1000 |"
+        "+--------+-----------+"
+        "| gd     |         1 |"
+        "+--------+-----------+"
+    );
+
+    test_table!(
+        table_column_styles_inline,
+        Table::new({
+            #[derive(Tabled)]
+            struct Package {
+                name: &'static str,
+                #[tabled(inline)]
+                stats: Stats,
+            }
+
+            #[derive(Tabled)]
+            struct Stats {
+                #[tabled(align = "right")]
+                downloads: usize,
+                #[tabled(skip)]
+                #[allow(dead_code)]
+                id: usize,
+                #[tabled(align = "center")]
+                stars: usize,
+            }
+
+            vec![
+                Package { name: "tabled", stats: Stats { downloads: 1000, id: 0, stars: 1 } },
+                Package { name: "gd", stats: Stats { downloads: 1, id: 1, stars: 100 } },
+            ]
+        }),
+        "+--------+-----------+-------+"
+        "| name   | downloads | stars |"
+        "+--------+-----------+-------+"
+        "| tabled |      1000 |   1   |"
+        "+--------+-----------+-------+"
+        "| gd     |         1 |  100  |"
+        "+--------+-----------+-------+"
+    );
+
+    test_table!(
+        table_column_styles_enum,
+        Table::new({
+            #[derive(Tabled)]
+            enum Status {
+                #[tabled(align = "center")]
+                Ok,
+                #[tabled(inline)]
+                Failed(#[tabled(rename = "code", align = "right")] u16),
+            }
+
+            vec![Status::Ok, Status::Failed(404)]
+        }),
+        "+----+------+"
+        "| Ok | code |"
+        "+----+------+"
+        "| +  |      |"
+        "+----+------+"
+        "|    |  404 |"
+        "+----+------+"
+    );
 }
 
 #[cfg(feature = "ansi")]
@@ -903,3 +1029,51 @@ test_table!(
     " ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
     " ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
 );
+
+#[test]
+fn table_to_string_with_layout() {
+    use tabled::settings::{Margin, Span};
+
+    let mut table = Matrix::table(3, 3);
+    table
+        .with(Style::modern())
+        .with(Margin::new(2, 0, 1, 0))
+        .modify((1, 1), Span::column(2))
+        .modify((2, 0), Span::row(2));
+
+    let (text, layout) = table.to_string_with_layout();
+
+    assert_eq!(text, table.to_string());
+
+    let lines = text.lines().collect::<Vec<_>>();
+    for cell in layout.cells() {
+        let rect = cell.text;
+        let content = lines[rect.lines()]
+            .iter()
+            .map(|line| {
+                line.chars()
+                    .skip(rect.column)
+                    .take(rect.width)
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (row, col) = cell.position;
+        let expected = table.get_records()[row][col].as_ref().to_string();
+        assert_eq!(content.trim(), expected, "{:?}", cell.position);
+    }
+
+    assert_eq!(layout.cells().len(), 14);
+    assert_eq!(layout.get_cell((1, 2)), None);
+    assert_eq!(layout.find_cell(0, 5), None);
+    assert_eq!(layout.find_cell(4, 4).unwrap().position, (1, 0));
+}
+
+#[test]
+fn table_to_string_with_layout_empty() {
+    let (text, layout) = Builder::default().build().to_string_with_layout();
+
+    assert_eq!(text, "");
+    assert!(layout.cells().is_empty());
+}
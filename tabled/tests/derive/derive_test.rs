@@ -241,6 +241,21 @@ mod tuple {
         }
     );
 
+    test_tuple!(
+        display_option_with_format,
+        { { u8 #[tabled(display_with = "display_option", format = "foo {}")] Option<sstr> } },
+        { 0 Some("v2") },
+        { ["0", "1"], ["0", "some v2"] },
+        pre: {
+            fn display_option(o: &Option<sstr>) -> String {
+                match o {
+                    Some(s) => format!("some {s}"),
+                    None => "none".to_string(),
+                }
+            }
+        }
+    );
+
     test_tuple!(format_1, { { u8 #[tabled(format = "foo {}")] sstr } },                                         { 0 "v2" },                     { ["0", "1"], ["0", "foo v2"] });
     test_tuple!(format_2, { { u8 #[tabled(format = "foo {:?}")] sstr } },                                       { 0 "v2" },                     { ["0", "1"], ["0", "foo \"v2\""] });
     // todo : self represents the tuple here. It should be the sstr element instead.
@@ -1630,6 +1645,341 @@ fn test_enum_format_1() {
     }
 }
 
+#[test]
+fn test_groups() {
+    #[derive(Tabled)]
+    struct Package {
+        name: &'static str,
+        #[tabled(group = "verbose")]
+        license: &'static str,
+        #[tabled(group = "verbose", group = "debug")]
+        size: usize,
+        version: &'static str,
+    }
+
+    let package = Package {
+        name: "tabled",
+        license: "MIT",
+        size: 100,
+        version: "0.17.0",
+    };
+
+    assert_eq!(Package::headers(), ["name", "license", "size", "version"]);
+    assert_eq!(package.fields(), ["tabled", "MIT", "100", "0.17.0"]);
+
+    assert_eq!(Package::headers_for(&[]), ["name", "version"]);
+    assert_eq!(package.fields_for(&[]), ["tabled", "0.17.0"]);
+
+    assert_eq!(
+        Package::headers_for(&["verbose"]),
+        ["name", "license", "size", "version"]
+    );
+    assert_eq!(
+        package.fields_for(&["verbose"]),
+        ["tabled", "MIT", "100", "0.17.0"]
+    );
+
+    assert_eq!(
+        Package::headers_for(&["debug"]),
+        ["name", "size", "version"]
+    );
+    assert_eq!(package.fields_for(&["debug"]), ["tabled", "100", "0.17.0"]);
+
+    assert_eq!(Package::headers_for(&["unknown"]), ["name", "version"]);
+    assert_eq!(package.fields_for(&["unknown"]), ["tabled", "0.17.0"]);
+}
+
+#[test]
+fn test_groups_with_other_attributes() {
+    #[allow(dead_code)]
+    #[derive(Tabled)]
+    #[tabled(rename_all = "UPPERCASE")]
+    struct Package {
+        #[tabled(skip)]
+        id: usize,
+        #[tabled(order = 2)]
+        name: &'static str,
+        #[tabled(group = "verbose", rename = "License")]
+        license: &'static str,
+        #[tabled(group = "verbose", format("{} KiB", self.size))]
+        size: usize,
+    }
+
+    let package = Package {
+        id: 0,
+        name: "tabled",
+        license: "MIT",
+        size: 100,
+    };
+
+    assert_eq!(Package::headers_for(&[]), ["NAME"]);
+    assert_eq!(package.fields_for(&[]), ["tabled"]);
+    assert_eq!(
+        Package::headers_for(&["verbose"]),
+        ["License", "SIZE", "NAME"]
+    );
+    assert_eq!(
+        package.fields_for(&["verbose"]),
+        ["MIT", "100 KiB", "tabled"]
+    );
+}
+
+#[test]
+fn test_groups_inline() {
+    #[derive(Tabled)]
+    struct Package {
+        name: &'static str,
+        #[tabled(inline("author."))]
+        author: Author,
+        #[tabled(inline, group = "verbose")]
+        repository: Repository,
+    }
+
+    #[derive(Tabled)]
+    struct Author {
+        name: &'static str,
+        #[tabled(group = "verbose")]
+        email: &'static str,
+    }
+
+    #[derive(Tabled)]
+    struct Repository {
+        url: &'static str,
+    }
+
+    let package = Package {
+        name: "tabled",
+        author: Author {
+            name: "zhiburt",
+            email: "zhiburt@gmail.com",
+        },
+        repository: Repository {
+            url: "https://github.com/zhiburt/tabled",
+        },
+    };
+
+    assert_eq!(Package::headers_for(&[]), ["name", "author.name"]);
+    assert_eq!(package.fields_for(&[]), ["tabled", "zhiburt"]);
+    assert_eq!(
+        Package::headers_for(&["verbose"]),
+        ["name", "author.name", "author.email", "url"]
+    );
+    assert_eq!(
+        package.fields_for(&["verbose"]),
+        [
+            "tabled",
+            "zhiburt",
+            "zhiburt@gmail.com",
+            "https://github.com/zhiburt/tabled"
+        ]
+    );
+}
+
+#[test]
+fn test_groups_in_tuple() {
+    #[derive(Tabled)]
+    struct Package {
+        name: &'static str,
+        #[tabled(group = "verbose")]
+        license: &'static str,
+    }
+
+    let value = (
+        &Package {
+            name: "tabled",
+            license: "MIT",
+        },
+        1,
+    );
+
+    assert_eq!(<(&Package, i32)>::headers_for(&[]), ["name", "i32"]);
+    assert_eq!(value.fields_for(&[]), ["tabled", "1"]);
+    assert_eq!(
+        <(&Package, i32)>::headers_for(&["verbose"]),
+        ["name", "license", "i32"]
+    );
+    assert_eq!(value.fields_for(&["verbose"]), ["tabled", "MIT", "1"]);
+}
+
+#[test]
+fn test_column_styles() {
+    use tabled::{
+        grid::config::AlignmentHorizontal,
+        settings::{Color, ColumnStyle},
+    };
+
+    #[derive(Tabled)]
+    struct Package {
+        #[tabled(align = "left", color = "red bold")]
+        name: &'static str,
+        #[tabled(skip)]
+        #[allow(dead_code)]
+        id: usize,
+        #[tabled(max_width = 10, wrap)]
+        description: &'static str,
+        version: &'static str,
+        #[tabled(color = "bg_bright_blue", order = 0)]
+        license: &'static str,
+    }
+
+    assert_eq!(
+        Package::column_styles(),
+        [
+            ColumnStyle::new().color(Color::BG_BRIGHT_BLUE),
+            ColumnStyle::new()
+                .alignment(AlignmentHorizontal::Left)
+                .color(Color::FG_RED | Color::BOLD),
+            ColumnStyle::new().max_width(10).wrap(true),
+            ColumnStyle::new(),
+        ]
+    );
+}
+
+#[test]
+fn test_column_styles_default() {
+    use tabled::settings::ColumnStyle;
+
+    #[derive(Tabled)]
+    struct Package {
+        name: &'static str,
+        version: &'static str,
+    }
+
+    assert_eq!(
+        Package::column_styles(),
+        [ColumnStyle::new(), ColumnStyle::new()]
+    );
+    assert_eq!(
+        <(Package, usize)>::column_styles(),
+        [ColumnStyle::new(), ColumnStyle::new(), ColumnStyle::new()]
+    );
+}
+
+#[test]
+fn test_option() {
+    #[derive(Tabled)]
+    struct Package {
+        name: &'static str,
+        #[tabled(option = "-")]
+        license: Option<&'static str>,
+        #[tabled(option)]
+        stars: Option<usize>,
+    }
+
+    let package = Package {
+        name: "tabled",
+        license: Some("MIT"),
+        stars: Some(100),
+    };
+    assert_eq!(package.fields(), ["tabled", "MIT", "100"]);
+
+    let package = Package {
+        name: "tabled",
+        license: None,
+        stars: None,
+    };
+    assert_eq!(package.fields(), ["tabled", "-", ""]);
+    assert_eq!(Package::headers(), ["name", "license", "stars"]);
+}
+
+#[test]
+fn test_join() {
+    #[derive(Tabled)]
+    struct Package {
+        name: &'static str,
+        #[tabled(join = ", ")]
+        keywords: Vec<&'static str>,
+        #[tabled(join = "|")]
+        versions: [u8; 3],
+    }
+
+    let package = Package {
+        name: "tabled",
+        keywords: vec!["table", "print"],
+        versions: [1, 2, 3],
+    };
+    assert_eq!(package.fields(), ["tabled", "table, print", "1|2|3"]);
+
+    let package = Package {
+        name: "tabled",
+        keywords: vec![],
+        versions: [0, 0, 0],
+    };
+    assert_eq!(package.fields(), ["tabled", "", "0|0|0"]);
+}
+
+#[test]
+fn test_table() {
+    #[derive(Tabled)]
+    struct Package {
+        name: &'static str,
+        #[tabled(table)]
+        authors: Vec<Author>,
+    }
+
+    #[derive(Tabled)]
+    struct Author {
+        name: &'static str,
+        email: &'static str,
+    }
+
+    let package = Package {
+        name: "tabled",
+        authors: vec![Author {
+            name: "zhiburt",
+            email: "zhiburt@gmail.com",
+        }],
+    };
+
+    assert_eq!(
+        package.fields(),
+        [
+            "tabled",
+            "+---------+-------------------+\n\
+             | name    | email             |\n\
+             +---------+-------------------+\n\
+             | zhiburt | zhiburt@gmail.com |\n\
+             +---------+-------------------+"
+        ]
+    );
+}
+
+#[test]
+fn test_option_join_table_in_enum() {
+    #[derive(Tabled)]
+    enum Release {
+        #[tabled(inline)]
+        Stable {
+            #[tabled(option = "none")]
+            notes: Option<String>,
+            #[tabled(join = " ")]
+            tags: Vec<String>,
+        },
+        #[tabled(inline)]
+        Nightly(#[tabled(rename = "changes", table)] Vec<(usize, &'static str)>),
+    }
+
+    let release = Release::Stable {
+        notes: None,
+        tags: vec![String::from("a"), String::from("b")],
+    };
+    assert_eq!(release.fields(), ["none", "a b", ""]);
+
+    let release = Release::Nightly(vec![(1, "fix")]);
+    assert_eq!(
+        release.fields(),
+        [
+            "",
+            "",
+            "+-------+------+\n\
+             | usize | &str |\n\
+             +-------+------+\n\
+             | 1     | fix  |\n\
+             +-------+------+"
+        ]
+    );
+}
+
 mod __ {
     #[test]
     fn dont_import_the_trait() {
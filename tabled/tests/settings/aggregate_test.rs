@@ -0,0 +1,265 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        aggregate::NonNumeric,
+        location::{ByColumnName, Locator},
+        object::Columns,
+        style::HorizontalLine,
+        Aggregate, Panel, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+fn costs() -> Table {
+    let mut builder = Builder::default();
+    builder.push_record(["service", "region", "requests", "cost"]);
+    builder.push_record(["auth", "eu", "1200", "3.5"]);
+    builder.push_record(["search", "us", "5400", "12.25"]);
+    builder.push_record(["billing", "eu", "300", "n/a"]);
+    builder.push_record(["mail", "us", "", "1"]);
+
+    let mut table = builder.build();
+    table.with(Style::psql());
+    table
+}
+
+test_table!(
+    test_0x0_aggregate,
+    Matrix::empty().with(Aggregate::sum(Columns::new(..))),
+    ""
+);
+
+test_table!(
+    test_aggregate_sum,
+    costs().with(Aggregate::sum(Columns::new(2..)).label("Total")),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    " Total   |        | 6900     | 16.75 "
+);
+
+test_table!(
+    test_aggregate_by_column_name,
+    costs().with(Aggregate::sum(Locator::column("requests"))),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    "         |        | 6900     |       "
+);
+
+test_table!(
+    test_aggregate_mean_precision,
+    costs().with(Aggregate::mean(Columns::new(2..)).precision(2).label("Mean")),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    " Mean    |        | 2300.00  | 5.58  "
+);
+
+test_table!(
+    test_aggregate_min_max,
+    costs()
+        .with(Aggregate::min(Columns::new(2..)).label("Min"))
+        .with(Aggregate::max(Columns::new(2..)).label("Max").footer(1)),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    " Min     |        | 300      | 1     "
+    " Max     |        | 5400     | 12.25 "
+);
+
+test_table!(
+    test_aggregate_count,
+    costs().with(Aggregate::count(Columns::new(..)).label_at(1, "count")),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    " 4       | count  | 3        | 4     "
+);
+
+test_table!(
+    test_aggregate_distinct,
+    costs().with(Aggregate::distinct(Columns::single(1))),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    "         | 2      |          |       "
+);
+
+test_table!(
+    test_aggregate_non_numeric_zero,
+    costs().with(Aggregate::mean(Columns::single(3)).non_numeric(NonNumeric::Zero)),
+    " service | region | requests | cost   "
+    "---------+--------+----------+--------"
+    " auth    | eu     | 1200     | 3.5    "
+    " search  | us     | 5400     | 12.25  "
+    " billing | eu     | 300      | n/a    "
+    " mail    | us     |          | 1      "
+    "         |        |          | 4.1875 "
+);
+
+test_table!(
+    test_aggregate_non_numeric_fail,
+    costs().with(
+        Aggregate::sum(Columns::new(2..))
+            .non_numeric(NonNumeric::Fail)
+            .placeholder("-")
+    ),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    "         |        | 6900     | -     "
+);
+
+test_table!(
+    test_aggregate_placeholder,
+    costs().with(Aggregate::max(Columns::new(..2)).placeholder("?")),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    " ?       | ?      |          |       "
+);
+
+test_table!(
+    test_aggregate_closure,
+    costs().with(Aggregate::by(Columns::single(2), |numbers| numbers.len() as f64 * 10.0)),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    "         |        | 30       |       "
+);
+
+test_table!(
+    test_aggregate_not_existing_column,
+    costs().with(Aggregate::sum(ByColumnName::new("xxx")).label("Total")),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    " Total   |        |          |       "
+);
+
+test_table!(
+    test_aggregate_no_header,
+    Matrix::iter([(1, 2), (3, 4)]).with(Aggregate::sum(Columns::new(..)).header(0)),
+    "+-----+-----+"
+    "| i32 | i32 |"
+    "+-----+-----+"
+    "|  1  |  2  |"
+    "+-----+-----+"
+    "|  3  |  4  |"
+    "+-----+-----+"
+    "|  4  |  6  |"
+    "+-----+-----+"
+);
+
+test_table!(
+    test_aggregate_skips_panels,
+    Matrix::iter([(1, 2), (3, 4)])
+        .with(Panel::header("1000"))
+        .with(Panel::footer("1000"))
+        .with(Aggregate::sum(Columns::new(..))),
+    "+-----+-----+"
+    "|   1000    |"
+    "+-----+-----+"
+    "| i32 | i32 |"
+    "+-----+-----+"
+    "|  1  |  2  |"
+    "+-----+-----+"
+    "|  3  |  4  |"
+    "+-----+-----+"
+    "|   1000    |"
+    "+-----+-----+"
+    "|  4  |  6  |"
+    "+-----+-----+"
+);
+
+test_table!(
+    test_aggregate_separator,
+    Matrix::iter([(1, 2), (3, 4)])
+        .with(Style::ascii().remove_horizontal().horizontals([(1, HorizontalLine::filled('-'))]))
+        .with(Aggregate::sum(Columns::new(..)).separator(HorizontalLine::filled('=')))
+        .with(Aggregate::mean(Columns::new(..)).footer(1)),
+    "+-----+-----+"
+    "| i32 | i32 |"
+    "-------------"
+    "|  1  |  2  |"
+    "|  3  |  4  |"
+    "============="
+    "|  4  |  6  |"
+    "|  2  |  3  |"
+    "+-----+-----+"
+);
+
+test_table!(
+    test_aggregate_separator_intersection,
+    costs().with(
+        Aggregate::sum(Columns::single(2))
+            .label("Total")
+            .separator(HorizontalLine::new('-').intersection('+'))
+    ),
+    " service | region | requests | cost  "
+    "---------+--------+----------+-------"
+    " auth    | eu     | 1200     | 3.5   "
+    " search  | us     | 5400     | 12.25 "
+    " billing | eu     | 300      | n/a   "
+    " mail    | us     |          | 1     "
+    "---------+--------+----------+-------"
+    " Total   |        | 6900     |       "
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    test_aggregate_colored,
+    {
+        use owo_colors::OwoColorize;
+
+        let data = [("a", 1.red().to_string()), ("b", 2.blue().to_string())];
+        let mut table = Matrix::iter(data);
+        table.with(Aggregate::sum(Columns::single(1)));
+        table
+    },
+    "+------+--------+"
+    "| &str | String |"
+    "+------+--------+"
+    "|  a   |   \u{1b}[31m1\u{1b}[39m    |"
+    "+------+--------+"
+    "|  b   |   \u{1b}[34m2\u{1b}[39m    |"
+    "+------+--------+"
+    "|      |   3    |"
+    "+------+--------+"
+);
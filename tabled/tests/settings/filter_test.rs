@@ -0,0 +1,218 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        location::{ByColumnName, Locator},
+        object::{Columns, Rows},
+        style::HorizontalLine,
+        Alignment, Filter, Modify, Padding, Panel, Span, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+fn jobs() -> Table {
+    let mut builder = Builder::default();
+    builder.push_record(["job", "status", "time"]);
+    builder.push_record(["build", "ok", "10"]);
+    builder.push_record(["test", "failed", "120"]);
+    builder.push_record(["lint", "ok", "3"]);
+    builder.push_record(["deploy", "failed", "50"]);
+    builder.build()
+}
+
+test_table!(
+    test_0x0_filter,
+    Matrix::empty().with(Filter::column(Columns::new(..), |_| false)),
+    ""
+);
+
+test_table!(
+    test_filter_by_column_name,
+    jobs().with(Filter::column(Locator::column("status"), |text| text == "failed")),
+    "+--------+--------+------+"
+    "| job    | status | time |"
+    "+--------+--------+------+"
+    "| test   | failed | 120  |"
+    "+--------+--------+------+"
+    "| deploy | failed | 50   |"
+    "+--------+--------+------+"
+);
+
+test_table!(
+    test_filter_by_column_index,
+    jobs().with(Filter::column(Columns::single(2), |text| text.parse::<usize>().unwrap() < 20)),
+    "+-------+--------+------+"
+    "| job   | status | time |"
+    "+-------+--------+------+"
+    "| build | ok     | 10   |"
+    "+-------+--------+------+"
+    "| lint  | ok     | 3    |"
+    "+-------+--------+------+"
+);
+
+test_table!(
+    test_filter_any_of_columns,
+    jobs().with(Filter::column(Columns::new(..), |text| text.starts_with('t'))),
+    "+------+--------+------+"
+    "| job  | status | time |"
+    "+------+--------+------+"
+    "| test | failed | 120  |"
+    "+------+--------+------+"
+);
+
+test_table!(
+    test_filter_all,
+    jobs().with(Filter::column(Columns::new(..), |_| false)),
+    "+-----+--------+------+"
+    "| job | status | time |"
+    "+-----+--------+------+"
+);
+
+test_table!(
+    test_filter_none,
+    jobs().with(Filter::column(Columns::new(..), |_| true)),
+    "+--------+--------+------+"
+    "| job    | status | time |"
+    "+--------+--------+------+"
+    "| build  | ok     | 10   |"
+    "+--------+--------+------+"
+    "| test   | failed | 120  |"
+    "+--------+--------+------+"
+    "| lint   | ok     | 3    |"
+    "+--------+--------+------+"
+    "| deploy | failed | 50   |"
+    "+--------+--------+------+"
+);
+
+test_table!(
+    test_filter_not_existing_column,
+    jobs().with(Filter::column(ByColumnName::new("xxx"), |_| true)),
+    "+-----+--------+------+"
+    "| job | status | time |"
+    "+-----+--------+------+"
+);
+
+test_table!(
+    test_filter_no_header,
+    jobs().with(Filter::column(Columns::first(), |text| text.len() == 4).header(0)),
+    "+------+--------+-----+"
+    "| test | failed | 120 |"
+    "+------+--------+-----+"
+    "| lint | ok     | 3   |"
+    "+------+--------+-----+"
+);
+
+test_table!(
+    test_filter_keeps_panels,
+    jobs()
+        .with(Panel::header("Jobs"))
+        .with(Panel::footer("Total 4"))
+        .with(Filter::column(Columns::single(1), |text| text == "ok")),
+    "+-------+--------+------+"
+    "| Jobs                  |"
+    "+-------+--------+------+"
+    "| job   | status | time |"
+    "+-------+--------+------+"
+    "| build | ok     | 10   |"
+    "+-------+--------+------+"
+    "| lint  | ok     | 3    |"
+    "+-------+--------+------+"
+    "| Total 4               |"
+    "+-------+--------+------+"
+);
+
+test_table!(
+    test_filter_keeps_cell_config,
+    jobs()
+        .with(Modify::new(Rows::single(4)).with(Alignment::right()))
+        .with(Modify::new((3, 0)).with(Padding::new(3, 3, 0, 0)))
+        .with(Filter::column(Columns::single(1), |text| text != "failed")),
+    "+----------+--------+------+"
+    "| job      | status | time |"
+    "+----------+--------+------+"
+    "| build    | ok     | 10   |"
+    "+----------+--------+------+"
+    "|   lint   | ok     | 3    |"
+    "+----------+--------+------+"
+);
+
+test_table!(
+    test_filter_keeps_row_config,
+    jobs()
+        .with(Modify::new(Rows::single(4)).with(Alignment::right()))
+        .with(Filter::column(Columns::single(1), |text| text == "failed")),
+    "+--------+--------+------+"
+    "| job    | status | time |"
+    "+--------+--------+------+"
+    "| test   | failed | 120  |"
+    "+--------+--------+------+"
+    "| deploy | failed |   50 |"
+    "+--------+--------+------+"
+);
+
+test_table!(
+    test_filter_moves_row_span,
+    jobs()
+        .modify((1, 1), Span::row(3))
+        .with(Filter::column(Columns::first(), |text| text != "build")),
+    "+--------+--------+------+"
+    "| job    | status | time |"
+    "+--------+--------+------+"
+    "| test   | ok     | 120  |"
+    "+--------+        +------+"
+    "| lint   |        | 3    |"
+    "+--------+--------+------+"
+    "| deploy | failed | 50   |"
+    "+--------+--------+------+"
+);
+
+test_table!(
+    test_filter_moves_column_span,
+    jobs()
+        .modify((4, 0), Span::column(2))
+        .with(Filter::column(Columns::first(), |text| text != "test")),
+    "+-------+--------+------+"
+    "| job   | status | time |"
+    "+-------+--------+------+"
+    "| build | ok     | 10   |"
+    "+-------+--------+------+"
+    "| lint  | ok     | 3    |"
+    "+-------+--------+------+"
+    "| deploy         | 50   |"
+    "+-------+--------+------+"
+);
+
+test_table!(
+    test_filter_moves_horizontal_lines,
+    jobs()
+        .with(Panel::footer("Total"))
+        .with(
+            Style::ascii()
+                .remove_horizontal()
+                .horizontals([(1, HorizontalLine::filled('=')), (5, HorizontalLine::filled('-'))])
+        )
+        .with(Filter::column(Columns::single(1), |text| text == "ok")),
+    "+-------+--------+------+"
+    "| job   | status | time |"
+    "========================="
+    "| build | ok     | 10   |"
+    "| lint  | ok     | 3    |"
+    "-------------------------"
+    "| Total                 |"
+    "+-------+--------+------+"
+);
+
+test_table!(
+    test_filter_keeps_header_line,
+    jobs()
+        .with(Style::psql())
+        .with(Filter::column(Columns::single(1), |text| text == "failed")),
+    " job    | status | time "
+    "--------+--------+------"
+    " test   | failed | 120  "
+    " deploy | failed | 50   "
+);
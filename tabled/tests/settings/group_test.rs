@@ -0,0 +1,228 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{Columns, Rows},
+        style::{BorderSpanCorrection, HorizontalLine},
+        Aggregate, Alignment, Group, Modify, Panel, Sort, Style,
+    },
+    Table,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+fn billing() -> Table {
+    let mut builder = Builder::default();
+    builder.push_record(["customer", "project", "hours"]);
+    builder.push_record(["acme", "web", "10"]);
+    builder.push_record(["globex", "api", "4"]);
+    builder.push_record(["acme", "api", "2"]);
+    builder.push_record(["acme", "web", "5"]);
+    builder.push_record(["initech", "web", "1"]);
+    builder.build()
+}
+
+test_table!(test_0x0_group, Matrix::empty().with(Group::column(0)), "");
+
+test_table!(
+    test_group,
+    billing().with(Group::column(0)),
+    "+----------+---------+-------+"
+    "| customer | project | hours |"
+    "+----------+---------+-------+"
+    "| acme     | web     | 10    |"
+    "+          +---------+-------+"
+    "|          | api     | 2     |"
+    "+          +---------+-------+"
+    "|          | web     | 5     |"
+    "+----------+---------+-------+"
+    "| globex   | api     | 4     |"
+    "+----------+---------+-------+"
+    "| initech  | web     | 1     |"
+    "+----------+---------+-------+"
+);
+
+test_table!(
+    test_group_subtotal,
+    billing().with(Group::column(0).subtotal(Aggregate::sum(Columns::single(2)).label("total"))),
+    "+----------+---------+-------+"
+    "| customer | project | hours |"
+    "+----------+---------+-------+"
+    "| acme     | web     | 10    |"
+    "+          +---------+-------+"
+    "|          | api     | 2     |"
+    "+          +---------+-------+"
+    "|          | web     | 5     |"
+    "+----------+---------+-------+"
+    "| total    |         | 17    |"
+    "+----------+---------+-------+"
+    "| globex   | api     | 4     |"
+    "+----------+---------+-------+"
+    "| total    |         | 4     |"
+    "+----------+---------+-------+"
+    "| initech  | web     | 1     |"
+    "+----------+---------+-------+"
+    "| total    |         | 1     |"
+    "+----------+---------+-------+"
+);
+
+test_table!(
+    test_group_nested,
+    billing()
+        .with(Group::columns([0, 1]).subtotal(Aggregate::sum(Columns::single(2)).label("total")))
+        .with(Style::psql()),
+    " customer | project | hours "
+    "----------+---------+-------"
+    " acme     | web     | 10    "
+    "          |         | 5     "
+    "          | total   | 15    "
+    "          | api     | 2     "
+    "          | total   | 2     "
+    " total    |         | 17    "
+    " globex   | api     | 4     "
+    "          | total   | 4     "
+    " total    |         | 4     "
+    " initech  | web     | 1     "
+    "          | total   | 1     "
+    " total    |         | 1     "
+);
+
+test_table!(
+    test_group_nested_without_subtotal,
+    billing().with(Group::columns([0, 1])).with(Style::modern()).with(BorderSpanCorrection),
+    "┌──────────┬─────────┬───────┐"
+    "│ customer │ project │ hours │"
+    "├──────────┼─────────┼───────┤"
+    "│ acme     │ web     │ 10    │"
+    "│          │         ├───────┤"
+    "│          │         │ 5     │"
+    "│          ├─────────┼───────┤"
+    "│          │ api     │ 2     │"
+    "├──────────┼─────────┼───────┤"
+    "│ globex   │ api     │ 4     │"
+    "├──────────┼─────────┼───────┤"
+    "│ initech  │ web     │ 1     │"
+    "└──────────┴─────────┴───────┘"
+);
+
+test_table!(
+    test_group_after_sort,
+    billing()
+        .with(Sort::column(0).descending())
+        .with(Group::column(0).subtotal(Aggregate::count(Columns::single(1))))
+        .with(Style::psql()),
+    " customer | project | hours "
+    "----------+---------+-------"
+    " initech  | web     | 1     "
+    "          | 1       |       "
+    " globex   | api     | 4     "
+    "          | 1       |       "
+    " acme     | web     | 10    "
+    "          | api     | 2     "
+    "          | web     | 5     "
+    "          | 3       |       "
+);
+
+test_table!(
+    test_group_no_header,
+    Matrix::iter([("a", 1), ("b", 2), ("a", 3)]).with(Group::column(0).header(0)).with(Style::psql()),
+    " &str | i32 "
+    "------+-----"
+    "  a   |  1  "
+    "      |  3  "
+    "  b   |  2  "
+);
+
+test_table!(
+    test_group_keeps_panels,
+    billing()
+        .with(Panel::header("Billing"))
+        .with(Panel::footer("2024"))
+        .with(Group::column(0).subtotal(Aggregate::sum(Columns::single(2))))
+        .with(Style::psql()),
+    " Billing                    "
+    "----------+---------+-------"
+    " customer | project | hours "
+    " acme     | web     | 10    "
+    "          | api     | 2     "
+    "          | web     | 5     "
+    "          |         | 17    "
+    " globex   | api     | 4     "
+    "          |         | 4     "
+    " initech  | web     | 1     "
+    "          |         | 1     "
+    " 2024                       "
+);
+
+test_table!(
+    test_group_moves_horizontal_lines,
+    billing()
+        .with(Panel::footer("2024"))
+        .with(
+            Style::ascii()
+                .remove_horizontal()
+                .horizontals([(1, HorizontalLine::filled('=')), (6, HorizontalLine::filled('-'))])
+        )
+        .with(Group::column(0).subtotal(Aggregate::sum(Columns::single(2)))),
+    "+----------+---------+-------+"
+    "| customer | project | hours |"
+    "=============================="
+    "| acme     | web     | 10    |"
+    "|          | api     | 2     |"
+    "|          | web     | 5     |"
+    "|          |         | 17    |"
+    "| globex   | api     | 4     |"
+    "|          |         | 4     |"
+    "| initech  | web     | 1     |"
+    "|          |         | 1     |"
+    "------------------------------"
+    "| 2024                       |"
+    "+----------+---------+-------+"
+);
+
+test_table!(
+    test_group_not_existing_column,
+    billing().with(Group::column(10).subtotal(Aggregate::sum(Columns::single(2)))),
+    "+----------+---------+-------+"
+    "| customer | project | hours |"
+    "+----------+---------+-------+"
+    "| acme     | web     | 10    |"
+    "+----------+---------+-------+"
+    "| globex   | api     | 4     |"
+    "+----------+---------+-------+"
+    "| acme     | api     | 2     |"
+    "+----------+---------+-------+"
+    "| acme     | web     | 5     |"
+    "+----------+---------+-------+"
+    "| initech  | web     | 1     |"
+    "+----------+---------+-------+"
+);
+
+test_table!(
+    test_group_moves_cell_config,
+    billing()
+        .with(Modify::new(Rows::single(2)).with(Alignment::right()))
+        .with(Modify::new(Rows::single(4)).with(Alignment::right()))
+        .with(Group::column(0).subtotal(Aggregate::sum(Columns::single(2)))),
+    "+----------+---------+-------+"
+    "| customer | project | hours |"
+    "+----------+---------+-------+"
+    "| acme     | web     | 10    |"
+    "+          +---------+-------+"
+    "|          | api     | 2     |"
+    "+          +---------+-------+"
+    "|          |     web |     5 |"
+    "+----------+---------+-------+"
+    "|          |         | 17    |"
+    "+----------+---------+-------+"
+    "|   globex |     api |     4 |"
+    "+----------+---------+-------+"
+    "|          |         | 4     |"
+    "+----------+---------+-------+"
+    "| initech  | web     | 1     |"
+    "+----------+---------+-------+"
+    "|          |         | 1     |"
+    "+----------+---------+-------+"
+);
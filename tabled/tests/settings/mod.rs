@@ -1,3 +1,4 @@
+mod aggregate_test;
 mod alignment_test;
 mod color_test;
 mod colorization;
@@ -6,8 +7,10 @@ mod concat_test;
 mod disable_test;
 mod duplicate_test;
 mod extract_test;
+mod filter_test;
 mod format_test;
 mod formatting_test;
+mod group_test;
 mod height_test;
 mod highlingt_test;
 mod layout_test;
@@ -20,6 +23,7 @@ mod render_settings;
 mod reverse_test;
 mod rotate_test;
 mod shadow_test;
+mod sort_test;
 mod span_test;
 mod split_test;
 mod style_test;
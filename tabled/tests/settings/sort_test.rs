@@ -0,0 +1,239 @@
+#![cfg(feature = "std")]
+
+use std::cmp::Ordering;
+
+use tabled::{
+    builder::Builder,
+    settings::{Alignment, Modify, Panel, Sort, Span},
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(test_0x0_sort, Matrix::empty().with(Sort::column(0)), "");
+
+test_table!(
+    test_sort_lexicographic,
+    Matrix::iter([("b", 2), ("c", 10), ("a", 1)]).with(Sort::column(0)),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  a   |  1  |"
+    "+------+-----+"
+    "|  b   |  2  |"
+    "+------+-----+"
+    "|  c   | 10  |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_lexicographic_numbers,
+    Matrix::iter([("b", 2), ("c", 10), ("a", 1)]).with(Sort::column(1)),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  a   |  1  |"
+    "+------+-----+"
+    "|  c   | 10  |"
+    "+------+-----+"
+    "|  b   |  2  |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_numeric,
+    Matrix::iter([("b", 2), ("c", 10), ("a", 1)]).with(Sort::column(1).numeric()),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  a   |  1  |"
+    "+------+-----+"
+    "|  b   |  2  |"
+    "+------+-----+"
+    "|  c   | 10  |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_numeric_descending,
+    Matrix::iter([("b", 2), ("c", 10), ("a", 1)]).with(Sort::column(1).numeric().descending()),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  c   | 10  |"
+    "+------+-----+"
+    "|  b   |  2  |"
+    "+------+-----+"
+    "|  a   |  1  |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_multiple_keys,
+    Matrix::iter([("b", 1), ("a", 3), ("b", 0), ("a", 20)])
+        .with(Sort::column(0).descending().then(Sort::column(1).numeric())),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  b   |  0  |"
+    "+------+-----+"
+    "|  b   |  1  |"
+    "+------+-----+"
+    "|  a   |  3  |"
+    "+------+-----+"
+    "|  a   | 20  |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_is_stable,
+    Matrix::iter([("b", 1), ("a", 3), ("b", 0), ("a", 20)]).with(Sort::column(0)),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  a   |  3  |"
+    "+------+-----+"
+    "|  a   | 20  |"
+    "+------+-----+"
+    "|  b   |  1  |"
+    "+------+-----+"
+    "|  b   |  0  |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_by_closure,
+    Matrix::iter([("ccc", 1), ("a", 3), ("bb", 0)])
+        .with(Sort::column(0).by(|a: &str, b: &str| a.len().cmp(&b.len()).reverse())),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "| ccc  |  1  |"
+    "+------+-----+"
+    "|  bb  |  0  |"
+    "+------+-----+"
+    "|  a   |  3  |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_no_header,
+    Matrix::iter([("a", 3), ("b", 1)]).with(Sort::column(0).descending().header(0)),
+    "+------+-----+"
+    "|  b   |  1  |"
+    "+------+-----+"
+    "|  a   |  3  |"
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_keeps_panels,
+    Matrix::iter([("b", 1), ("c", 3), ("a", 2)])
+        .with(Panel::header("Header"))
+        .with(Panel::footer("Footer"))
+        .with(Sort::column(0)),
+    "+------+-----+"
+    "|   Header   |"
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  a   |  2  |"
+    "+------+-----+"
+    "|  b   |  1  |"
+    "+------+-----+"
+    "|  c   |  3  |"
+    "+------+-----+"
+    "|   Footer   |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_keeps_vertical_spans,
+    Matrix::iter([("d", 1), ("c", 3), ("b", 2), ("a", 0)])
+        .modify((1, 1), Span::row(2))
+        .with(Sort::column(0)),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  d   |  1  |"
+    "+------+     +"
+    "|  c   |     |"
+    "+------+-----+"
+    "|  a   |  0  |"
+    "+------+-----+"
+    "|  b   |  2  |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_builder,
+    {
+        let mut builder = Builder::default();
+        builder.push_record(["name", "size"]);
+        builder.push_record(["file10", "1 KB"]);
+        builder.push_record(["file9", "10 KB"]);
+        builder.push_record(["file100", "2 KB"]);
+        builder.build().with(Sort::column(0).numeric()).to_string()
+    },
+    "+---------+-------+"
+    "| name    | size  |"
+    "+---------+-------+"
+    "| file9   | 10 KB |"
+    "+---------+-------+"
+    "| file10  | 1 KB  |"
+    "+---------+-------+"
+    "| file100 | 2 KB  |"
+    "+---------+-------+"
+);
+
+test_table!(
+    test_sort_column_out_of_bounds,
+    Matrix::iter([("b", 1), ("a", 3)]).with(Sort::column(10).by(|_: &str, _: &str| Ordering::Less)),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  b   |  1  |"
+    "+------+-----+"
+    "|  a   |  3  |"
+    "+------+-----+"
+);
+
+test_table!(
+    test_sort_moves_cell_config,
+    Matrix::iter([("b", 2), ("c", 10), ("a", 1)])
+        .with(Modify::new((1, 0)).with(Alignment::right()))
+        .with(Sort::column(0)),
+    "+------+-----+"
+    "| &str | i32 |"
+    "+------+-----+"
+    "|  a   |  1  |"
+    "+------+-----+"
+    "|    b |  2  |"
+    "+------+-----+"
+    "|  c   | 10  |"
+    "+------+-----+"
+);
+
+#[cfg(feature = "ansi")]
+test_table!(
+    test_sort_colored,
+    {
+        use owo_colors::OwoColorize;
+
+        let data = [("b".red().to_string(), 1), ("a".blue().to_string(), 2), ("c".to_string(), 3)];
+        let mut table = Matrix::iter(data);
+        table.with(Sort::column(0));
+        table
+    },
+    "+--------+-----+"
+    "| String | i32 |"
+    "+--------+-----+"
+    "|   \u{1b}[34ma\u{1b}[39m    |  2  |"
+    "+--------+-----+"
+    "|   \u{1b}[31mb\u{1b}[39m    |  1  |"
+    "+--------+-----+"
+    "|   c    |  3  |"
+    "+--------+-----+"
+);
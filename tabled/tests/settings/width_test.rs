@@ -1,12 +1,17 @@
 #![cfg(feature = "std")]
 
 use tabled::{
-    grid::util::string::get_text_width,
+    grid::util::{
+        string::get_text_width,
+        width::{AmbiguousWide, CharCount, CustomWidth},
+    },
     settings::{
         formatting::{TabSize, TrimStrategy},
         object::{Columns, Object, Rows, Segment},
         peaker::{PriorityLeft, PriorityMax, PriorityMin, PriorityRight},
-        width::{Justify, MinWidth, SuffixLimit, Width},
+        width::{
+            ColumnConstraint, ColumnOverflow, Justify, MinWidth, SuffixLimit, TruncateMode, Width,
+        },
         Alignment, Margin, Modify, Padding, Panel, Settings, Span, Style,
     },
 };
@@ -143,28 +148,6 @@ test_table!(
     "| sentence          |"
 );
 
-#[cfg(feature = "ansi")]
-test_table!(
-    max_width_wrapped_keep_words_3,
-    {
-        let table = Matrix::iter(vec!["this is a long    sentence"])
-            .with(Style::markdown())
-            .with(Modify::new(Segment::all()).with(Alignment::left()))
-            .with(Modify::new(Segment::all()).with(Width::wrap(17).keep_words(true)))
-            .to_string();
-
-        assert_width!(&table, 17 + 2 + 2);
-
-        table
-    },
-    // 'sentence' doesn't have a space ' sentence' because we use left alignment
-    "| &str              |"
-    "|-------------------|"
-    "| this is a long    |"
-    "|  sentence         |"
-);
-
-#[cfg(not(feature = "ansi"))]
 test_table!(
     max_width_wrapped_keep_words_3,
     {
@@ -310,7 +293,7 @@ test_table!(
     "| String            |"
     "|-------------------|"
     "| this is a long    |"
-    "|  sentence         |"
+    "| sentence          |"
 );
 
 #[cfg(feature = "ansi")]
@@ -323,7 +306,7 @@ test_table!(
     "| String            |"
     "|-------------------|"
     "| \u{1b}[32m\u{1b}[40mthis is a long   \u{1b}[39m\u{1b}[49m |"
-    "| \u{1b}[32m\u{1b}[40m sentence\u{1b}[39m\u{1b}[49m         |"
+    "| \u{1b}[32m\u{1b}[40msentence\u{1b}[39m\u{1b}[49m          |"
 );
 
 #[cfg(feature = "ansi")]
@@ -512,6 +495,7 @@ fn dont_change_content_if_width_is_less_then_max_width() {
     );
 }
 
+#[cfg(not(feature = "grapheme"))]
 #[test]
 fn max_width_with_emoji() {
     let data = &["🤠", "😳🥵🥶😱😨", "🚴🏻‍♀️🚴🏻🚴🏻‍♂️🚵🏻‍♀️🚵🏻🚵🏻‍♂️"];
@@ -533,6 +517,540 @@ fn max_width_with_emoji() {
     );
 }
 
+#[cfg(feature = "grapheme")]
+#[test]
+fn max_width_with_emoji_graphemes() {
+    let data = &["🤠", "😳🥵🥶😱😨", "🚴🏻‍♀️🚴🏻🚴🏻‍♂️🚵🏻‍♀️🚵🏻🚵🏻‍♂️"];
+
+    let table = Matrix::iter(data)
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::truncate(6).suffix("...")))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "|  &str  |"
+            "|--------|"
+            "|   🤠   |"
+            "| 😳�... |"
+            "| 🚴🏻\u{200d}♀\u{fe0f}�... |"
+        )
+    );
+}
+
+#[cfg(feature = "grapheme")]
+#[test]
+fn wrap_keeps_graphemes() {
+    let data = &["👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧", "e\u{301}e\u{301}e\u{301}", "🇺🇦🇩🇪🇫🇷"];
+
+    let table = Matrix::iter(data)
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Width::wrap(4)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "| &str |"
+            "|------|"
+            "| 👨\u{200d}👩\u{200d}👧👨\u{200d}👩\u{200d}👧 |"
+            "| 👨\u{200d}👩\u{200d}👧   |"
+            "| e\u{301}e\u{301}e\u{301}  |"
+            "| 🇺🇦🇩🇪 |"
+            "| 🇫🇷   |"
+        )
+    );
+}
+
+test_table!(
+    wrap_with_ambiguous_wide_metric,
+    Matrix::iter(["±±±±±±", "a±b"])
+        .with(Width::wrap(8).metric(AmbiguousWide))
+        .with(Width::metric(AmbiguousWide)),
+    "+------+"
+    "| &str |"
+    "+------+"
+    "| ±± |"
+    "| ±± |"
+    "| ±± |"
+    "+------+"
+    "| a±b |"
+    "+------+"
+);
+
+test_table!(
+    wrap_keep_words_with_ambiguous_wide_metric,
+    Matrix::iter(["±± ○○ ±"])
+        .with(
+            Modify::new(Segment::all())
+                .with(Width::wrap(5).keep_words(true).metric(AmbiguousWide)),
+        )
+        .with(Width::metric(AmbiguousWide)),
+    "+-------+"
+    "| &str  |"
+    "+-------+"
+    "| ±±  |"
+    "| ○○  |"
+    "| ±    |"
+    "+-------+"
+);
+
+test_table!(
+    truncate_with_char_count_metric,
+    Matrix::iter(["日本語のテキスト", "abcdef"])
+        .with(Modify::new(Rows::new(1..)).with(Width::truncate(4).suffix("..").metric(CharCount)))
+        .with(Width::metric(CharCount)),
+    "+------+"
+    "| &str |"
+    "+------+"
+    "| 日本.. |"
+    "+------+"
+    "| ab.. |"
+    "+------+"
+);
+
+test_table!(
+    truncate_table_with_ambiguous_wide_metric,
+    Matrix::iter(["±±±±±±", "abc"])
+        .with(Width::truncate(7).metric(AmbiguousWide))
+        .with(Width::metric(AmbiguousWide)),
+    "+-----+"
+    "| &st |"
+    "+-----+"
+    "| ±  |"
+    "+-----+"
+    "| abc |"
+    "+-----+"
+);
+
+test_table!(
+    increase_with_ambiguous_wide_metric,
+    Matrix::iter(["±", "○○"])
+        .with(Modify::new(Segment::all()).with(Width::increase(6).metric(AmbiguousWide)))
+        .with(Width::metric(AmbiguousWide)),
+    "+--------+"
+    "| &str   |"
+    "+--------+"
+    "| ±     |"
+    "+--------+"
+    "| ○○   |"
+    "+--------+"
+);
+
+test_table!(
+    justify_with_char_count_metric,
+    Matrix::iter(["日本", "a"])
+        .with(Width::justify(3).metric(CharCount))
+        .with(Width::metric(CharCount)),
+    "+-----+"
+    "| &st |"
+    "+-----+"
+    "| 日本  |"
+    "+-----+"
+    "| a   |"
+    "+-----+"
+);
+
+test_table!(
+    custom_width_metric,
+    Matrix::iter(["✅ done", "🚀 wip"])
+        .with(Width::metric(CustomWidth::default().set("✅", 1))),
+    "+--------+"
+    "|  &str  |"
+    "+--------+"
+    "| ✅ done |"
+    "+--------+"
+    "| 🚀 wip |"
+    "+--------+"
+);
+
+test_table!(
+    trim_horizontal_with_ambiguous_wide_metric,
+    Matrix::iter(["  ±1  ", "abc"])
+        .with(Modify::new(Segment::all()).with(TrimStrategy::Horizontal))
+        .with(Width::metric(AmbiguousWide)),
+    "+---------+"
+    "|  &str   |"
+    "+---------+"
+    "|   ±1   |"
+    "+---------+"
+    "|   abc   |"
+    "+---------+"
+);
+
+test_table!(
+    wrap_break_at_separators,
+    Matrix::iter([
+        "/usr/local/share/doc/tabled/README.md",
+        "std::collections::HashMap<String, Vec<u8>>",
+    ])
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(16).break_at_separators(true))),
+    "+------------------+"
+    "|       &str       |"
+    "+------------------+"
+    "| /usr/local/      |"
+    "| share/doc/       |"
+    "| tabled/README.md |"
+    "+------------------+"
+    "| std::            |"
+    "| collections::    |"
+    "| HashMap<String,  |"
+    "| Vec<u8>>         |"
+    "+------------------+"
+);
+
+test_table!(
+    wrap_soft_hyphens,
+    Matrix::iter(["in\u{AD}com\u{AD}pre\u{AD}hen\u{AD}si\u{AD}ble words"])
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(8).soft_hyphens(true))),
+    "+---------+"
+    "|  &str   |"
+    "+---------+"
+    "| incom-  |"
+    "| prehen- |"
+    "| sible   |"
+    "| words   |"
+    "+---------+"
+);
+
+test_table!(
+    wrap_hyphenate,
+    Matrix::iter(["Supercalifragilisticexpialidocious is long"])
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(10).hyphenate(true))),
+    "+------------+"
+    "|    &str    |"
+    "+------------+"
+    "| Supercali- |"
+    "| fragilist- |"
+    "| icexpiali- |"
+    "| docious is |"
+    "| long       |"
+    "+------------+"
+);
+
+test_table!(
+    wrap_balance,
+    Matrix::iter(["aaa bb cc ddddd ee f gggg"])
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(6).balance(true))),
+    "+-------+"
+    "| &str  |"
+    "+-------+"
+    "| aaa   |"
+    "| bb cc |"
+    "| ddddd |"
+    "| ee f  |"
+    "| gggg  |"
+    "+-------+"
+);
+
+test_table!(
+    wrap_table_break_at_separators,
+    Matrix::new(2, 2)
+        .insert((1, 1), "crate::settings::width::Wrap")
+        .insert((2, 2), "tabled/src/settings/width/wrap.rs")
+        .with(
+            Width::wrap(40)
+                .break_at_separators(true)
+                .hyphenate(true)
+                .priority(PriorityMax::right()),
+        ),
+    "+---+-----------------+----------------+"
+    "| N |    column 0     |    column 1    |"
+    "+---+-----------------+----------------+"
+    "| 0 |   crate::       |      0-1       |"
+    "|   |   settings::    |                |"
+    "|   |   width::Wrap   |                |"
+    "+---+-----------------+----------------+"
+    "| 1 |       1-0       | tabled/src/    |"
+    "|   |                 | settings/      |"
+    "|   |                 | width/wrap.rs  |"
+    "+---+-----------------+----------------+"
+);
+
+test_table!(
+    truncate_mode_start,
+    Matrix::iter(["/home/user/projects/tabled/src/lib.rs", "short"]).with(
+        Modify::new(Rows::new(1..))
+            .with(Width::truncate(16).suffix("...").mode(TruncateMode::Start)),
+    ),
+    "+------------------+"
+    "|       &str       |"
+    "+------------------+"
+    "| ...ed/src/lib.rs |"
+    "+------------------+"
+    "|      short       |"
+    "+------------------+"
+);
+
+test_table!(
+    truncate_mode_middle,
+    Matrix::iter([
+        "0c5d3c9f8e7d6c5b4a3928170615243342516a7b",
+        "550e8400-e29b-41d4-a716-446655440000",
+    ])
+    .with(
+        Modify::new(Rows::new(1..))
+            .with(Width::truncate(13).suffix("…").mode(TruncateMode::Middle)),
+    ),
+    "+---------------+"
+    "|     &str      |"
+    "+---------------+"
+    "| 0c5d3c…516a7b |"
+    "+---------------+"
+    "| 550e84…440000 |"
+    "+---------------+"
+);
+
+test_table!(
+    truncate_mode_path,
+    Matrix::iter([
+        "/home/user/projects/tabled/src/lib.rs",
+        "C:\\Users\\user\\Documents\\report.docx",
+        "a_very_long_file_name.txt",
+    ])
+    .with(
+        Modify::new(Rows::new(1..)).with(Width::truncate(20).suffix("…").mode(TruncateMode::Path)),
+    ),
+    "+----------------------+"
+    "|         &str         |"
+    "+----------------------+"
+    "| …/tabled/src/lib.rs  |"
+    "+----------------------+"
+    "|    …\\report.docx     |"
+    "+----------------------+"
+    "| …_long_file_name.txt |"
+    "+----------------------+"
+);
+
+test_table!(
+    truncate_mode_middle_multiline,
+    Matrix::iter(["abcdefghijklmnop\nqrstuvwxyz0123456789"]).with(
+        Modify::new(Rows::new(1..)).with(
+            Width::truncate(9)
+                .suffix("..")
+                .mode(TruncateMode::Middle)
+                .multiline(true),
+        ),
+    ),
+    "+-----------+"
+    "|   &str    |"
+    "+-----------+"
+    "| abcd..nop |"
+    "| qrst..789 |"
+    "+-----------+"
+);
+
+test_table!(
+    truncate_mode_start_without_suffix,
+    Matrix::iter(["😳😳😳😳😳"])
+        .with(Modify::new(Rows::new(1..)).with(Width::truncate(5).mode(TruncateMode::Start))),
+    "+-------+"
+    "| &str  |"
+    "+-------+"
+    "| �😳😳 |"
+    "+-------+"
+);
+
+test_table!(
+    truncate_table_mode_path,
+    Matrix::new(2, 2)
+        .insert((1, 1), "/usr/local/share/man/man1/tabled.1")
+        .insert((2, 2), "tabled/src/settings/width/truncate.rs")
+        .with(
+            Width::truncate(50)
+                .suffix("…")
+                .mode(TruncateMode::Path)
+                .priority(PriorityMax::right()),
+        ),
+    "+---+----------------------+---------------------+"
+    "| N |       column 0       |      column 1       |"
+    "+---+----------------------+---------------------+"
+    "| 0 | …/man/man1/tabled.1  |         0-1         |"
+    "+---+----------------------+---------------------+"
+    "| 1 |         1-0          | …/width/truncate.rs |"
+    "+---+----------------------+---------------------+"
+);
+
+#[cfg(feature = "ansi")]
+#[test]
+fn truncate_mode_colored() {
+    let data = &[
+        format!(
+            "{}{}",
+            "/home/user/".blue(),
+            "projects/tabled/src/lib.rs".red()
+        ),
+        format!(
+            "{}{}",
+            "0c5d3c9f8e7d6c5b4a39".green(),
+            "28170615243342516a7b".red()
+        ),
+    ];
+
+    let table = Matrix::iter(data)
+        .with(Style::markdown())
+        .modify(
+            Rows::single(1),
+            Width::truncate(20)
+                .suffix("…")
+                .suffix_try_color(true)
+                .mode(TruncateMode::Path),
+        )
+        .modify(
+            Rows::single(2),
+            Width::truncate(13)
+                .suffix("…")
+                .suffix_try_color(true)
+                .mode(TruncateMode::Middle),
+        )
+        .to_string();
+
+    assert_eq!(
+        ansi_str::AnsiStr::ansi_strip(&table),
+        static_table!(
+            "|       String        |"
+            "|---------------------|"
+            "| …/tabled/src/lib.rs |"
+            "|    0c5d3c…516a7b    |"
+        )
+    );
+
+    assert!(table.contains("\u{1b}[31m"));
+    assert!(table.contains("\u{1b}[32m"));
+}
+
+test_table!(
+    layout_flex_weights,
+    Matrix::new(3, 3).with(Width::layout(50).column(1, ColumnConstraint::new().flex(2))),
+    "+----------+-----------------+---------+---------+"
+    "|    N     |    column 0     | column  | column  |"
+    "|          |                 | 1       | 2       |"
+    "+----------+-----------------+---------+---------+"
+    "|    0     |       0-0       |   0-1   |   0-2   |"
+    "+----------+-----------------+---------+---------+"
+    "|    1     |       1-0       |   1-1   |   1-2   |"
+    "+----------+-----------------+---------+---------+"
+    "|    2     |       2-0       |   2-1   |   2-2   |"
+    "+----------+-----------------+---------+---------+"
+);
+
+test_table!(
+    layout_fixed_and_max,
+    Matrix::new(3, 3)
+        .insert((1, 1), "Some long text which doesn't fit")
+        .with(
+            Width::layout(40)
+                .column(0, ColumnConstraint::fixed(1))
+                .column(
+                    1,
+                    ColumnConstraint::new()
+                        .max(10)
+                        .overflow(ColumnOverflow::WrapWords),
+                ),
+        ),
+    "+---+-----------+-----------+----------+"
+    "| N | column 0  | column 1  | column 2 |"
+    "+---+-----------+-----------+----------+"
+    "| 0 | Some long |    0-1    |   0-2    |"
+    "|   | text      |           |          |"
+    "|   | which     |           |          |"
+    "|   | doesn't   |           |          |"
+    "|   | fit       |           |          |"
+    "+---+-----------+-----------+----------+"
+    "| 1 |    1-0    |    1-1    |   1-2    |"
+    "+---+-----------+-----------+----------+"
+    "| 2 |    2-0    |    2-1    |   2-2    |"
+    "+---+-----------+-----------+----------+"
+);
+
+test_table!(
+    layout_content_sized,
+    Matrix::new(3, 3)
+        .insert((2, 3), "content sized column")
+        .with(Width::layout(50).column(3, ColumnConstraint::new().flex(0))),
+    "+--------+--------+-------+----------------------+"
+    "|   N    | column | colum |       column 2       |"
+    "|        |  0     | n 1   |                      |"
+    "+--------+--------+-------+----------------------+"
+    "|   0    |  0-0   |  0-1  |         0-2          |"
+    "+--------+--------+-------+----------------------+"
+    "|   1    |  1-0   |  1-1  | content sized column |"
+    "+--------+--------+-------+----------------------+"
+    "|   2    |  2-0   |  2-1  |         2-2          |"
+    "+--------+--------+-------+----------------------+"
+);
+
+test_table!(
+    layout_shrink_content_sized,
+    Matrix::new(3, 3)
+        .insert((2, 3), "content sized column")
+        .with(
+            Width::layout(30).others(
+                ColumnConstraint::new()
+                    .flex(0)
+                    .overflow(ColumnOverflow::Truncate),
+            ),
+        ),
+    "+---+--------+-------+-------+"
+    "| N | column | colum | colum |"
+    "+---+--------+-------+-------+"
+    "| 0 |  0-0   |  0-1  |  0-2  |"
+    "+---+--------+-------+-------+"
+    "| 1 |  1-0   |  1-1  | conte |"
+    "+---+--------+-------+-------+"
+    "| 2 |  2-0   |  2-1  |  2-2  |"
+    "+---+--------+-------+-------+"
+);
+
+test_table!(
+    layout_min_exceeds_width,
+    Matrix::new(2, 2).with(Width::layout(10).others(ColumnConstraint::new().min(5))),
+    "+-------+-------+-------+"
+    "|   N   | colum | colum |"
+    "|       | n 0   | n 1   |"
+    "+-------+-------+-------+"
+    "|   0   |  0-0  |  0-1  |"
+    "+-------+-------+-------+"
+    "|   1   |  1-0  |  1-1  |"
+    "+-------+-------+-------+"
+);
+
+test_table!(
+    layout_elide,
+    Matrix::iter(["/usr/local/share/man/man1/tabled.1", "0c5d3c9f8e7d6c5b4a39"]).with(
+        Width::layout(16)
+            .others(ColumnConstraint::new().overflow(ColumnOverflow::Elide(TruncateMode::Middle))),
+    ),
+    "+--------------+"
+    "|     &str     |"
+    "+--------------+"
+    "| /usr/l…led.1 |"
+    "+--------------+"
+    "| 0c5d3c…b4a39 |"
+    "+--------------+"
+);
+
+test_table!(
+    layout_span,
+    Matrix::new(3, 3)
+        .insert((1, 0), "a long spanned text cell")
+        .with(Modify::new((1, 0)).with(Span::column(2)))
+        .with(Width::layout(30).column(2, ColumnConstraint::fixed(3))),
+    "+-------+-------+-----+------+"
+    "|   N   | colum | col | colu |"
+    "|       | n 0   | umn | mn 2 |"
+    "|       |       |  1  |      |"
+    "+-------+-------+-----+------+"
+    "| a long spanne | 0-1 | 0-2  |"
+    "| d text cell   |     |      |"
+    "+-------+-------+-----+------+"
+    "|   1   |  1-0  | 1-1 | 1-2  |"
+    "+-------+-------+-----+------+"
+    "|   2   |  2-0  | 2-1 | 2-2  |"
+    "+-------+-------+-----+------+"
+);
+
 #[cfg(feature = "ansi")]
 #[test]
 fn color_chars_are_stripped() {
@@ -2837,12 +3355,12 @@ mod derived {
                 "+-------+-------+"
                 "| asd D | true  |"
                 "| ebian |       |"
-                "|  2    |       |"
+                "| 2     |       |"
                 "| links |       |"
-                "|  in a |       |"
-                "|  stri |       |"
-                "| ng De |       |"
-                "| bian  |       |"
+                "| in a  |       |"
+                "| strin |       |"
+                "| g Deb |       |"
+                "| ian   |       |"
                 "+-------+-------+"
             )
         );
@@ -2902,7 +3420,7 @@ mod derived {
                 "|        | erlink |"
                 "+--------+--------+"
                 "| Debian | true   |"
-                "|  :link |        |"
+                "| :link  |        |"
                 "+--------+--------+"
             )
         );
@@ -2921,11 +3439,10 @@ mod derived {
                 "+--------+--------+"
                 "| asd    | true   |"
                 "| Debian |        |"
-                "|  2     |        |"
+                "| 2      |        |"
                 "| links  |        |"
                 "| in a   |        |"
                 "| string |        |"
-                "|        |        |"
                 "| Debian |        |"
                 "+--------+--------+"
             )
@@ -2,6 +2,7 @@ use syn::{Attribute, LitInt};
 
 use crate::{
     casing_style::CasingStyle,
+    column_style::{Align, ColorNames, ColumnStyle},
     error::Error,
     parse::field_attr::{parse_field_attributes, FieldAttr, FieldAttrKind},
 };
@@ -18,6 +19,11 @@ pub struct FieldAttributes {
     pub order: Option<usize>,
     pub format: Option<String>,
     pub format_with_args: Option<Vec<FormatArg>>,
+    pub groups: Vec<String>,
+    pub style: ColumnStyle,
+    pub option: Option<String>,
+    pub join: Option<String>,
+    pub table: bool,
 }
 
 pub struct FormatArg {
@@ -35,6 +41,27 @@ impl FieldAttributes {
         let mut attributes = Self::default();
         attributes.fill_attributes(attrs)?;
 
+        if attributes.style.wrap && attributes.style.max_width.is_none() {
+            return Err(Error::message(
+                "A `wrap` attribute requires a `max_width` to be set",
+            ));
+        }
+
+        let count_displays = [
+            attributes.option.is_some(),
+            attributes.join.is_some(),
+            attributes.table,
+        ]
+        .iter()
+        .filter(|&&is_set| is_set)
+        .count();
+        let has_custom_display = attributes.display_with.is_some() || attributes.format.is_some();
+        if count_displays > 1 || (count_displays == 1 && has_custom_display) {
+            return Err(Error::message(
+                "Only one of `option`, `join` and `table` attributes can be used, and none of them together with `display_with` or `format`",
+            ));
+        }
+
         Ok(attributes)
     }
 
@@ -84,6 +111,16 @@ impl FieldAttributes {
                 }
             }
             FieldAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            FieldAttrKind::Group(value) => self.groups.push(value.value()),
+            FieldAttrKind::Align(lit) => self.style.align = Some(Align::from_lit(&lit)?),
+            FieldAttrKind::Color(lit) => self.style.color = Some(ColorNames::from_lit(&lit)?),
+            FieldAttrKind::MaxWidth(value) => {
+                self.style.max_width = Some(lit_int_to_usize(&value)?);
+            }
+            FieldAttrKind::Wrap(b) => self.style.wrap = b.value,
+            FieldAttrKind::Option(value) => self.option = Some(value.value()),
+            FieldAttrKind::Join(value) => self.join = Some(value.value()),
+            FieldAttrKind::Table(b) => self.table = b.value,
         }
 
         Ok(())
@@ -0,0 +1,139 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::ExprPath;
+
+use crate::error::Error;
+
+/// Defines a horizontal alignment of a column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+impl Align {
+    pub fn from_lit(name: &syn::LitStr) -> Result<Self, Error> {
+        match name.value().to_lowercase().as_str() {
+            "left" => Ok(Align::Left),
+            "right" => Ok(Align::Right),
+            "center" => Ok(Align::Center),
+            _ => Err(Error::new(
+                format!("unsupported alignment: `{:?}`", name.value()),
+                name.span(),
+                Some("supported values are ['left', 'right', 'center']".to_owned()),
+            )),
+        }
+    }
+
+    fn ident(self) -> Ident {
+        let name = match self {
+            Align::Left => "Left",
+            Align::Right => "Right",
+            Align::Center => "Center",
+        };
+
+        Ident::new(name, Span::call_site())
+    }
+}
+
+/// Defines a color of a column as a list of `Color` constant names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColorNames(Vec<String>);
+
+impl ColorNames {
+    const COLORS: [&'static str; 8] = [
+        "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    ];
+
+    pub fn from_lit(lit: &syn::LitStr) -> Result<Self, Error> {
+        let value = lit.value();
+
+        let mut list = Vec::new();
+        for name in value.split_whitespace() {
+            let constant = Self::constant_name(&name.to_lowercase()).ok_or_else(|| {
+                Error::new(
+                    format!("unsupported color: `{:?}`", name),
+                    lit.span(),
+                    Some("supported values are colors ['black', 'red', 'green', 'yellow', 'blue', 'magenta', 'cyan', 'white'] with optional 'bg_' and 'bright_' prefixes, 'bold' and 'underline', separated by spaces".to_owned()),
+                )
+            })?;
+
+            list.push(constant);
+        }
+
+        if list.is_empty() {
+            return Err(Error::new("a color must not be empty", lit.span(), None));
+        }
+
+        Ok(Self(list))
+    }
+
+    fn constant_name(name: &str) -> Option<String> {
+        match name {
+            "bold" => return Some(String::from("BOLD")),
+            "underline" => return Some(String::from("UNDERLINE")),
+            _ => {}
+        }
+
+        let (ground, name) = match name.strip_prefix("bg_") {
+            Some(name) => ("BG", name),
+            None => ("FG", name),
+        };
+
+        let (bright, name) = match name.strip_prefix("bright_") {
+            Some(name) => ("BRIGHT_", name),
+            None => ("", name),
+        };
+
+        if !Self::COLORS.contains(&name) {
+            return None;
+        }
+
+        Some(format!("{}_{}{}", ground, bright, name.to_uppercase()))
+    }
+}
+
+/// Column style hints of a field.
+#[derive(Clone, Debug, Default)]
+pub struct ColumnStyle {
+    pub align: Option<Align>,
+    pub color: Option<ColorNames>,
+    pub max_width: Option<usize>,
+    pub wrap: bool,
+}
+
+impl ColumnStyle {
+    pub fn is_empty(&self) -> bool {
+        self.align.is_none() && self.color.is_none() && self.max_width.is_none()
+    }
+
+    pub fn to_tokens(&self, crate_path: &ExprPath) -> TokenStream {
+        let mut tokens = quote!(#crate_path::settings::ColumnStyle::new());
+
+        if let Some(align) = self.align {
+            let align = align.ident();
+            tokens =
+                quote!(#tokens.alignment(#crate_path::grid::config::AlignmentHorizontal::#align));
+        }
+
+        if let Some(color) = &self.color {
+            let colors = color
+                .0
+                .iter()
+                .map(|name| Ident::new(name, Span::call_site()))
+                .map(|name| quote!(#crate_path::settings::Color::#name));
+            tokens = quote!(#tokens.color(#(#colors)|*));
+        }
+
+        if let Some(width) = self.max_width {
+            tokens = quote!(#tokens.max_width(#width));
+        }
+
+        if self.wrap {
+            tokens = quote!(#tokens.wrap(true));
+        }
+
+        tokens
+    }
+}
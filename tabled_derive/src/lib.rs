@@ -7,6 +7,7 @@ extern crate proc_macro;
 
 mod attributes;
 mod casing_style;
+mod column_style;
 mod error;
 mod parse;
 
@@ -49,6 +50,32 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
         .unwrap();
     let fields = info.values;
     let headers = info.headers;
+    let styles = info.styles;
+    let styles = info.has_styles.then(|| {
+        let crate_path = get_crate_path(&tabled_trait_path);
+
+        quote! {
+            fn column_styles() -> Vec<#crate_path::settings::ColumnStyle> {
+                #styles
+            }
+        }
+    });
+    let groups = info.groups.map(|groups| {
+        let fields_for = groups.values;
+        let headers_for = groups.headers;
+
+        quote! {
+            #[allow(unused_variables)]
+            fn fields_for(&self, groups: &[&str]) -> Vec<::std::borrow::Cow<'_, str>> {
+                #fields_for
+            }
+
+            #[allow(unused_variables)]
+            fn headers_for(groups: &[&str]) -> Vec<::std::borrow::Cow<'static, str>> {
+                #headers_for
+            }
+        }
+    });
 
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -64,6 +91,10 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
             fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
                 #headers
             }
+
+            #groups
+
+            #styles
         }
     };
 
@@ -199,6 +230,11 @@ fn info_from_fields(
 
     let mut headers = Vec::new();
     let mut values = Vec::new();
+    let mut group_headers = Vec::new();
+    let mut group_values = Vec::new();
+    let mut styles = Vec::new();
+    let mut has_groups = false;
+    let mut has_styles = false;
     let mut reorder = HashMap::new();
 
     let mut skipped = 0;
@@ -219,17 +255,57 @@ fn info_from_fields(
             reorder.insert(order, i - skipped);
         }
 
-        let header = field_headers(field, i, &attributes, header_prefix, trait_path);
+        let header = field_headers(field, i, &attributes, header_prefix, trait_path, false);
         headers.push(header);
 
         let field_name_result = field_name(i, field);
-        let value = get_field_fields(&field_name_result, &attributes, fields, field_name);
+        let value = get_field_fields(
+            &field_name_result,
+            &field.ty,
+            &attributes,
+            fields,
+            field_name,
+            trait_path,
+            false,
+        );
         values.push(value);
+
+        let style = field_styles(field, &attributes, trait_path);
+        styles.push(style);
+
+        // inlined types may have their own groups and styles
+        has_groups |= attributes.inline || !attributes.groups.is_empty();
+        has_styles |= attributes.inline || !attributes.style.is_empty();
+
+        // a field marked by groups is present only when any of them is requested
+        let header = field_headers(field, i, &attributes, header_prefix, trait_path, true);
+        let value = get_field_fields(
+            &field_name_result,
+            &field.ty,
+            &attributes,
+            fields,
+            field_name,
+            trait_path,
+            true,
+        );
+        if attributes.groups.is_empty() {
+            group_headers.push(quote!(out.extend(#header);));
+            group_values.push(quote!(out.extend(#value);));
+        } else {
+            let names = &attributes.groups;
+            let cond = quote!([#(#names),*].iter().any(|name| groups.contains(name)));
+
+            group_headers.push(quote!(if #cond { out.extend(#header); }));
+            group_values.push(quote!(if #cond { out.extend(#value); }));
+        }
     }
 
     if !reorder.is_empty() {
         values = reorder_fields(&reorder, &values);
         headers = reorder_fields(&reorder, &headers);
+        group_values = reorder_fields(&reorder, &group_values);
+        group_headers = reorder_fields(&reorder, &group_headers);
+        styles = reorder_fields(&reorder, &styles);
     }
 
     let headers = quote!({
@@ -244,7 +320,32 @@ fn info_from_fields(
         out
     });
 
-    Ok(Impl { headers, values })
+    let groups = Groups {
+        headers: quote!({
+            let mut out = Vec::new();
+            #(#group_headers)*
+            out
+        }),
+        values: quote!({
+            let mut out = Vec::new();
+            #(#group_values)*
+            out
+        }),
+    };
+
+    let styles = quote!({
+        let mut out = Vec::new();
+        #(out.extend(#styles);)*
+        out
+    });
+
+    Ok(Impl {
+        headers,
+        values,
+        groups: has_groups.then_some(groups),
+        styles,
+        has_styles,
+    })
 }
 
 fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Vec<T> {
@@ -281,13 +382,14 @@ fn field_headers(
     attributes: &FieldAttributes,
     prefix: &str,
     trait_path: &ExprPath,
+    with_groups: bool,
 ) -> TokenStream {
     if attributes.inline {
         let prefix = attributes
             .inline_prefix
             .as_ref()
             .map_or_else(|| "", |s| s.as_str());
-        return get_type_headers(&field.ty, prefix, "", trait_path);
+        return get_type_headers(&field.ty, prefix, "", trait_path, with_groups);
     }
 
     let header_name = field_header_name(field, attributes, index);
@@ -299,6 +401,16 @@ fn field_headers(
     }
 }
 
+fn field_styles(field: &Field, attributes: &FieldAttributes, trait_path: &ExprPath) -> TokenStream {
+    if attributes.inline {
+        let field_type = &field.ty;
+        return quote! { <#field_type as #trait_path>::column_styles() };
+    }
+
+    let style = attributes.style.to_tokens(&get_crate_path(trait_path));
+    quote! { vec![#style] }
+}
+
 fn collect_info_enum(
     ast: &DataEnum,
     attrs: &TypeAttributes,
@@ -327,10 +439,13 @@ fn _collect_info_enum(
     let orderedvariants = reodered_variants(ast)?;
 
     let mut headers_list = Vec::new();
+    let mut styles_list = Vec::new();
+    let mut has_styles = false;
     let mut variants = Vec::new();
     for v in orderedvariants {
         let mut attributes = FieldAttributes::parse(&v.attrs)?;
         merge_attributes(&mut attributes, attrs);
+        check_variant_attributes(&attributes)?;
         if attributes.is_ignored {
             continue;
         }
@@ -338,6 +453,8 @@ fn _collect_info_enum(
         let info = info_from_variant(v, &attributes, attrs, trait_path)?;
         variants.push((v, info.values));
         headers_list.push(info.headers);
+        styles_list.push(info.styles);
+        has_styles |= info.has_styles;
     }
 
     let variant_sizes = get_enum_variant_length(ast, trait_path)
@@ -352,7 +469,20 @@ fn _collect_info_enum(
         .concat()
     };
 
-    Ok(Impl { headers, values })
+    let styles = quote! {
+        [
+            #(#styles_list,)*
+        ]
+        .concat()
+    };
+
+    Ok(Impl {
+        headers,
+        values,
+        groups: None,
+        styles,
+        has_styles,
+    })
 }
 
 fn collect_info_enum_inlined(
@@ -367,6 +497,7 @@ fn collect_info_enum_inlined(
     for variant in orderedvariants {
         let mut attributes = FieldAttributes::parse(&variant.attrs)?;
         merge_attributes(&mut attributes, attrs);
+        check_variant_attributes(&attributes)?;
         let mut name = String::new();
         if !attributes.is_ignored {
             name = variant_name(variant, &attributes);
@@ -384,7 +515,29 @@ fn collect_info_enum_inlined(
         }
     };
 
-    Ok(Impl { headers, values })
+    Ok(Impl {
+        headers,
+        values,
+        groups: None,
+        styles: quote! { Vec::new() },
+        has_styles: false,
+    })
+}
+
+fn check_variant_attributes(attributes: &FieldAttributes) -> Result<(), Error> {
+    if !attributes.groups.is_empty() {
+        return Err(Error::message(
+            "A group attribute isn't supported for enum variants",
+        ));
+    }
+
+    if attributes.option.is_some() || attributes.join.is_some() || attributes.table {
+        return Err(Error::message(
+            "`option`, `join` and `table` attributes aren't supported for enum variants",
+        ));
+    }
+
+    Ok(())
 }
 
 fn info_from_variant(
@@ -460,12 +613,29 @@ fn info_from_variant(
     // we need exactly string because of it must be inlined as string
     let values = quote! { vec![#value] };
 
-    Ok(Impl { headers, values })
+    let style = attr.style.to_tokens(&get_crate_path(trait_path));
+    let styles = quote! { vec![#style] };
+
+    Ok(Impl {
+        headers,
+        values,
+        groups: None,
+        styles,
+        has_styles: !attr.style.is_empty(),
+    })
 }
 
 struct Impl {
     headers: TokenStream,
     values: TokenStream,
+    groups: Option<Groups>,
+    styles: TokenStream,
+    has_styles: bool,
+}
+
+struct Groups {
+    headers: TokenStream,
+    values: TokenStream,
 }
 
 fn get_type_headers(
@@ -473,12 +643,19 @@ fn get_type_headers(
     inline_prefix: &str,
     prefix: &str,
     tabled_trait: &ExprPath,
+    with_groups: bool,
 ) -> TokenStream {
-    if prefix.is_empty() && inline_prefix.is_empty() {
+    let headers = if with_groups {
+        quote! { <#field_type as #tabled_trait>::headers_for(groups) }
+    } else {
         quote! { <#field_type as #tabled_trait>::headers() }
+    };
+
+    if prefix.is_empty() && inline_prefix.is_empty() {
+        headers
     } else {
         quote! {
-            <#field_type as #tabled_trait>::headers().into_iter()
+            #headers.into_iter()
                 .map(|header| {
                     let header = format!("{}{}{}", #prefix, #inline_prefix, header);
                     ::std::borrow::Cow::Owned(header)
@@ -490,11 +667,18 @@ fn get_type_headers(
 
 fn get_field_fields(
     field: &TokenStream,
+    field_type: &Type,
     attr: &FieldAttributes,
     fields: &Fields,
     field_name: FieldNameFn,
+    trait_path: &ExprPath,
+    with_groups: bool,
 ) -> TokenStream {
     if attr.inline {
+        if with_groups {
+            return quote! { #field.fields_for(groups) };
+        }
+
         return quote! { #field.fields() };
     }
 
@@ -540,6 +724,37 @@ fn get_field_fields(
         };
 
         return quote!(vec![::std::borrow::Cow::Owned(#call)]);
+    } else if let Some(placeholder) = &attr.option {
+        // the type annotation makes a reference to a variant field be coerced
+        return quote!({
+            let value: &#field_type = &#field;
+            match value {
+                Some(value) => vec![::std::borrow::Cow::Owned(format!("{}", value))],
+                None => vec![::std::borrow::Cow::Borrowed(#placeholder)],
+            }
+        });
+    } else if let Some(separator) = &attr.join {
+        return quote!({
+            let value: &#field_type = &#field;
+            let mut out = String::new();
+            for (i, item) in value.into_iter().enumerate() {
+                if i > 0 {
+                    out.push_str(#separator);
+                }
+
+                out.push_str(&format!("{}", item));
+            }
+
+            vec![::std::borrow::Cow::Owned(out)]
+        });
+    } else if attr.table {
+        let crate_path = get_crate_path(trait_path);
+        return quote!({
+            let value: &#field_type = &#field;
+            let table = #crate_path::Table::new(value);
+
+            vec![::std::borrow::Cow::Owned(table.to_string())]
+        });
     }
 
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
@@ -853,6 +1068,13 @@ fn parse_crate_name(name: &str) -> Result<ExprPath, Error> {
     syn::parse_str(name).map_err(|_| Error::message("unexpected crate attribute type"))
 }
 
+fn get_crate_path(trait_path: &ExprPath) -> ExprPath {
+    let mut p = trait_path.clone();
+    let count = p.path.segments.len();
+    p.path.segments = p.path.segments.into_iter().take(count - 1).collect();
+    p
+}
+
 fn create_tabled_trait_path(mut p: ExprPath) -> ExprPath {
     p.path.segments.push(PathSegment {
         ident: Ident::new("Tabled", proc_macro2::Span::call_site()),
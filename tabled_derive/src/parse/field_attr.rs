@@ -33,6 +33,14 @@ pub enum FieldAttrKind {
     DisplayWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
     Order(LitInt),
     FormatWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
+    Group(LitStr),
+    Align(LitStr),
+    Color(LitStr),
+    MaxWidth(LitInt),
+    Wrap(LitBool),
+    Option(LitStr),
+    Join(LitStr),
+    Table(LitBool),
 }
 
 impl Parse for FieldAttr {
@@ -55,6 +63,11 @@ impl Parse for FieldAttr {
                         return Ok(Self::new(DisplayWith(lit, None, Punctuated::new())))
                     }
                     "format" => return Ok(Self::new(FormatWith(lit, None, Punctuated::new()))),
+                    "group" => return Ok(Self::new(Group(lit))),
+                    "align" => return Ok(Self::new(Align(lit))),
+                    "color" => return Ok(Self::new(Color(lit))),
+                    "option" => return Ok(Self::new(Option(lit))),
+                    "join" => return Ok(Self::new(Join(lit))),
                     _ => {}
                 }
             }
@@ -65,6 +78,8 @@ impl Parse for FieldAttr {
                 match name_str.as_str() {
                     "skip" => return Ok(Self::new(Skip(lit))),
                     "inline" => return Ok(Self::new(Inline(lit, None))),
+                    "wrap" => return Ok(Self::new(Wrap(lit))),
+                    "table" => return Ok(Self::new(Table(lit))),
                     _ => {}
                 }
             }
@@ -72,8 +87,10 @@ impl Parse for FieldAttr {
             if input.peek(LitInt) {
                 let lit = input.parse::<LitInt>()?;
 
-                if let "order" = name_str.as_str() {
-                    return Ok(Self::new(Order(lit)));
+                match name_str.as_str() {
+                    "order" => return Ok(Self::new(Order(lit))),
+                    "max_width" => return Ok(Self::new(MaxWidth(lit))),
+                    _ => {}
                 }
             }
 
@@ -131,6 +148,9 @@ impl Parse for FieldAttr {
 
         match name_str.as_str() {
             "skip" => return Ok(Self::new(Skip(LitBool::new(true, Span::call_site())))),
+            "wrap" => return Ok(Self::new(Wrap(LitBool::new(true, Span::call_site())))),
+            "table" => return Ok(Self::new(Table(LitBool::new(true, Span::call_site())))),
+            "option" => return Ok(Self::new(Option(LitStr::new("", Span::call_site())))),
             "inline" => {
                 return Ok(Self::new(Inline(
                     LitBool::new(true, Span::call_site()),